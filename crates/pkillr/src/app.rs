@@ -1,17 +1,37 @@
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use nix::unistd::{Uid, getppid};
-
-use crate::config::{Config, SortField, Theme};
-use crate::process::{ProcessDetails, ProcessInfo, ProcessManager, can_kill, get_process_tree};
-use crate::signals::{Signal, SignalEvent, SignalSender};
+use ratatui::style::{Color, Style};
+
+use crate::config::{
+    Column, Config, CpuSource, EnterAction, MAX_PERCENT_PRECISION, MatchStyle, MemoryThresholds,
+    NameSource, PathDisplay, RuntimeThresholds, SearchWeights, SelectionStyle, SignalOrder,
+    SortField, Theme, TreeConnectors, percent_width,
+};
+use crate::help::{self, HelpEntry};
+use crate::metrics::Metrics;
+use crate::process::{
+    JobIds, Pressure, ProcessDetails, ProcessInfo, ProcessManager, ProcessSource, ProcessState,
+    argv0_basename, can_kill, get_process_tree, own_tty, read_job_ids, read_pressure,
+    read_tracer_pid, tty_name,
+};
+use crate::protect::{self, ProtectedTarget};
+use crate::risk::{RiskInfo, RiskLevel, assess_risk};
+use crate::settings;
+use crate::signals::{
+    Reaped, Signal, SignalEvent, SignalSender, TargetKind, parse_signal_value, reap_child,
+};
+use crate::views::{self, SavedView};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use regex::{Regex, RegexBuilder};
+use sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AppMode {
@@ -31,15 +51,18 @@ pub enum SortColumn {
     Name,
     User,
     Runtime,
+    CpuTime,
+    MajorFaults,
 }
 
 impl SortColumn {
-    const ALL: [SortColumn; 6] = [
+    const ALL: [SortColumn; 7] = [
         SortColumn::Cpu,
         SortColumn::Memory,
         SortColumn::Pid,
         SortColumn::Name,
         SortColumn::User,
+        SortColumn::CpuTime,
         SortColumn::Runtime,
     ];
 
@@ -67,6 +90,8 @@ impl SortColumn {
             SortField::Mem => SortColumn::Memory,
             SortField::Pid => SortColumn::Pid,
             SortField::Name => SortColumn::Name,
+            SortField::Time => SortColumn::CpuTime,
+            SortField::Faults => SortColumn::MajorFaults,
         }
     }
 
@@ -78,10 +103,22 @@ impl SortColumn {
             SortColumn::Name => "Name",
             SortColumn::User => "User",
             SortColumn::Runtime => "Runtime",
+            SortColumn::CpuTime => "CPU time",
+            SortColumn::MajorFaults => "Major faults",
         }
     }
 }
 
+/// screen positions of the process table from the last draw, for mouse hits.
+#[derive(Debug, Clone)]
+pub struct TableGeometry {
+    pub header_y: u16,
+    pub rows_y: u16,
+    pub rows_height: u16,
+    /// `(start_x, end_x, column)` for each sortable header cell.
+    pub columns: Vec<(u16, u16, SortColumn)>,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum StatusLevel {
     Info,
@@ -91,18 +128,6 @@ pub enum StatusLevel {
 
 pub type SignalHistoryEntry = SignalEvent;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub enum RiskLevel {
-    Elevated,
-    Critical,
-}
-
-#[derive(Debug, Clone)]
-pub struct RiskInfo {
-    pub level: RiskLevel,
-    pub reason: String,
-}
-
 #[derive(Debug, Clone)]
 pub struct TreeRow {
     pub pid: u32,
@@ -117,6 +142,43 @@ pub struct TreeRow {
     pub collapsed: bool,
     pub prefix: String,
     pub risk: Option<RiskInfo>,
+    pub job_ids: Option<JobIds>,
+    /// descendants cut off by the tree depth limit.
+    pub hidden_descendants: usize,
+}
+
+/// one process listed in the batch signal preview.
+#[derive(Debug, Clone)]
+pub struct BatchTarget {
+    pub pid: u32,
+    pub name: String,
+    pub risk: Option<RiskInfo>,
+    /// the debugger attached to it and its name; it may intercept the signal.
+    pub tracer: Option<(u32, String)>,
+}
+
+/// every filtered process about to receive `signal`, pending an explicit y.
+#[derive(Debug, Clone)]
+pub struct BatchKillPrompt {
+    pub signal: Signal,
+    pub query: String,
+    pub targets: Vec<BatchTarget>,
+}
+
+/// signals the batch preview cycles through with Tab.
+const BATCH_SIGNALS: [Signal; 4] = [
+    Signal::Sigterm,
+    Signal::Sigkill,
+    Signal::Sigint,
+    Signal::Sighup,
+];
+
+/// environment snapshot of the process other info panes are compared against.
+#[derive(Debug, Clone)]
+pub struct EnvReference {
+    pub pid: u32,
+    pub name: String,
+    pub environment: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -125,19 +187,136 @@ pub struct TreeKillPrompt {
     pub signal: Signal,
     pub lines: Vec<String>,
     pub risk: Option<RiskInfo>,
+    /// the debugger attached to the root and its name; it may intercept the signal.
+    pub tracer: Option<(u32, String)>,
 }
 
 const SEARCH_DEBOUNCE: Duration = Duration::from_millis(100);
+const SURVIVAL_CHECK_DELAY: Duration = Duration::from_millis(1500);
+/// how long the selection must rest on a row before its can't-kill reason is shown.
+const KILL_HINT_DELAY: Duration = Duration::from_millis(250);
+/// how many respawns a blacklisted target gets re-killed before giving up.
+const RESPAWN_MAX_KILLS: u32 = 5;
+/// how long a blacklist entry keeps watching for respawns.
+const RESPAWN_WATCH_WINDOW: Duration = Duration::from_secs(60);
+/// below this many visible rows, remind users that other users' processes are hidden.
+const SPARSE_PROCESS_LIST: usize = 10;
+
+/// a killed process whose respawns are re-killed automatically.
+#[derive(Debug, Clone)]
+struct RespawnWatch {
+    name: String,
+    cmdline: Vec<String>,
+    signal: Signal,
+    kills: u32,
+    expires: Instant,
+    /// same-named processes that already existed when the watch started.
+    ignored: HashSet<u32>,
+}
 
 #[derive(Debug, Clone)]
 enum SearchMode {
     Fuzzy(String),
+    Substring(String),
     Regex {
         pattern: String,
         flags: String,
         matcher: Regex,
     },
     History(String),
+    /// `swap>SIZE`: processes with more than this many bytes swapped out.
+    Swap(u64),
+    /// `exact:NAME`: the whole process name, nothing more or less.
+    Exact(String),
+    /// `net:true` / `net:false`: processes with, or without, a tcp or udp socket.
+    Network(bool),
+    /// `user:NAME`: processes owned by exactly this user.
+    User(String),
+}
+
+/// a search pushed onto the filter stack; every layer must match for a row to show.
+#[derive(Debug, Clone)]
+struct FilterLayer {
+    text: String,
+    mode: SearchMode,
+}
+
+/// processes, cpu and memory added up for one user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSummary {
+    pub user: String,
+    pub processes: usize,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+}
+
+/// what the per-user summary is ordered by, largest first.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum UserSort {
+    #[default]
+    Count,
+    Cpu,
+    Memory,
+}
+
+impl UserSort {
+    fn next(self) -> Self {
+        match self {
+            UserSort::Count => UserSort::Cpu,
+            UserSort::Cpu => UserSort::Memory,
+            UserSort::Memory => UserSort::Count,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            UserSort::Count => "count",
+            UserSort::Cpu => "CPU",
+            UserSort::Memory => "memory",
+        }
+    }
+}
+
+/// the info pane's sections, top to bottom; `1`-`9` jump to them in this order.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum InfoSection {
+    Basic,
+    Command,
+    Children,
+    Capabilities,
+    Environment,
+    OpenFiles,
+    MemoryMaps,
+    Network,
+    Cgroups,
+}
+
+impl InfoSection {
+    pub const ALL: [InfoSection; 9] = [
+        InfoSection::Basic,
+        InfoSection::Command,
+        InfoSection::Children,
+        InfoSection::Capabilities,
+        InfoSection::Environment,
+        InfoSection::OpenFiles,
+        InfoSection::MemoryMaps,
+        InfoSection::Network,
+        InfoSection::Cgroups,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            InfoSection::Basic => "basics",
+            InfoSection::Command => "command",
+            InfoSection::Children => "children",
+            InfoSection::Capabilities => "capabilities",
+            InfoSection::Environment => "environment",
+            InfoSection::OpenFiles => "open files",
+            InfoSection::MemoryMaps => "memory maps",
+            InfoSection::Network => "network",
+            InfoSection::Cgroups => "cgroups",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -146,10 +325,62 @@ struct SearchHit {
     name_indices: Vec<usize>,
 }
 
+/// a signal on its way to `targets`, kept whole while a prompt waits on the user.
 #[derive(Debug, Clone)]
-enum PendingKill {
-    Direct { targets: Vec<u32>, signal: Signal },
-    Tree { targets: Vec<u32>, signal: Signal },
+struct PendingKill {
+    targets: Vec<u32>,
+    signal: Signal,
+    mode: KillMode,
+    /// sigqueue payload; only ever set for direct kills.
+    value: Option<i32>,
+    /// the user already said yes to signalling their own shell.
+    shell_confirmed: bool,
+    /// the user pressed `!` to send it past a protection rule.
+    protection_overridden: bool,
+    /// already announced and held for `arm_delay`; sent straight away next time.
+    announced: bool,
+}
+
+impl PendingKill {
+    fn new(targets: Vec<u32>, signal: Signal, mode: KillMode) -> Self {
+        Self {
+            targets,
+            signal,
+            mode,
+            value: None,
+            shell_confirmed: false,
+            protection_overridden: false,
+            announced: false,
+        }
+    }
+
+    fn queued(pid: u32, signal: Signal, value: i32) -> Self {
+        Self {
+            value: Some(value),
+            ..Self::new(vec![pid], signal, KillMode::Direct)
+        }
+    }
+
+    fn shell_confirmed(self) -> Self {
+        Self {
+            shell_confirmed: true,
+            ..self
+        }
+    }
+
+    fn protection_overridden(self) -> Self {
+        Self {
+            protection_overridden: true,
+            ..self
+        }
+    }
+
+    fn announced(self) -> Self {
+        Self {
+            announced: true,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -166,9 +397,18 @@ pub struct App {
 
     mode: AppMode,
     search_query: String,
+    match_style: MatchStyle,
+    search_weights: SearchWeights,
+    case_sensitive: bool,
     sort_column: SortColumn,
     sort_descending: bool,
+    columns: Vec<Column>,
+    locked_widths: Option<Vec<u16>>,
+    measure_widths: bool,
+    cpu_precision: usize,
+    mem_precision: usize,
     show_all_processes: bool,
+    show_threads: bool,
 
     info_pane_open: bool,
     tree_view_open: bool,
@@ -176,48 +416,174 @@ pub struct App {
     signal_menu_selected: usize,
     signal_menu_scroll_offset: usize,
     signal_menu_target: Option<u32>,
+    signal_menu_target_kind: TargetKind,
+    /// pid and name of whatever is ptrace-attached to the menu's target.
+    signal_menu_tracer: Option<(u32, String)>,
+    signal_menu_digits: String,
+    signal_entry: Option<String>,
     shell_confirm: Option<PendingKill>,
+    protected: Vec<ProtectedTarget>,
+    /// a kill refused for hitting a protected target; `!` as the next key sends it anyway.
+    protection_override: Option<PendingKill>,
+    blacklist_confirm: Option<u32>,
+    batch_kill_prompt: Option<BatchKillPrompt>,
+    respawn_watches: Vec<RespawnWatch>,
     history_popup_open: bool,
+    saved_views: Vec<SavedView>,
+    config_file: Option<PathBuf>,
+    /// startup config before the config file's `[display]` table is layered on.
+    base_config: Config,
+    env_reference: Option<EnvReference>,
+    views_popup_open: bool,
+    views_selected: usize,
+    users_popup_open: bool,
+    users_selected: usize,
+    users_scroll_offset: usize,
+    users_sort: UserSort,
+    view_name_entry: Option<String>,
+    pid_list_entry: Option<String>,
     help_popup_open: bool,
+    help_filter: String,
+    help_filter_editing: bool,
+    help_scroll: usize,
+    help_max_scroll: usize,
     search_pending: bool,
     last_search_edit: Option<Instant>,
     search_matches: HashMap<u32, Vec<usize>>,
     search_scores: HashMap<u32, i64>,
+    filtered_totals: Option<(f32, u64)>,
+    debug_scores: bool,
     mode_before_popup: Option<AppMode>,
 
     theme: Theme,
+    selection_style: SelectionStyle,
+    signal_order: SignalOrder,
+    tree_connectors: TreeConnectors,
+    name_source: NameSource,
     refresh_rate_ms: u64,
+    /// set when the refresh rate outpaces sysinfo's cpu sampling; explained on the first tick.
+    cpu_interval_note: Option<String>,
 
     status_message: Option<(String, StatusLevel)>,
     signal_history: VecDeque<SignalHistoryEntry>,
+    last_signal: Signal,
+    survival_checks: Vec<(u32, Signal, Instant)>,
+    /// our own children that were signalled and still need reaping once they end.
+    pending_reaps: Vec<u32>,
+    kill_cooldown: Duration,
+    last_kill_at: Option<Instant>,
+    arm_delay: Duration,
+    arm_min_targets: usize,
+    /// an announced kill waiting out `arm_delay`, with when it fires; Esc drops it.
+    armed_kill: Option<(PendingKill, Instant)>,
+    /// the selected pid, when the selection landed on it, and whether its kill hint was shown.
+    kill_hint: Option<(u32, Instant, bool)>,
+    tree_kill_suggestion: Option<u32>,
+    clipboard_request: Option<String>,
+    pager_request: Option<u32>,
     needs_refresh: bool,
     paused: bool,
 
     info_pane_scroll: u16,
+    /// scroll offset of each section's heading, recorded by the last info pane render.
+    info_section_starts: Vec<(InfoSection, u16)>,
     info_focus: bool,
     info_env_expanded: bool,
     info_files_expanded: bool,
     info_maps_expanded: bool,
     info_network_expanded: bool,
     info_cgroups_expanded: bool,
+    /// show the command line as one string rather than an argument per line.
+    info_args_joined: bool,
+    info_path_display: PathDisplay,
     info_details_cache: Option<(u32, ProcessDetails)>,
+    /// pid and last known name of an info pane target that vanished before its details were read.
+    info_exited: Option<(u32, String)>,
+    info_max_maps: usize,
+    info_max_open_files: usize,
+    info_max_environment: usize,
+    info_env_entry_max: usize,
+    info_pages: usize,
+    info_pages_pid: Option<u32>,
 
     table_scroll_offset: usize,
+    /// earlier searches still narrowing the list, oldest first.
+    filter_stack: Vec<FilterLayer>,
+    table_visible_height: usize,
+    table_geometry: Option<TableGeometry>,
     tree_selected_index: usize,
     tree_rows: Vec<TreeRow>,
     tree_collapsed: HashSet<u32>,
     tree_scroll_offset: usize,
+    tree_visible_height: usize,
+    pending_z: bool,
     tree_kill_prompt: Option<TreeKillPrompt>,
+    tree_show_job_ids: bool,
+    tree_show_own_metrics: bool,
+    tree_max_depth: Option<usize>,
+    tree_root: Option<u32>,
+    tree_hide_idle: bool,
+    tree_min_cpu: f32,
+    tree_min_memory_bytes: u64,
+    tree_with_info: bool,
+    tree_confirm_min: usize,
+    enter_action: EnterAction,
+    show_refresh_age: bool,
+    pin_selection: bool,
+    /// pkillr's controlling terminal, never offered to the tty kill.
+    own_tty: Option<u32>,
+    /// bumped by every process list refresh, alongside when it happened.
+    refresh_generation: u64,
+    last_refresh: Instant,
+    /// whole seconds of refresh age last drawn, so the header redraws only when it changes.
+    shown_refresh_age: u64,
+    hide_idle: bool,
+    idle_cpu_threshold: f32,
+    /// info pane was closed on entering the tree and comes back on leaving it.
+    restore_info_pane: bool,
+    tree_hidden_count: usize,
     is_root: bool,
     parent_pid: u32,
     total_memory_bytes: u64,
-
-    process_manager: ProcessManager,
+    pressure: Option<Pressure>,
+    swap_collected: bool,
+    /// whether each pid has tcp/udp sockets, scanned only while a `net:` filter needs
+    /// them and dropped on every refresh. pids whose fds couldn't be read are missing.
+    network_pids: Option<HashMap<u32, bool>>,
+    /// pid to cgroup path, read on demand and dropped with each refresh.
+    cgroup_paths: Option<HashMap<u32, String>>,
+    swap_warn_bytes: u64,
+    memory_thresholds: MemoryThresholds,
+    runtime_colors: Option<RuntimeThresholds>,
+    safe_mode: bool,
+    advanced_signals: bool,
+    mouse: bool,
+    metrics: Arc<Metrics>,
+    /// sigqueue value being typed in the signal menu.
+    signal_value_entry: Option<String>,
+
+    process_source: Box<dyn ProcessSource>,
     signal_sender: SignalSender,
 }
 
 impl App {
     pub fn new(config: Config) -> Self {
+        let mut manager = ProcessManager::new();
+        manager.set_cpu_source(config.cpu_source);
+        Self::with_source(config, Box::new(manager))
+    }
+
+    /// builds the app on top of `source` instead of the live process table.
+    pub fn with_source(config: Config, source: Box<dyn ProcessSource>) -> Self {
+        Self::with_signals(config, source, SignalSender::new())
+    }
+
+    /// like `with_source`, but signals go through `signal_sender` as well.
+    pub fn with_signals(
+        config: Config,
+        source: Box<dyn ProcessSource>,
+        signal_sender: SignalSender,
+    ) -> Self {
         let current_uid = Uid::current();
         let is_root = current_uid.as_raw() == 0;
 
@@ -228,49 +594,152 @@ impl App {
             selected_pids: HashSet::new(),
             mode: AppMode::Normal,
             search_query: config.initial_filter.clone().unwrap_or_default(),
+            match_style: config.match_style,
+            search_weights: config.search_weights,
+            case_sensitive: config.case_sensitive,
             sort_column: SortColumn::from_sort_field(config.initial_sort),
             sort_descending: config.sort_descending,
+            columns: config.columns.clone(),
+            locked_widths: None,
+            measure_widths: config.lock_widths,
+            cpu_precision: config.cpu_precision.min(MAX_PERCENT_PRECISION),
+            mem_precision: config.mem_precision.min(MAX_PERCENT_PRECISION),
             show_all_processes: config.show_all_processes,
+            show_threads: false,
             info_pane_open: false,
             tree_view_open: false,
             signal_menu_open: false,
             signal_menu_selected: 0,
             signal_menu_scroll_offset: 0,
             signal_menu_target: None,
+            signal_menu_target_kind: TargetKind::Unknown,
+            signal_menu_tracer: None,
+            signal_menu_digits: String::new(),
+            signal_entry: None,
             shell_confirm: None,
+            protected: config.protected.clone(),
+            protection_override: None,
+            blacklist_confirm: None,
+            batch_kill_prompt: None,
+            respawn_watches: Vec::new(),
             history_popup_open: false,
+            saved_views: Vec::new(),
+            config_file: config.config_file.clone(),
+            base_config: config.clone(),
+            env_reference: None,
+            views_popup_open: false,
+            views_selected: 0,
+            users_popup_open: false,
+            users_selected: 0,
+            users_scroll_offset: 0,
+            users_sort: UserSort::default(),
+            view_name_entry: None,
+            pid_list_entry: None,
             help_popup_open: false,
+            help_filter: String::new(),
+            help_filter_editing: false,
+            help_scroll: 0,
+            help_max_scroll: 0,
             search_pending: false,
             last_search_edit: None,
             search_matches: HashMap::new(),
             search_scores: HashMap::new(),
+            filtered_totals: None,
+            debug_scores: config.debug_scores,
             mode_before_popup: None,
             theme: config.theme,
+            selection_style: config.selection_style,
+            signal_order: config.signal_order,
+            tree_connectors: config.tree_style.connectors(),
+            name_source: config.name_source,
             refresh_rate_ms: config.refresh_rate_ms,
+            cpu_interval_note: cpu_interval_note(config.refresh_rate_ms, config.cpu_source),
             status_message: None,
             signal_history: VecDeque::with_capacity(10),
+            last_signal: Signal::default(),
+            survival_checks: Vec::new(),
+            pending_reaps: Vec::new(),
+            kill_cooldown: config.kill_cooldown,
+            arm_delay: config.arm_delay,
+            arm_min_targets: config.arm_min_targets,
+            armed_kill: None,
+            last_kill_at: None,
+            kill_hint: None,
+            tree_kill_suggestion: None,
+            clipboard_request: None,
+            pager_request: None,
             needs_refresh: true,
             paused: false,
             info_pane_scroll: 0,
+            info_section_starts: Vec::new(),
             info_focus: false,
             info_env_expanded: false,
             info_files_expanded: false,
             info_maps_expanded: false,
             info_network_expanded: false,
             info_cgroups_expanded: false,
+            info_args_joined: false,
+            info_path_display: config.info_path_display,
             info_details_cache: None,
+            info_exited: None,
+            info_max_maps: config.info_max_maps,
+            info_max_open_files: config.info_max_open_files,
+            info_max_environment: config.info_max_environment,
+            info_env_entry_max: config.info_env_entry_max,
+            info_pages: 1,
+            info_pages_pid: None,
             table_scroll_offset: 0,
+            filter_stack: Vec::new(),
+            table_visible_height: 0,
+            table_geometry: None,
             tree_selected_index: 0,
             tree_rows: Vec::new(),
             tree_collapsed: HashSet::new(),
             tree_scroll_offset: 0,
+            tree_visible_height: 0,
+            pending_z: false,
             tree_kill_prompt: None,
+            tree_show_job_ids: false,
+            tree_show_own_metrics: false,
+            tree_max_depth: config.tree_max_depth,
+            tree_root: None,
+            tree_hide_idle: false,
+            tree_min_cpu: config.tree_min_cpu,
+            tree_min_memory_bytes: config.tree_min_memory_bytes,
+            tree_with_info: config.tree_with_info,
+            tree_confirm_min: config.tree_confirm_min,
+            enter_action: config.enter_action,
+            show_refresh_age: config.show_refresh_age,
+            pin_selection: config.pin_selection,
+            own_tty: own_tty(),
+            refresh_generation: 0,
+            last_refresh: Instant::now(),
+            shown_refresh_age: 0,
+            hide_idle: config.hide_idle,
+            idle_cpu_threshold: config.idle_cpu_threshold,
+            restore_info_pane: false,
+            tree_hidden_count: 0,
             is_root,
             parent_pid: getppid().as_raw() as u32,
             total_memory_bytes: 0,
-            process_manager: ProcessManager::new(),
-            signal_sender: SignalSender::new(),
+            pressure: None,
+            swap_collected: false,
+            network_pids: None,
+            cgroup_paths: None,
+            swap_warn_bytes: config.swap_warn_bytes,
+            memory_thresholds: config.memory_thresholds,
+            runtime_colors: config.runtime_colors,
+            safe_mode: config.safe_mode,
+            advanced_signals: config.advanced_signals,
+            mouse: config.mouse,
+            metrics: Arc::new(Metrics::new()),
+            signal_value_entry: None,
+            process_source: source,
+            signal_sender,
         };
+        if let Err(err) = app.load_config_file() {
+            app.set_status(StatusLevel::Warning, err);
+        }
         app.refresh_process_data();
         app.refresh_pause_state();
         app.update_signal_history();
@@ -284,35 +753,26 @@ impl App {
         self.refresh_process_data();
     }
 
-    pub fn apply_filters(&mut self) {
-        let mut data = self.processes.clone();
-        let raw_query = self.search_query.trim().to_string();
-        self.search_matches.clear();
-        self.search_scores.clear();
-
-        let mode = match Self::parse_search_mode(&raw_query) {
-            Ok(mode) => mode,
-            Err(err) => {
-                self.filtered_processes.clear();
-                self.selected_pids.clear();
-                self.table_scroll_offset = 0;
-                self.set_status(StatusLevel::Error, err);
-                self.invalidate_process_details();
-                self.search_pending = false;
-                self.last_search_edit = None;
-                self.needs_refresh = true;
-                return;
-            }
-        };
-
-        match &mode {
+    /// narrows `data` to what `mode` matches, recording name highlights and scores.
+    fn filter_by_mode(
+        &mut self,
+        mut data: Vec<ProcessInfo>,
+        mode: &SearchMode,
+    ) -> Vec<ProcessInfo> {
+        let weights = self.search_weights;
+        let case_sensitive = self.case_sensitive;
+        match mode {
             SearchMode::Fuzzy(query) => {
                 if !query.is_empty() {
-                    let matcher = SkimMatcherV2::default();
+                    let matcher = if case_sensitive {
+                        SkimMatcherV2::default().respect_case()
+                    } else {
+                        SkimMatcherV2::default().ignore_case()
+                    };
                     data = data
                         .into_iter()
                         .filter_map(|proc| {
-                            fuzzy_match_process(&proc, query, &matcher).map(|hit| {
+                            fuzzy_match_process(&proc, query, &matcher, &weights).map(|hit| {
                                 if !hit.name_indices.is_empty() {
                                     self.search_matches.insert(proc.pid, hit.name_indices);
                                 }
@@ -323,12 +783,30 @@ impl App {
                         .collect();
                 }
             }
+            SearchMode::Substring(query) => {
+                if !query.is_empty() {
+                    data = data
+                        .into_iter()
+                        .filter_map(|proc| {
+                            substring_match_process(&proc, query, case_sensitive, &weights).map(
+                                |hit| {
+                                    if !hit.name_indices.is_empty() {
+                                        self.search_matches.insert(proc.pid, hit.name_indices);
+                                    }
+                                    self.search_scores.insert(proc.pid, hit.score);
+                                    proc
+                                },
+                            )
+                        })
+                        .collect();
+                }
+            }
             SearchMode::Regex { matcher, .. } => {
                 let regex = matcher.clone();
                 data = data
                     .into_iter()
                     .filter_map(|proc| {
-                        regex_match_process(&proc, &regex).map(|hit| {
+                        regex_match_process(&proc, &regex, &weights).map(|hit| {
                             if !hit.name_indices.is_empty() {
                                 self.search_matches.insert(proc.pid, hit.name_indices);
                             }
@@ -341,10 +819,84 @@ impl App {
             SearchMode::History(filter) => {
                 data = self.filter_by_history(data, filter);
             }
+            SearchMode::Swap(threshold) => {
+                if !self.swap_collected {
+                    // swap is only read on demand; fetch it now rather than
+                    // waiting for the next refresh.
+                    self.swap_collected = true;
+                    self.process_source.set_collect_swap(true);
+                    self.processes = self.process_source.get_processes(self.show_all_processes);
+                    data = self.processes.clone();
+                }
+                data.retain(|proc| proc.swap_bytes.is_some_and(|swap| swap > *threshold));
+            }
+            SearchMode::Network(wanted) => {
+                let network = self.network_pids.get_or_insert_with(|| {
+                    let pids: Vec<u32> = self.processes.iter().map(|proc| proc.pid).collect();
+                    self.process_source.network_pids(&pids)
+                });
+                // an unreadable fd dir says nothing either way, so those stay listed.
+                data.retain(|proc| network.get(&proc.pid).is_none_or(|found| found == wanted));
+            }
+            SearchMode::User(user) => data.retain(|proc| proc.user == *user),
+            SearchMode::Exact(name) => {
+                data.retain(|proc| {
+                    if case_sensitive {
+                        proc.name == *name
+                    } else {
+                        proc.name.eq_ignore_ascii_case(name)
+                    }
+                });
+                for proc in &data {
+                    self.search_matches
+                        .insert(proc.pid, full_match_indices(&proc.name));
+                }
+            }
+        }
+        data
+    }
+
+    pub fn apply_filters(&mut self) {
+        let mut data = self.processes.clone();
+        let raw_query = self.search_query.trim().to_string();
+        self.search_matches.clear();
+        self.search_scores.clear();
+
+        let mode = match Self::parse_search_mode(&raw_query, self.match_style) {
+            Ok(mode) => mode,
+            Err(err) => {
+                self.filtered_processes.clear();
+                self.filtered_totals = None;
+                self.selected_pids.clear();
+                self.table_scroll_offset = 0;
+                self.set_status(StatusLevel::Error, err);
+                self.invalidate_process_details();
+                self.search_pending = false;
+                self.last_search_edit = None;
+                self.needs_refresh = true;
+                return;
+            }
+        };
+
+        for layer in self.filter_stack.clone() {
+            data = self.filter_by_mode(data, &layer.mode);
+        }
+        // only the live query ranks and highlights; the layers just narrow.
+        self.search_matches.clear();
+        self.search_scores.clear();
+        data = self.filter_by_mode(data, &mode);
+
+        if self.hide_idle {
+            // the selected row stays put even once it goes quiet, so a
+            // refresh never pulls it out from under the cursor.
+            let selected = self.current_pid();
+            let threshold = self.idle_cpu_threshold;
+            data.retain(|proc| proc.cpu_percent >= threshold || Some(proc.pid) == selected);
         }
 
         let mut sort_by_score = !self.search_scores.is_empty();
-        if matches!(mode, SearchMode::Fuzzy(ref query) if query.is_empty()) {
+        if matches!(mode, SearchMode::Fuzzy(ref query) | SearchMode::Substring(ref query) if query.is_empty())
+        {
             sort_by_score = false;
         }
 
@@ -362,14 +914,33 @@ impl App {
 
         let previous_len = self.filtered_processes.len();
         self.filtered_processes = data;
+        self.filtered_totals = if raw_query.is_empty() {
+            None
+        } else {
+            let cpu = self
+                .filtered_processes
+                .iter()
+                .map(|proc| proc.cpu_percent)
+                .sum();
+            let memory = self
+                .filtered_processes
+                .iter()
+                .map(|proc| proc.memory_bytes)
+                .sum();
+            Some((cpu, memory))
+        };
         self.selected_pids
             .retain(|pid| self.filtered_processes.iter().any(|proc| proc.pid == *pid));
         self.clamp_selection();
         if self.filtered_processes.is_empty() {
             self.table_scroll_offset = 0;
             let message = match mode {
-                SearchMode::Fuzzy(query) if query.is_empty() => "No processes found".to_string(),
-                SearchMode::Fuzzy(query) => format!("No matches for '{}'", query),
+                SearchMode::Fuzzy(query) | SearchMode::Substring(query) if query.is_empty() => {
+                    "No processes found".to_string()
+                }
+                SearchMode::Fuzzy(query) | SearchMode::Substring(query) => {
+                    format!("No matches for '{}'", query)
+                }
                 SearchMode::Regex { pattern, flags, .. } => {
                     let rendered = if flags.is_empty() {
                         format!("/{pattern}/")
@@ -384,6 +955,16 @@ impl App {
                 SearchMode::History(filter) => {
                     format!("No history entries matching '{}'", filter)
                 }
+                SearchMode::Swap(threshold) => {
+                    format!(
+                        "No processes with more than {} in swap",
+                        format_size(threshold)
+                    )
+                }
+                SearchMode::Exact(name) => format!("No process named exactly '{}'", name),
+                SearchMode::Network(true) => "No processes with network sockets".to_string(),
+                SearchMode::Network(false) => "Every process has a network socket".to_string(),
+                SearchMode::User(user) => format!("No processes owned by '{}'", user),
             };
             self.set_status(StatusLevel::Info, message);
         } else {
@@ -391,7 +972,9 @@ impl App {
             self.table_scroll_offset = self.table_scroll_offset.min(max_offset);
             if previous_len == 0 {
                 match mode {
-                    SearchMode::Fuzzy(query) if !query.is_empty() => {
+                    SearchMode::Fuzzy(query) | SearchMode::Substring(query)
+                        if !query.is_empty() =>
+                    {
                         let message = format!("Showing matches for '{}'", query);
                         self.set_status(StatusLevel::Info, message);
                     }
@@ -423,6 +1006,9 @@ impl App {
     }
 
     pub fn tick(&mut self, now: Instant) {
+        if let Some(note) = self.cpu_interval_note.take() {
+            self.set_status(StatusLevel::Info, note);
+        }
         if self.search_pending {
             if let Some(last) = self.last_search_edit {
                 if now.saturating_duration_since(last) >= SEARCH_DEBOUNCE {
@@ -432,6 +1018,114 @@ impl App {
                 self.apply_filters();
             }
         }
+        self.fire_armed_kill(now);
+        self.reap_own_children();
+        self.check_survivors(now);
+        self.check_kill_hint(now);
+        if self.show_refresh_age {
+            let age = self.refresh_age(now).as_secs();
+            if age != self.shown_refresh_age {
+                self.shown_refresh_age = age;
+                self.needs_refresh = true;
+            }
+        }
+    }
+
+    /// collects signalled children of pkillr itself that have ended, so they don't show up as
+    /// zombies we made; nobody else's children are ever waited on.
+    fn reap_own_children(&mut self) {
+        if self.pending_reaps.is_empty() {
+            return;
+        }
+        let mut reaped = Vec::new();
+        self.pending_reaps.retain(|pid| match reap_child(*pid) {
+            Ok(Reaped::Running) => true,
+            Ok(status) => {
+                reaped.push((*pid, status));
+                false
+            }
+            // already collected elsewhere, or no longer ours.
+            Err(_) => false,
+        });
+        for (pid, status) in reaped {
+            let outcome = match status {
+                Reaped::Exited(code) => format!("exited with status {code}"),
+                Reaped::Killed(signal) => format!("was killed by {}", signal.name()),
+                Reaped::Running => continue,
+            };
+            self.set_status(
+                StatusLevel::Info,
+                format!("reaped our child PID {pid}: it {outcome}"),
+            );
+        }
+    }
+
+    /// explains why the selected row can't be killed once the selection settles on it.
+    fn check_kill_hint(&mut self, now: Instant) {
+        let Some(pid) = self.current_pid() else {
+            self.kill_hint = None;
+            return;
+        };
+        match self.kill_hint {
+            Some((hinted, _, _)) if hinted != pid => self.kill_hint = Some((pid, now, false)),
+            None => self.kill_hint = Some((pid, now, false)),
+            Some((_, since, false)) if now.saturating_duration_since(since) >= KILL_HINT_DELAY => {
+                self.kill_hint = Some((pid, since, true));
+                if let Some(proc) = self.process_snapshot(pid)
+                    && let Err(reason) = can_kill(&proc)
+                {
+                    let message = format!("can't kill {} (PID {}): {}", proc.name, pid, reason);
+                    self.set_status(StatusLevel::Warning, message);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn check_survivors(&mut self, now: Instant) {
+        if self.survival_checks.is_empty() {
+            return;
+        }
+        let (due, pending): (Vec<_>, Vec<_>) = self
+            .survival_checks
+            .drain(..)
+            .partition(|(_, _, sent)| now.saturating_duration_since(*sent) >= SURVIVAL_CHECK_DELAY);
+        self.survival_checks = pending;
+
+        for (pid, signal, _) in due {
+            let Some(proc) = self.process_snapshot(pid) else {
+                continue;
+            };
+            if !self
+                .processes
+                .iter()
+                .any(|child| child.parent_pid == Some(pid))
+            {
+                continue;
+            }
+            self.tree_kill_suggestion = Some(pid);
+            let message = format!(
+                "{} (PID {}) is still alive after {} — press x to kill its tree",
+                proc.name,
+                pid,
+                signal.name()
+            );
+            self.set_status(StatusLevel::Warning, message);
+        }
+    }
+
+    /// someone typing `SIGTERM` or `kill` into the search probably wants the signal menu.
+    fn hint_signal_search(&mut self) {
+        let Some(signal) = signal_like_query(&self.search_query) else {
+            return;
+        };
+        self.set_status(
+            StatusLevel::Info,
+            format!(
+                "{} is a signal: Enter to leave search, then s opens the signal menu",
+                signal.name()
+            ),
+        );
     }
 
     fn mark_search_dirty(&mut self) {
@@ -440,6 +1134,32 @@ impl App {
         self.apply_filters();
     }
 
+    pub fn match_style(&self) -> MatchStyle {
+        self.match_style
+    }
+
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    pub fn toggle_case_sensitive(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
+        self.apply_filters();
+        let message = if self.case_sensitive {
+            "search is now case-sensitive"
+        } else {
+            "search is now case-insensitive"
+        };
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    pub fn toggle_match_style(&mut self) {
+        self.match_style = self.match_style.toggled();
+        self.apply_filters();
+        let message = format!("search matching: {}", self.match_style.label());
+        self.set_status(StatusLevel::Info, message);
+    }
+
     fn flush_search_filters(&mut self) {
         if self.search_pending {
             self.apply_filters();
@@ -447,15 +1167,37 @@ impl App {
     }
 
     pub fn handle_input(&mut self, event: KeyEvent) -> Result<bool> {
+        if self.handle_armed_kill_input(event) {
+            return Ok(false);
+        }
         if let Some(result) = self.handle_shell_confirm_input(event)? {
             return Ok(result);
         }
+        if self.handle_protection_override_input(event) {
+            return Ok(false);
+        }
+        if self.handle_blacklist_confirm_input(event) {
+            return Ok(false);
+        }
+        if self.handle_batch_kill_input(event) {
+            return Ok(false);
+        }
         if self.help_popup_open {
             return self.handle_help_popup_input(event);
         }
         if self.history_popup_open {
             return self.handle_history_popup_input(event);
         }
+        if self.views_popup_open {
+            return self.handle_views_popup_input(event);
+        }
+        if self.users_popup_open {
+            return self.handle_users_popup_input(event);
+        }
+
+        if !matches!(self.mode, AppMode::Normal | AppMode::InfoPane) {
+            self.tree_kill_suggestion = None;
+        }
 
         let should_quit = match self.mode {
             AppMode::Search => self.handle_search_input(event)?,
@@ -497,62 +1239,420 @@ impl App {
         }
     }
 
+    /// selects the filtered rows that weren't selected and deselects the rest.
+    pub fn invert_selection(&mut self) {
+        let inverted: HashSet<u32> = self
+            .filtered_processes
+            .iter()
+            .map(|proc| proc.pid)
+            .filter(|pid| !self.selected_pids.contains(pid))
+            .collect();
+        self.selected_pids = inverted;
+        self.report_selection_count();
+    }
+
+    pub fn select_all_filtered(&mut self) {
+        self.selected_pids = self
+            .filtered_processes
+            .iter()
+            .map(|proc| proc.pid)
+            .collect();
+        self.report_selection_count();
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected_pids.clear();
+        self.report_selection_count();
+    }
+
+    fn report_selection_count(&mut self) {
+        let count = self.selected_pids.len();
+        let message = match count {
+            0 => "selection cleared".to_string(),
+            1 => "1 process selected".to_string(),
+            _ => format!("{count} processes selected"),
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
+    /// true, with a status nudge, while a kill key pressed too soon after the last one should be ignored.
+    /// ignored presses restart the wait too, so a held key never gets through.
+    fn kill_cooling_down(&mut self) -> bool {
+        let now = Instant::now();
+        let cooling = self
+            .last_kill_at
+            .is_some_and(|last| now.duration_since(last) < self.kill_cooldown);
+        self.last_kill_at = Some(now);
+        if cooling {
+            self.set_status(StatusLevel::Info, "slow down");
+        }
+        cooling
+    }
+
     pub fn kill_selected(&mut self, signal: Signal) {
-        let targets = self.collect_target_pids();
-        if !self.dispatch_signal_targets(targets, signal, KillMode::Direct, false) {
+        if self.kill_cooling_down() {
             return;
         }
+        let targets = self.collect_target_pids();
+        self.dispatch_signal_targets(PendingKill::new(targets, signal, KillMode::Direct));
     }
 
     pub fn kill_selected_with_tree(&mut self, signal: Signal) {
-        let targets = self.collect_target_pids();
-        if !self.dispatch_signal_targets(targets, signal, KillMode::Tree, false) {
+        if self.kill_cooling_down() {
             return;
         }
+        let targets = self.collect_target_pids();
+        self.dispatch_signal_targets(PendingKill::new(targets, signal, KillMode::Tree));
     }
 
-    fn dispatch_signal_targets(
-        &mut self,
-        targets: Vec<u32>,
-        signal: Signal,
-        mode: KillMode,
-        allow_shell_override: bool,
-    ) -> bool {
-        if targets.is_empty() {
+    /// every signal pkillr sends passes through here, so each guard applies to all of them:
+    /// thread resolution, safe mode, protection, the shell check and arming, in that order.
+    fn dispatch_signal_targets(&mut self, pending: PendingKill) -> bool {
+        if pending.targets.is_empty() {
             self.set_status(StatusLevel::Warning, "no process selected");
             return false;
         }
+        let (targets, via_owner) = self.resolve_thread_targets(pending.targets);
+        let pending = PendingKill { targets, ..pending };
+        let PendingKill {
+            signal,
+            mode,
+            value,
+            ..
+        } = pending;
+        // tree kills and the tree view reach other users' processes whatever `--all` says,
+        // so the guards look at everyone.
+        let everyone = if self.safe_mode || !self.protected.is_empty() {
+            self.process_source.get_processes(true)
+        } else {
+            Vec::new()
+        };
+        if self.refuse_in_safe_mode(&everyone, &pending.targets, mode) {
+            return false;
+        }
+        if self.refuse_protected(&everyone, &pending) {
+            return false;
+        }
 
-        if !allow_shell_override && !self.is_root {
-            if targets.iter().any(|pid| *pid == self.parent_pid) {
-                self.shell_confirm = Some(match mode {
-                    KillMode::Direct => PendingKill::Direct { targets, signal },
-                    KillMode::Tree => PendingKill::Tree { targets, signal },
-                });
-                self.set_status(
-                    StatusLevel::Warning,
-                    format!(
-                        "This is your shell process (PID {}). Continue? (y/n)",
-                        self.parent_pid
-                    ),
-                );
-                self.needs_refresh = true;
-                self.refresh_pause_state();
-                return false;
-            }
+        if !pending.shell_confirmed && !self.is_root && pending.targets.contains(&self.parent_pid) {
+            self.shell_confirm = Some(pending);
+            self.set_status(
+                StatusLevel::Warning,
+                format!(
+                    "This is your shell process (PID {}); {} {}. Continue? (y/n)",
+                    self.parent_pid,
+                    signal.name(),
+                    signal.predicted_effect(TargetKind::Interactive)
+                ),
+            );
+            self.needs_refresh = true;
+            self.refresh_pause_state();
+            return false;
+        }
+
+        if !pending.announced
+            && !self.arm_delay.is_zero()
+            && pending.targets.len() >= self.arm_min_targets.max(1)
+        {
+            self.arm_kill(pending);
+            return false;
         }
 
+        let targets = pending.targets;
+        let stuck: Vec<(u32, String)> = targets
+            .iter()
+            .filter_map(|pid| self.process_snapshot(*pid))
+            .filter(|proc| proc.state == ProcessState::DiskSleep)
+            .map(|proc| (proc.pid, proc.name))
+            .collect();
+        let own_pid = std::process::id();
+        let own_children: Vec<u32> = targets
+            .iter()
+            .copied()
+            .filter(|pid| {
+                self.process_snapshot(*pid)
+                    .is_some_and(|proc| proc.parent_pid == Some(own_pid))
+            })
+            .collect();
         let executed = match mode {
-            KillMode::Direct => self.dispatch_direct(targets, signal),
+            KillMode::Direct => self.dispatch_direct(targets, signal, value),
             KillMode::Tree => self.dispatch_tree(targets, signal),
         };
+        if executed {
+            for pid in own_children {
+                if !self.pending_reaps.contains(&pid) {
+                    self.pending_reaps.push(pid);
+                }
+            }
+        }
+        if executed && let Some((pid, name)) = stuck.first() {
+            let message = format!(
+                "{} (PID {}) is in uninterruptible sleep; signals will not take effect until IO completes",
+                name, pid
+            );
+            self.set_status(StatusLevel::Warning, message);
+        }
+        if executed {
+            self.last_signal = signal;
+        }
+        if via_owner && let Some((message, _)) = self.status_message.as_mut() {
+            message.push_str(" (thread rows signal their owning process)");
+        }
 
         self.needs_refresh = true;
         self.refresh_pause_state();
         executed
     }
 
-    fn dispatch_direct(&mut self, targets: Vec<u32>, signal: Signal) -> bool {
+    /// counters shared with the metrics endpoint.
+    pub fn metrics(&self) -> Arc<Metrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    pub fn safe_mode(&self) -> bool {
+        self.safe_mode
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.is_root
+    }
+
+    /// first critical process among `targets` (and, for tree kills, their descendants).
+    fn safe_mode_violation(
+        &self,
+        everyone: &[ProcessInfo],
+        targets: &[u32],
+        mode: KillMode,
+    ) -> Option<(u32, RiskInfo)> {
+        first_affected(everyone, targets, mode, |proc| {
+            self.assess_risk(proc)
+                .filter(|risk| risk.level == RiskLevel::Critical)
+        })
+    }
+
+    /// blocks signals to protected targets until `!` is pressed to send them anyway.
+    fn refuse_protected(&mut self, everyone: &[ProcessInfo], pending: &PendingKill) -> bool {
+        if pending.protection_overridden {
+            return false;
+        }
+        let Some((pid, rule)) = self.protected_target(everyone, &pending.targets, pending.mode)
+        else {
+            return false;
+        };
+        let name = guarded_name(everyone, pid);
+        self.set_status(
+            StatusLevel::Error,
+            format!(
+                "{} (PID {}) is protected by {} — press ! to send {} anyway",
+                name,
+                pid,
+                rule,
+                pending.signal.name()
+            ),
+        );
+        self.protection_override = Some(pending.clone());
+        self.needs_refresh = true;
+        true
+    }
+
+    /// announces the signal and its targets, then holds them until `tick` passes `arm_delay`.
+    fn arm_kill(&mut self, pending: PendingKill) {
+        /// names listed before the rest are summarized as a count.
+        const NAMED_TARGETS: usize = 3;
+        let targets = &pending.targets;
+        let mut names: Vec<String> = targets
+            .iter()
+            .take(NAMED_TARGETS)
+            .map(|pid| {
+                self.process_name_for_pid(*pid)
+                    .unwrap_or_else(|| format!("PID {pid}"))
+            })
+            .collect();
+        if targets.len() > NAMED_TARGETS {
+            names.push(format!("+{} more", targets.len() - NAMED_TARGETS));
+        }
+        let message = format!(
+            "→ {} to {}: {} — Esc to abort",
+            pending.signal.name(),
+            process_count(targets.len()),
+            names.join(", ")
+        );
+        self.armed_kill = Some((pending, Instant::now() + self.arm_delay));
+        self.set_status(StatusLevel::Warning, message);
+        self.needs_refresh = true;
+    }
+
+    pub fn kill_armed(&self) -> bool {
+        self.armed_kill.is_some()
+    }
+
+    fn fire_armed_kill(&mut self, now: Instant) {
+        let Some((pending, _)) = self.armed_kill.take_if(|(_, fires_at)| now >= *fires_at) else {
+            return;
+        };
+        // answers given before it was armed travel with it, so nothing is asked twice.
+        self.dispatch_signal_targets(pending.announced());
+    }
+
+    /// Esc drops an armed kill; every other key carries on as usual.
+    fn handle_armed_kill_input(&mut self, event: KeyEvent) -> bool {
+        if self.armed_kill.is_none() || event.code != KeyCode::Esc {
+            return false;
+        }
+        self.armed_kill = None;
+        self.set_status(StatusLevel::Info, "aborted; nothing was sent");
+        self.needs_refresh = true;
+        true
+    }
+
+    fn protected_target(
+        &self,
+        everyone: &[ProcessInfo],
+        targets: &[u32],
+        mode: KillMode,
+    ) -> Option<(u32, ProtectedTarget)> {
+        if self.protected.is_empty() {
+            return None;
+        }
+        first_affected(everyone, targets, mode, |proc| {
+            protect::protecting(&self.protected, proc).cloned()
+        })
+    }
+
+    /// `!` sends the refused kill; any other key drops it and is handled as usual.
+    fn handle_protection_override_input(&mut self, event: KeyEvent) -> bool {
+        let Some(pending) = self.protection_override.take() else {
+            return false;
+        };
+        match event.code {
+            KeyCode::Char('!') => {
+                self.dispatch_signal_targets(pending.protection_overridden());
+                true
+            }
+            KeyCode::Esc => {
+                self.set_status(StatusLevel::Info, "kept protected process alive");
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// blocks the signal with an explanation when safe mode forbids it.
+    fn refuse_in_safe_mode(
+        &mut self,
+        everyone: &[ProcessInfo],
+        targets: &[u32],
+        mode: KillMode,
+    ) -> bool {
+        if !self.safe_mode {
+            return false;
+        }
+        let Some((pid, risk)) = self.safe_mode_violation(everyone, targets, mode) else {
+            return false;
+        };
+        let name = guarded_name(everyone, pid);
+        self.set_status(
+            StatusLevel::Error,
+            format!(
+                "safe mode: refusing to signal {} (PID {}): {} — drop --safe and [safety] safe_mode to allow",
+                name, pid, risk.reason
+            ),
+        );
+        self.needs_refresh = true;
+        true
+    }
+
+    fn resolve_thread_targets(&self, targets: Vec<u32>) -> (Vec<u32>, bool) {
+        let mut resolved = Vec::with_capacity(targets.len());
+        let mut via_owner = false;
+        for pid in targets {
+            let owner = self
+                .process_snapshot(pid)
+                .and_then(|proc| proc.thread_owner);
+            let target = owner.unwrap_or(pid);
+            via_owner |= owner.is_some();
+            if !resolved.contains(&target) {
+                resolved.push(target);
+            }
+        }
+        (resolved, via_owner)
+    }
+
+    pub fn show_threads(&self) -> bool {
+        self.show_threads
+    }
+
+    pub fn show_all_processes(&self) -> bool {
+        self.show_all_processes
+    }
+
+    pub fn toggle_show_all(&mut self) {
+        self.show_all_processes = !self.show_all_processes;
+        self.force_refresh_processes();
+        let message = if self.show_all_processes {
+            "showing all processes"
+        } else {
+            "showing your processes only"
+        };
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    /// onboarding hint shown while a short list may just be missing other users' processes.
+    /// a search or filter layer explains a short list by itself, so it stays quiet then.
+    pub fn hidden_processes_hint(&self) -> Option<&'static str> {
+        if self.show_all_processes
+            || !self.search_query.trim().is_empty()
+            || !self.filter_stack.is_empty()
+            || self.filtered_processes.len() >= SPARSE_PROCESS_LIST
+        {
+            return None;
+        }
+        Some("showing your processes only — press a (or restart with --all) to show all.")
+    }
+
+    pub fn hide_idle(&self) -> bool {
+        self.hide_idle
+    }
+
+    pub fn idle_cpu_threshold(&self) -> f32 {
+        self.idle_cpu_threshold
+    }
+
+    pub fn toggle_hide_idle(&mut self) {
+        self.hide_idle = !self.hide_idle;
+        let current = self.current_pid();
+        let previous_index = self.selected_index;
+        self.apply_filters();
+        self.selected_index = current
+            .and_then(|pid| {
+                self.filtered_processes
+                    .iter()
+                    .position(|proc| proc.pid == pid)
+            })
+            .unwrap_or(previous_index);
+        self.clamp_selection();
+        let message = if self.hide_idle {
+            format!("hiding processes under {:.1}% cpu", self.idle_cpu_threshold)
+        } else {
+            "showing idle processes".to_string()
+        };
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    pub fn toggle_threads(&mut self) {
+        self.show_threads = !self.show_threads;
+        self.process_source.set_include_threads(self.show_threads);
+        self.force_refresh_processes();
+        let message = if self.show_threads {
+            "showing thread rows"
+        } else {
+            "hiding thread rows"
+        };
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    fn dispatch_direct(&mut self, targets: Vec<u32>, signal: Signal, value: Option<i32>) -> bool {
         let mut successes = Vec::new();
         let mut errors = Vec::new();
 
@@ -561,8 +1661,18 @@ impl App {
                 .process_name_for_pid(pid)
                 .unwrap_or_else(|| format!("PID {pid}"));
             let risk = self.risk_for_pid(pid);
-            match self.signal_sender.send_signal(pid, signal) {
+            match self
+                .signal_sender
+                .send_signal_with_value(pid, signal, value)
+            {
                 Ok(_) => {
+                    let has_children = self
+                        .processes
+                        .iter()
+                        .any(|proc| proc.parent_pid == Some(pid));
+                    if has_children && is_termination_request(signal) {
+                        self.survival_checks.push((pid, signal, Instant::now()));
+                    }
                     successes.push((pid, name, risk));
                     self.selected_pids.remove(&pid);
                 }
@@ -570,17 +1680,39 @@ impl App {
             }
         }
 
+        self.metrics
+            .record_signal(signal, true, successes.len() as u64);
+        self.metrics
+            .record_signal(signal, false, errors.len() as u64);
         self.update_signal_history();
         self.force_refresh_processes();
         self.invalidate_process_details();
 
         if errors.is_empty() {
-            if !successes.is_empty() {
+            if let (Some(value), [(pid, name, _)]) = (value, successes.as_slice()) {
+                let message = format!(
+                    "queued {} (value {}) to {} ({})",
+                    signal.name(),
+                    value,
+                    name,
+                    pid
+                );
+                self.set_status(StatusLevel::Info, message);
+            } else if !successes.is_empty() {
                 self.report_kill_success(&successes, signal);
             }
+        } else if successes.len() + errors.len() > 1 {
+            self.report_batch_outcome(successes.len(), &errors, signal);
         } else {
-            let (_, _, err) = &errors[0];
-            self.report_kill_error(err);
+            let (pid, name, err) = &errors[0];
+            if self.error_category(err) == "not found" {
+                self.set_status(
+                    StatusLevel::Warning,
+                    format!("{name} (PID {pid}) already exited"),
+                );
+            } else {
+                self.report_kill_error(err);
+            }
         }
 
         !successes.is_empty() || !errors.is_empty()
@@ -597,10 +1729,13 @@ impl App {
             }
             match self.signal_sender.kill_process_tree(pid, signal) {
                 Ok(killed) => {
+                    self.metrics
+                        .record_signal(signal, true, killed.len() as u64);
                     total_killed += killed.len();
                     self.selected_pids.remove(&pid);
                 }
                 Err(err) => {
+                    self.metrics.record_signal(signal, false, 1);
                     errors.push(err);
                     break;
                 }
@@ -622,8 +1757,9 @@ impl App {
                         level = StatusLevel::Warning;
                     }
                     let message = format!(
-                        "Killed process tree: {} processes terminated — caution: {}",
-                        total_killed, risk.reason
+                        "Killed process tree: {} terminated — caution: {}",
+                        process_count(total_killed),
+                        risk.reason
                     );
                     self.set_status(level, message);
                 } else {
@@ -633,7 +1769,10 @@ impl App {
                     }
                     self.set_status(
                         level,
-                        format!("Killed process tree: {} processes terminated", total_killed),
+                        format!(
+                            "Killed process tree: {} terminated",
+                            process_count(total_killed)
+                        ),
                     );
                 }
             }
@@ -700,12 +1839,146 @@ impl App {
         self.set_status(level, message);
     }
 
+    pub fn yank_kill_command(&mut self) {
+        let targets = self.collect_target_pids();
+        if targets.is_empty() {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        }
+        let pids: Vec<String> = targets.iter().map(|pid| pid.to_string()).collect();
+        let command = format!(
+            "kill -{} {}",
+            self.last_signal.name().trim_start_matches("SIG"),
+            pids.join(" ")
+        );
+        self.set_status(StatusLevel::Info, format!("copied: {command}"));
+        self.clipboard_request = Some(command);
+    }
+
+    /// copies the selected pids, sorted so repeated copies of one selection match.
+    pub fn copy_selected_pids(&mut self) {
+        if self.selected_pids.is_empty() {
+            self.set_status(StatusLevel::Warning, "no processes selected");
+            return;
+        }
+        let mut pids: Vec<u32> = self.selected_pids.iter().copied().collect();
+        pids.sort_unstable();
+        let text = pids
+            .iter()
+            .map(|pid| pid.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.set_status(StatusLevel::Info, format!("copied: {text}"));
+        self.clipboard_request = Some(text);
+    }
+
+    pub fn take_clipboard_request(&mut self) -> Option<String> {
+        self.clipboard_request.take()
+    }
+
+    /// leaves the text in the status line so it can still be copied by hand.
+    pub fn clipboard_failed(&mut self, text: &str, err: String) {
+        self.set_status(
+            StatusLevel::Warning,
+            format!("clipboard unavailable ({err}): {text}"),
+        );
+    }
+
+    /// asks the terminal loop to page the current process's raw `/proc` files.
+    pub fn request_pager(&mut self) {
+        if !cfg!(target_os = "linux") {
+            self.set_status(
+                StatusLevel::Warning,
+                "raw /proc files are only available on linux",
+            );
+            return;
+        }
+        match self.current_pid() {
+            Some(pid) => self.pager_request = Some(pid),
+            None => self.set_status(StatusLevel::Warning, "no process selected"),
+        }
+    }
+
+    pub fn take_pager_request(&mut self) -> Option<u32> {
+        self.pager_request.take()
+    }
+
+    /// reports how paging went once the terminal is back.
+    pub fn finish_pager(&mut self, result: Result<(), String>) {
+        if let Err(err) = result {
+            self.set_status(StatusLevel::Error, err);
+        }
+        self.needs_refresh = true;
+    }
+
+    /// one status line for a multi-target signal with failures, e.g.
+    /// "SIGTERM: 3 killed, 1 permission denied (PID 12), 1 not found (PID 40)".
+    fn report_batch_outcome(
+        &mut self,
+        succeeded: usize,
+        errors: &[(u32, String, String)],
+        signal: Signal,
+    ) {
+        const LISTED_PIDS: usize = 3;
+        let mut failures: Vec<(String, Vec<u32>)> = Vec::new();
+        for (pid, _, err) in errors {
+            let category = self.error_category(err);
+            match failures.iter_mut().find(|(known, _)| *known == category) {
+                Some((_, pids)) => pids.push(*pid),
+                None => failures.push((category, vec![*pid])),
+            }
+        }
+        // only the signals meant to end a process get to call the survivors killed.
+        let outcome = if matches!(signal, Signal::Sigkill | Signal::Sigterm) {
+            "killed"
+        } else {
+            "signalled"
+        };
+        let mut parts = vec![format!("{succeeded} {outcome}")];
+        for (category, pids) in &failures {
+            let mut listed: Vec<String> = pids
+                .iter()
+                .take(LISTED_PIDS)
+                .map(|pid| pid.to_string())
+                .collect();
+            if pids.len() > LISTED_PIDS {
+                listed.push("…".to_string());
+            }
+            parts.push(format!(
+                "{} {} (PID {})",
+                pids.len(),
+                category,
+                listed.join(", ")
+            ));
+        }
+        let level = if succeeded > 0 {
+            StatusLevel::Warning
+        } else {
+            StatusLevel::Error
+        };
+        self.set_status(level, format!("{}: {}", signal.name(), parts.join(", ")));
+    }
+
+    /// short label grouping signal failures with the same cause.
+    fn error_category(&self, error: &str) -> String {
+        let lowered = error.to_ascii_lowercase();
+        if lowered.contains("permission") {
+            "permission denied".to_string()
+        } else if lowered.contains("not found") {
+            "not found".to_string()
+        } else {
+            self.friendly_error_message(error)
+                .trim_end_matches('.')
+                .to_lowercase()
+        }
+    }
+
     fn report_kill_error(&mut self, error: &str) {
         let message = self.friendly_error_message(error);
         self.set_status(StatusLevel::Error, message);
     }
 
-    pub(crate) fn friendly_error_message(&self, error: &str) -> String {
+    pub fn friendly_error_message(&self, error: &str) -> String {
         let lowered = error.to_ascii_lowercase();
         if lowered.contains("permission") {
             "Permission denied. Run with sudo or select a user-owned process.".to_string()
@@ -729,51 +2002,370 @@ impl App {
         self.invalidate_process_details();
     }
 
-    pub fn jump_to_bottom(&mut self) {
-        if self.filtered_processes.is_empty() {
+    /// moves to the next visible process in uninterruptible (D) sleep, wrapping around.
+    pub fn jump_to_disk_sleep(&mut self) {
+        let count = self.filtered_processes.len();
+        let next = (1..=count)
+            .map(|step| (self.selected_index + step) % count.max(1))
+            .find(|idx| self.filtered_processes[*idx].state == ProcessState::DiskSleep);
+        let Some(idx) = next else {
+            self.set_status(StatusLevel::Info, "no processes in uninterruptible sleep");
             return;
-        }
-        self.selected_index = self.filtered_processes.len() - 1;
-        self.needs_refresh = true;
+        };
+        self.selected_index = idx;
+        self.clamp_selection();
         self.invalidate_process_details();
+        let proc = &self.filtered_processes[idx];
+        let message = format!(
+            "{} (PID {}) is in uninterruptible sleep (D), usually waiting on disk or NFS",
+            proc.name, proc.pid
+        );
+        self.set_status(StatusLevel::Warning, message);
+        self.needs_refresh = true;
     }
 
-    pub fn needs_refresh(&self) -> bool {
-        self.needs_refresh
+    /// selects the busiest visible process by cpu (or memory) without touching
+    /// the sort; ties go to the lowest pid.
+    pub fn jump_to_heaviest(&mut self, by_memory: bool) {
+        let heaviest = self
+            .filtered_processes
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                let load = if by_memory {
+                    a.memory_bytes.cmp(&b.memory_bytes)
+                } else {
+                    a.cpu_percent.total_cmp(&b.cpu_percent)
+                };
+                load.then_with(|| b.pid.cmp(&a.pid))
+            })
+            .map(|(idx, proc)| (idx, proc.name.clone(), proc.cpu_percent, proc.memory_bytes));
+        let Some((idx, name, cpu, memory)) = heaviest else {
+            self.set_status(StatusLevel::Info, "no processes to jump to");
+            return;
+        };
+        self.selected_index = idx;
+        self.clamp_selection();
+        self.invalidate_process_details();
+        let message = if by_memory {
+            format!("jumped to top memory: {} ({})", name, format_size(memory))
+        } else {
+            format!("jumped to top CPU: {} ({:.1}%)", name, cpu)
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
     }
 
-    pub fn clear_refresh_flag(&mut self) {
-        self.needs_refresh = false;
-    }
+    /// selects the parent (`to_parent`) or first child of the current row,
+    /// dropping the search filter when it hides the target.
+    fn jump_to_relative(&mut self, to_parent: bool) {
+        let Some(current) = self.current_pid() else {
+            return;
+        };
+        let relation = if to_parent { "parent" } else { "child" };
+        let target = if to_parent {
+            self.process_snapshot(current)
+                .and_then(|proc| proc.parent_pid)
+        } else {
+            self.filtered_processes
+                .iter()
+                .chain(self.processes.iter())
+                .find(|proc| proc.parent_pid == Some(current))
+                .map(|proc| proc.pid)
+        };
+        let Some(target) = target else {
+            self.set_status(
+                StatusLevel::Info,
+                format!("PID {} has no {}", current, relation),
+            );
+            self.needs_refresh = true;
+            return;
+        };
 
-    pub fn request_redraw(&mut self) {
+        if !self
+            .filtered_processes
+            .iter()
+            .any(|proc| proc.pid == target)
+            && !self.search_query.is_empty()
+            && self.processes.iter().any(|proc| proc.pid == target)
+        {
+            self.search_query.clear();
+            self.apply_filters();
+            self.set_status(
+                StatusLevel::Info,
+                format!("cleared the filter to show {} PID {}", relation, target),
+            );
+        }
+
+        match self
+            .filtered_processes
+            .iter()
+            .position(|proc| proc.pid == target)
+        {
+            Some(idx) => {
+                self.selected_index = idx;
+                self.clamp_selection();
+                self.invalidate_process_details();
+            }
+            None => self.set_status(
+                StatusLevel::Warning,
+                format!("{} PID {} is not in the current view", relation, target),
+            ),
+        }
         self.needs_refresh = true;
     }
 
-    pub fn is_paused(&self) -> bool {
-        self.paused
+    pub fn filter_layers(&self) -> impl Iterator<Item = &str> {
+        self.filter_stack.iter().map(|layer| layer.text.as_str())
     }
 
-    pub fn mode(&self) -> AppMode {
-        self.mode
+    /// every filter layer and then the live search, as the header shows them; empty when
+    /// nothing narrows the list.
+    fn layered_query(&self) -> String {
+        let search = self.search_query.trim();
+        self.filter_layers()
+            .chain((!search.is_empty()).then_some(search))
+            .collect::<Vec<_>>()
+            .join(" › ")
     }
 
-    pub fn search_query(&self) -> &str {
-        &self.search_query
+    /// moves the current search onto the filter stack and empties the input for the next one.
+    fn push_filter_layer(&mut self) {
+        let text = self.search_query.trim().to_string();
+        if text.is_empty() {
+            self.set_status(StatusLevel::Info, "type a filter before pushing it");
+            return;
+        }
+        let mode = match Self::parse_search_mode(&text, self.match_style) {
+            Ok(mode) => mode,
+            Err(err) => {
+                self.set_status(StatusLevel::Error, err);
+                return;
+            }
+        };
+        self.filter_stack.push(FilterLayer {
+            text: text.clone(),
+            mode,
+        });
+        self.search_query.clear();
+        self.apply_filters();
+        self.set_status(
+            StatusLevel::Info,
+            format!(
+                "filter layer {}: {text} — Backspace on an empty search removes it",
+                self.filter_stack.len()
+            ),
+        );
     }
 
-    pub fn signal_history(&self) -> &VecDeque<SignalHistoryEntry> {
-        &self.signal_history
+    fn pop_filter_layer(&mut self) {
+        let Some(layer) = self.filter_stack.pop() else {
+            self.set_status(StatusLevel::Info, "no filter layers to remove");
+            self.needs_refresh = true;
+            return;
+        };
+        self.apply_filters();
+        self.set_status(
+            StatusLevel::Info,
+            format!("removed filter layer '{}'", layer.text),
+        );
+    }
+
+    /// drops the search filter, keeping the selected process selected.
+    pub fn clear_filter(&mut self) {
+        if self.search_query.is_empty() && self.filter_stack.is_empty() {
+            self.set_status(StatusLevel::Info, "no filter to clear");
+            self.needs_refresh = true;
+            return;
+        }
+        let current = self.current_pid();
+        self.search_query.clear();
+        self.filter_stack.clear();
+        self.apply_filters();
+        if let Some(idx) = current.and_then(|pid| {
+            self.filtered_processes
+                .iter()
+                .position(|proc| proc.pid == pid)
+        }) {
+            self.selected_index = idx;
+            self.clamp_selection();
+        }
+        self.set_status(StatusLevel::Info, "filter cleared.");
+    }
+
+    pub fn jump_to_bottom(&mut self) {
+        if self.filtered_processes.is_empty() {
+            return;
+        }
+        self.selected_index = self.filtered_processes.len() - 1;
+        self.needs_refresh = true;
+        self.invalidate_process_details();
+    }
+
+    pub fn needs_refresh(&self) -> bool {
+        self.needs_refresh
+    }
+
+    pub fn clear_refresh_flag(&mut self) {
+        self.needs_refresh = false;
+    }
+
+    pub fn request_redraw(&mut self) {
+        self.needs_refresh = true;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn mode(&self) -> AppMode {
+        self.mode
+    }
+
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    pub fn signal_history(&self) -> &VecDeque<SignalHistoryEntry> {
+        &self.signal_history
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// width of each column: the locked measurement when there is one.
+    /// percent columns follow their precision even when the text columns are locked.
+    pub fn column_widths(&self) -> Vec<u16> {
+        let widths = match &self.locked_widths {
+            Some(widths) if widths.len() == self.columns.len() => widths.clone(),
+            _ => self.columns.iter().map(|column| column.width()).collect(),
+        };
+        self.columns
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| match column {
+                Column::Cpu => percent_width(self.cpu_precision),
+                Column::Mem => percent_width(self.mem_precision),
+                _ => width,
+            })
+            .collect()
+    }
+
+    pub fn cpu_precision(&self) -> usize {
+        self.cpu_precision
+    }
+
+    pub fn mem_precision(&self) -> usize {
+        self.mem_precision
+    }
+
+    fn cycle_cpu_precision(&mut self) {
+        self.cpu_precision = (self.cpu_precision + 1) % (MAX_PERCENT_PRECISION + 1);
+        self.report_precision("CPU%", self.cpu_precision);
+    }
+
+    fn cycle_mem_precision(&mut self) {
+        self.mem_precision = (self.mem_precision + 1) % (MAX_PERCENT_PRECISION + 1);
+        self.report_precision("MEM%", self.mem_precision);
+    }
+
+    fn report_precision(&mut self, column: &str, precision: usize) {
+        let message = match precision {
+            0 => format!("{column} shows whole numbers"),
+            1 => format!("{column} shows 1 decimal"),
+            _ => format!("{column} shows {precision} decimals"),
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
+    /// true when the renderer should measure the rows and lock the result.
+    pub fn widths_need_measuring(&self) -> bool {
+        self.measure_widths && !self.filtered_processes.is_empty()
+    }
+
+    pub fn lock_column_widths(&mut self, widths: Vec<u16>) {
+        self.locked_widths = Some(widths);
+        self.measure_widths = false;
+    }
+
+    pub fn remeasure_column_widths(&mut self) {
+        self.measure_widths = true;
+        self.set_status(
+            StatusLevel::Info,
+            "column widths locked to the current rows",
+        );
+        self.needs_refresh = true;
+    }
+
+    pub fn sort_column(&self) -> SortColumn {
+        self.sort_column
+    }
+
+    pub fn sort_descending(&self) -> bool {
+        self.sort_descending
     }
 
     pub fn theme(&self) -> Theme {
         self.theme
     }
 
+    pub fn selection_style(&self) -> SelectionStyle {
+        self.selection_style
+    }
+
+    pub fn name_source(&self) -> NameSource {
+        self.name_source
+    }
+
+    /// text for the Name column; kernel threads fall back to their comm name.
+    pub fn display_name<'a>(&self, proc: &'a ProcessInfo) -> &'a str {
+        match self.name_source {
+            NameSource::Comm => &proc.name,
+            NameSource::Argv0 => argv0_basename(&proc.cmdline).unwrap_or(&proc.name),
+        }
+    }
+
     pub fn filtered_processes(&self) -> &[ProcessInfo] {
         &self.filtered_processes
     }
 
+    pub fn show_refresh_age(&self) -> bool {
+        self.show_refresh_age
+    }
+
+    pub fn refresh_generation(&self) -> u64 {
+        self.refresh_generation
+    }
+
+    pub fn refresh_age(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_refresh)
+    }
+
+    fn toggle_refresh_age(&mut self) {
+        self.show_refresh_age = !self.show_refresh_age;
+        let message = if self.show_refresh_age {
+            "showing time since the last refresh"
+        } else {
+            "hiding time since the last refresh"
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
+    pub fn debug_scores(&self) -> bool {
+        self.debug_scores
+    }
+
+    pub fn search_score(&self, pid: u32) -> Option<i64> {
+        self.search_scores.get(&pid).copied()
+    }
+
+    pub fn filtered_totals(&self) -> Option<(f32, u64)> {
+        self.filtered_totals
+    }
+
     pub fn highlight_indices(&self, pid: u32) -> Option<&[usize]> {
         self.search_matches
             .get(&pid)
@@ -804,6 +2396,77 @@ impl App {
         self.table_scroll_offset = offset;
     }
 
+    pub fn set_table_visible_height(&mut self, height: usize) {
+        self.table_visible_height = height;
+    }
+
+    pub fn set_table_geometry(&mut self, geometry: Option<TableGeometry>) {
+        self.table_geometry = geometry;
+    }
+
+    /// whether the terminal should report mouse events; follows `--no-mouse` and a reload.
+    pub fn mouse_enabled(&self) -> bool {
+        self.mouse
+    }
+
+    /// header clicks sort by that column (again to flip the order), row
+    /// clicks select, and the wheel moves the selection.
+    pub fn handle_mouse(&mut self, event: MouseEvent) {
+        if !self.mouse
+            || self.tree_view_open
+            || self.help_popup_open
+            || self.history_popup_open
+            || self.views_popup_open
+            || self.users_popup_open
+            || !matches!(self.mode, AppMode::Normal | AppMode::InfoPane)
+        {
+            return;
+        }
+        match event.kind {
+            MouseEventKind::ScrollDown => self.select_next(),
+            MouseEventKind::ScrollUp => self.select_prev(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(geometry) = self.table_geometry.as_ref() else {
+                    return;
+                };
+                if event.row == geometry.header_y {
+                    if let Some(&(_, _, column)) = geometry
+                        .columns
+                        .iter()
+                        .find(|(start, end, _)| (*start..*end).contains(&event.column))
+                    {
+                        self.sort_by_column(column);
+                    }
+                } else if event.row >= geometry.rows_y
+                    && event.row < geometry.rows_y + geometry.rows_height
+                {
+                    let index = self.table_scroll_offset + usize::from(event.row - geometry.rows_y);
+                    if index < self.filtered_processes.len() {
+                        self.selected_index = index;
+                        self.needs_refresh = true;
+                        self.invalidate_process_details();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn sort_by_column(&mut self, column: SortColumn) {
+        if self.sort_column == column {
+            self.sort_descending = !self.sort_descending;
+        } else {
+            self.sort_column = column;
+        }
+        self.apply_filters();
+        let message = format!(
+            "sorting by {} {}",
+            self.sort_column.display_name(),
+            order_text(self.sort_descending)
+        );
+        self.set_status(StatusLevel::Info, message);
+    }
+
     pub fn status_message(&self) -> Option<&(String, StatusLevel)> {
         self.status_message.as_ref()
     }
@@ -816,6 +2479,40 @@ impl App {
         self.total_memory_bytes
     }
 
+    /// memory color under the configured thresholds.
+    pub fn memory_color(&self, bytes: u64) -> Color {
+        self.theme
+            .get_memory_color(bytes, self.memory_thresholds, self.total_memory_bytes)
+    }
+
+    /// `None` while runtime coloring is off.
+    pub fn runtime_color(&self, runtime: Duration) -> Option<Color> {
+        self.runtime_colors
+            .map(|thresholds| self.theme.get_runtime_color(runtime, thresholds))
+    }
+
+    pub fn process_style(&self, proc: &ProcessInfo) -> Style {
+        self.theme
+            .style_for_process(proc, self.memory_thresholds, self.total_memory_bytes)
+    }
+
+    /// known only while a `net:` filter has scanned sockets since the last refresh.
+    pub fn has_network_sockets(&self, pid: u32) -> bool {
+        self.network_pids
+            .as_ref()
+            .is_some_and(|pids| pids.get(&pid) == Some(&true))
+    }
+
+    /// whether `proc` has more swapped out than the warning threshold.
+    pub fn is_heavily_swapped(&self, proc: &ProcessInfo) -> bool {
+        proc.swap_bytes
+            .is_some_and(|swap| swap > 0 && swap >= self.swap_warn_bytes)
+    }
+
+    pub fn pressure(&self) -> Option<Pressure> {
+        self.pressure
+    }
+
     pub fn signal_menu_open(&self) -> bool {
         self.signal_menu_open
     }
@@ -836,6 +2533,23 @@ impl App {
         self.signal_menu_target
     }
 
+    /// the predicted effect of the highlighted signal on the menu's target.
+    pub fn signal_menu_prediction(&self) -> Option<String> {
+        let signal = *self.signal_menu_signals().get(self.signal_menu_selected)?;
+        let effect = signal.predicted_effect(self.signal_menu_target_kind);
+        Some(match &self.signal_menu_tracer {
+            // SIGKILL is the one signal a tracer can't hold back.
+            Some((pid, name)) if signal != Signal::Sigkill => {
+                format!("{effect}; traced by PID {pid} ({name}), which may intercept it")
+            }
+            _ => effect,
+        })
+    }
+
+    pub fn signal_menu_tracer(&self) -> Option<&(u32, String)> {
+        self.signal_menu_tracer.as_ref()
+    }
+
     pub fn history_popup_open(&self) -> bool {
         self.history_popup_open
     }
@@ -860,6 +2574,40 @@ impl App {
         self.tree_scroll_offset = offset;
     }
 
+    pub fn set_tree_visible_height(&mut self, height: usize) {
+        self.tree_visible_height = height;
+    }
+
+    /// scrolls so the selected row sits in the middle of the visible list.
+    fn center_selection(&mut self) {
+        if self.tree_view_open {
+            self.tree_scroll_offset = centered_offset(
+                self.tree_selected_index,
+                self.tree_rows.len(),
+                self.tree_visible_height,
+            );
+        } else {
+            self.table_scroll_offset = centered_offset(
+                self.selected_index,
+                self.filtered_processes.len(),
+                self.table_visible_height,
+            );
+        }
+        self.needs_refresh = true;
+    }
+
+    /// handles the second key of a `z` chord; returns true when consumed.
+    fn handle_pending_z(&mut self, event: KeyEvent) -> bool {
+        if !std::mem::take(&mut self.pending_z) {
+            return false;
+        }
+        if event.code == KeyCode::Char('z') {
+            self.center_selection();
+            return true;
+        }
+        false
+    }
+
     pub fn tree_selected_index(&self) -> usize {
         self.tree_selected_index
     }
@@ -874,6 +2622,97 @@ impl App {
         self.tree_kill_prompt.as_ref()
     }
 
+    fn toggle_tree_job_ids(&mut self) {
+        self.tree_show_job_ids = !self.tree_show_job_ids;
+        self.rebuild_tree_nodes();
+        let message = if self.tree_show_job_ids {
+            "showing process group and session ids"
+        } else {
+            "hiding process group and session ids"
+        };
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    pub fn tree_hide_idle(&self) -> bool {
+        self.tree_hide_idle
+    }
+
+    pub fn tree_hidden_count(&self) -> usize {
+        self.tree_hidden_count
+    }
+
+    fn toggle_tree_hide_idle(&mut self) {
+        self.tree_hide_idle = !self.tree_hide_idle;
+        self.rebuild_tree_nodes();
+        let message = if self.tree_hide_idle {
+            format!(
+                "tree: hiding branches under {:.1}% cpu and {} ({} hidden)",
+                self.tree_min_cpu,
+                format_memory_threshold(self.tree_min_memory_bytes),
+                self.tree_hidden_count
+            )
+        } else {
+            "tree: showing all branches".to_string()
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
+    /// whether `pid`'s whole subtree is below both load thresholds.
+    fn tree_branch_idle(
+        &self,
+        pid: u32,
+        map: &HashMap<u32, ProcessInfo>,
+        children: &HashMap<u32, Vec<u32>>,
+    ) -> bool {
+        if !self.tree_hide_idle {
+            return false;
+        }
+        let (cpu, mem) = self.subtree_totals(pid, map, children);
+        cpu < self.tree_min_cpu && mem < self.tree_min_memory_bytes
+    }
+
+    pub fn tree_max_depth(&self) -> Option<usize> {
+        self.tree_max_depth
+    }
+
+    fn change_tree_depth(&mut self, deeper: bool) {
+        let deepest = self
+            .tree_rows
+            .iter()
+            .map(|row| row.depth)
+            .max()
+            .unwrap_or(0);
+        let current = self.tree_max_depth.unwrap_or(deepest);
+        self.tree_max_depth = if deeper {
+            Some(current + 1)
+        } else {
+            Some(current.saturating_sub(1))
+        };
+        self.rebuild_tree_nodes();
+        let message = match self.tree_max_depth {
+            Some(depth) => format!("tree depth limited to {}", depth),
+            None => "tree depth unlimited".to_string(),
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
+    pub fn tree_show_own_metrics(&self) -> bool {
+        self.tree_show_own_metrics
+    }
+
+    fn toggle_tree_own_metrics(&mut self) {
+        self.tree_show_own_metrics = !self.tree_show_own_metrics;
+        let message = if self.tree_show_own_metrics {
+            "tree: showing each process's own cpu/mem"
+        } else {
+            "tree: showing subtree totals"
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
     pub fn is_info_pane_open(&self) -> bool {
         self.info_pane_open
     }
@@ -940,6 +2779,45 @@ impl App {
         self.needs_refresh = true;
     }
 
+    pub fn set_info_section_starts(&mut self, starts: Vec<(InfoSection, u16)>) {
+        self.info_section_starts = starts;
+    }
+
+    pub fn jump_to_info_section(&mut self, section: InfoSection) {
+        let Some(&(_, start)) = self
+            .info_section_starts
+            .iter()
+            .find(|(known, _)| *known == section)
+        else {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("info pane: no {} section", section.title()),
+            );
+            return;
+        };
+        self.info_pane_scroll = start;
+        self.set_status(StatusLevel::Info, format!("info pane: {}", section.title()));
+        self.needs_refresh = true;
+    }
+
+    /// moves to the next (or previous) section heading from the current scroll position.
+    pub fn jump_info_section(&mut self, forward: bool) {
+        let scroll = self.info_pane_scroll;
+        let target = if forward {
+            self.info_section_starts
+                .iter()
+                .find(|(_, start)| *start > scroll)
+        } else {
+            self.info_section_starts
+                .iter()
+                .rev()
+                .find(|(_, start)| *start < scroll)
+        };
+        if let Some(&(section, _)) = target {
+            self.jump_to_info_section(section);
+        }
+    }
+
     pub fn info_env_expanded(&self) -> bool {
         self.info_env_expanded
     }
@@ -996,6 +2874,32 @@ impl App {
         self.info_cgroups_expanded
     }
 
+    pub fn info_args_joined(&self) -> bool {
+        self.info_args_joined
+    }
+
+    pub fn toggle_info_args(&mut self) {
+        if !self.info_pane_open {
+            return;
+        }
+        self.info_args_joined = !self.info_args_joined;
+        self.needs_refresh = true;
+    }
+
+    pub fn info_path_display(&self) -> PathDisplay {
+        self.info_path_display
+    }
+
+    pub fn cycle_info_path_display(&mut self) {
+        if !self.info_pane_open {
+            return;
+        }
+        self.info_path_display = self.info_path_display.next();
+        let message = format!("info paths: {}", self.info_path_display.label());
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
     pub fn toggle_info_cgroups(&mut self) {
         if !self.info_pane_open {
             return;
@@ -1005,20 +2909,111 @@ impl App {
         self.needs_refresh = true;
     }
 
+    pub fn info_maps_limit(&self) -> usize {
+        paged_limit(self.info_max_maps, self.info_pages)
+    }
+
+    pub fn info_open_files_limit(&self) -> usize {
+        paged_limit(self.info_max_open_files, self.info_pages)
+    }
+
+    pub fn info_environment_limit(&self) -> usize {
+        paged_limit(self.info_max_environment, self.info_pages)
+    }
+
+    pub fn info_env_entry_max(&self) -> usize {
+        self.info_env_entry_max
+    }
+
+    pub fn load_more_info_entries(&mut self) {
+        if !self.info_pane_open {
+            return;
+        }
+        self.info_pages = self.info_pages.saturating_add(1);
+        self.needs_refresh = true;
+    }
+
+    pub fn env_reference(&self) -> Option<&EnvReference> {
+        self.env_reference.as_ref()
+    }
+
+    /// whether the reference process still exists; its snapshot outlives it.
+    pub fn env_reference_alive(&self) -> bool {
+        self.env_reference
+            .as_ref()
+            .is_some_and(|reference| self.processes.iter().any(|proc| proc.pid == reference.pid))
+    }
+
+    pub fn toggle_env_reference(&mut self) {
+        if let Some(reference) = self.env_reference.take() {
+            let message = format!("cleared environment reference PID {}", reference.pid);
+            self.set_status(StatusLevel::Info, message);
+            return;
+        }
+        let Some(pid) = self.info_pid() else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        let Some(proc) = self.processes.iter().find(|proc| proc.pid == pid) else {
+            self.set_status(StatusLevel::Warning, "process disappeared");
+            return;
+        };
+        if proc.environment.is_empty() {
+            self.set_status(
+                StatusLevel::Warning,
+                "environment unavailable for this process",
+            );
+            return;
+        }
+        let message = format!("PID {} is the environment reference (= to clear)", proc.pid);
+        self.env_reference = Some(EnvReference {
+            pid: proc.pid,
+            name: proc.name.clone(),
+            environment: proc.environment.clone(),
+        });
+        self.set_status(StatusLevel::Info, message);
+    }
+
+    /// the process the info pane describes: the tree selection while the tree is open.
+    pub fn info_pid(&self) -> Option<u32> {
+        if self.tree_view_open {
+            self.tree_selected_pid()
+        } else {
+            self.current_pid()
+        }
+    }
+
     pub fn process_details(&mut self) -> Option<&ProcessDetails> {
-        let pid = self.current_pid()?;
+        let pid = self.info_pid()?;
         if !self.info_pane_open {
             return None;
         }
+        if self.info_pages_pid != Some(pid) {
+            self.info_pages_pid = Some(pid);
+            self.info_pages = 1;
+        }
 
+        if self
+            .info_exited
+            .as_ref()
+            .is_some_and(|(gone, _)| *gone == pid)
+        {
+            return None;
+        }
         let cached_pid = self.info_details_cache.as_ref().map(|(cached, _)| *cached);
         if cached_pid != Some(pid) {
-            match self.process_manager.get_details(pid) {
+            match self.process_source.get_details(pid) {
                 Some(details) => {
                     self.info_details_cache = Some((pid, details));
                 }
                 None => {
+                    // still listed from the last refresh, but gone by the time we looked;
+                    // remembered so every redraw doesn't ask again.
+                    let name = self
+                        .process_name_for_pid(pid)
+                        .unwrap_or_else(|| format!("PID {pid}"));
                     self.info_details_cache = None;
+                    self.info_exited = Some((pid, name));
                     return None;
                 }
             }
@@ -1027,6 +3022,13 @@ impl App {
         self.info_details_cache.as_ref().map(|(_, details)| details)
     }
 
+    /// the info pane target that exited before its details could be read.
+    pub fn info_exited(&self) -> Option<(u32, &str)> {
+        self.info_exited
+            .as_ref()
+            .map(|(pid, name)| (*pid, name.as_str()))
+    }
+
     fn process_name_for_pid(&self, pid: u32) -> Option<String> {
         self.processes
             .iter()
@@ -1040,24 +3042,79 @@ impl App {
             })
     }
 
+    /// the signals in display order; menu indices point into this, not `Signal::all()`.
+    pub fn signal_menu_signals(&self) -> Vec<Signal> {
+        let mut signals = Signal::all().to_vec();
+        if self.signal_order == SignalOrder::Name {
+            signals.sort_by_key(|signal| signal.name());
+        }
+        signals
+    }
+
+    pub fn tree_connectors(&self) -> TreeConnectors {
+        self.tree_connectors
+    }
+
+    pub fn enter_action(&self) -> EnterAction {
+        self.enter_action
+    }
+
+    pub fn signal_order(&self) -> SignalOrder {
+        self.signal_order
+    }
+
+    /// flips the menu order, keeping the highlighted signal highlighted.
+    fn toggle_signal_order(&mut self) {
+        let highlighted = self
+            .signal_menu_signals()
+            .get(self.signal_menu_selected)
+            .copied();
+        self.signal_order = self.signal_order.toggle();
+        if let Some(signal) = highlighted {
+            self.signal_menu_selected = self
+                .signal_menu_signals()
+                .iter()
+                .position(|sig| *sig == signal)
+                .unwrap_or(0);
+        }
+        let message = match self.signal_order {
+            SignalOrder::Number => "signals ordered by number",
+            SignalOrder::Name => "signals ordered by name",
+        };
+        self.set_status(StatusLevel::Info, message);
+        self.needs_refresh = true;
+    }
+
     fn open_signal_menu(&mut self, target: Option<u32>) {
         self.signal_menu_open = true;
         self.signal_menu_target = target;
-        if let Some(default_idx) = Signal::all()
+        self.signal_menu_target_kind = target.map_or(TargetKind::Unknown, TargetKind::of);
+        self.signal_menu_tracer = target.and_then(|pid| self.tracer_of(pid));
+        let signals = self.signal_menu_signals();
+        self.signal_menu_selected = signals
             .iter()
             .position(|sig| matches!(sig, Signal::Sigterm))
-        {
-            self.signal_menu_selected = default_idx;
-        } else if self.signal_menu_selected >= Signal::all().len() {
-            self.signal_menu_selected = 0;
-        }
+            .unwrap_or(0);
         self.signal_menu_scroll_offset = 0;
+        self.signal_menu_digits.clear();
+        self.signal_value_entry = None;
         self.set_mode(AppMode::SignalMenu);
         self.needs_refresh = true;
     }
 
+    /// the debugger attached to `pid` and its name, when one is.
+    fn tracer_of(&self, pid: u32) -> Option<(u32, String)> {
+        read_tracer_pid(pid).map(|tracer| {
+            let name = self
+                .process_snapshot(tracer)
+                .map_or_else(|| "unknown".to_string(), |proc| proc.name);
+            (tracer, name)
+        })
+    }
+
     fn close_signal_menu(&mut self) {
         self.signal_menu_open = false;
+        self.signal_value_entry = None;
         self.signal_menu_scroll_offset = 0;
         self.signal_menu_target = None;
         if self.tree_view_open {
@@ -1095,6 +3152,9 @@ impl App {
             self.mode_before_popup = Some(self.mode);
         }
         self.help_popup_open = true;
+        self.help_filter.clear();
+        self.help_filter_editing = false;
+        self.help_scroll = 0;
         self.refresh_pause_state();
         self.needs_refresh = true;
     }
@@ -1103,76 +3163,501 @@ impl App {
         if !self.help_popup_open {
             return;
         }
-        self.help_popup_open = false;
-        self.restore_mode_after_overlay();
+        self.help_popup_open = false;
+        self.restore_mode_after_overlay();
+    }
+
+    fn restore_mode_after_overlay(&mut self) {
+        if self.history_popup_open {
+            self.set_mode(AppMode::HistoryView);
+            return;
+        }
+
+        if self.shell_confirm.is_some() {
+            self.refresh_pause_state();
+            self.needs_refresh = true;
+            return;
+        }
+
+        if self.tree_view_open {
+            self.set_mode(AppMode::TreeView);
+            self.mode_before_popup = None;
+            return;
+        }
+
+        if self.info_pane_open && !matches!(self.mode, AppMode::Search | AppMode::SignalMenu) {
+            self.set_mode(AppMode::InfoPane);
+            self.mode_before_popup = None;
+        } else if let Some(previous) = self.mode_before_popup.take() {
+            self.set_mode(previous);
+        } else if !matches!(self.mode, AppMode::Search | AppMode::SignalMenu) {
+            self.set_mode(AppMode::Normal);
+        } else {
+            self.refresh_pause_state();
+            self.needs_refresh = true;
+        }
+    }
+
+    fn send_signal_from_menu(&mut self, signal: Signal) {
+        if self.kill_cooling_down() {
+            return;
+        }
+        let target = self.signal_menu_target.or_else(|| {
+            if self.tree_view_open {
+                self.tree_selected_pid()
+            } else {
+                self.current_pid()
+            }
+        });
+
+        let Some(pid) = target else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            self.close_signal_menu();
+            return;
+        };
+        let executed =
+            self.dispatch_signal_targets(PendingKill::new(vec![pid], signal, KillMode::Direct));
+        self.close_signal_menu();
+        if executed {
+            self.invalidate_process_details();
+        }
+    }
+
+    fn handle_history_popup_input(&mut self, _event: KeyEvent) -> Result<bool> {
+        self.close_history_popup();
+        Ok(false)
+    }
+
+    fn handle_help_popup_input(&mut self, event: KeyEvent) -> Result<bool> {
+        if self.help_filter_editing {
+            match event.code {
+                KeyCode::Esc => {
+                    self.help_filter.clear();
+                    self.help_filter_editing = false;
+                }
+                KeyCode::Enter => self.help_filter_editing = false,
+                KeyCode::Backspace => {
+                    self.help_filter.pop();
+                }
+                KeyCode::Char(c) => self.help_filter.push(c),
+                _ => {}
+            }
+            self.help_scroll = 0;
+            return Ok(false);
+        }
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => self.close_help_popup(),
+            KeyCode::Char('/') => {
+                self.help_filter_editing = true;
+                self.help_scroll = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_help(1),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_help(-1),
+            KeyCode::PageDown => self.scroll_help(10),
+            KeyCode::PageUp => self.scroll_help(-10),
+            KeyCode::Char('g') | KeyCode::Home => self.help_scroll = 0,
+            KeyCode::Char('G') | KeyCode::End => self.help_scroll = self.help_max_scroll,
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn scroll_help(&mut self, delta: isize) {
+        self.help_scroll = self
+            .help_scroll
+            .saturating_add_signed(delta)
+            .min(self.help_max_scroll);
+    }
+
+    pub fn help_filter(&self) -> &str {
+        &self.help_filter
+    }
+
+    pub fn help_filter_editing(&self) -> bool {
+        self.help_filter_editing
+    }
+
+    pub fn help_entries(&self) -> Vec<HelpEntry> {
+        help::filtered(&self.help_filter)
+    }
+
+    pub fn help_scroll(&self) -> usize {
+        self.help_scroll
+    }
+
+    /// called by the renderer once it knows how many lines fit.
+    pub fn set_help_max_scroll(&mut self, max_scroll: usize) {
+        self.help_max_scroll = max_scroll;
+        self.help_scroll = self.help_scroll.min(max_scroll);
+    }
+
+    /// re-reads the config file. `[safety]` is applied first and on its own, so a broken
+    /// display or views section can't switch the guards off; if `[safety]` itself fails to
+    /// parse the previous guards stay. the rest changes only if everything else parses.
+    fn load_config_file(&mut self) -> Result<(), String> {
+        let Some(path) = self.config_file.clone() else {
+            return Ok(());
+        };
+        let safety = protect::load(&path)?;
+        let mut protected = safety.protected;
+        protected.extend(self.base_config.protected.iter().cloned());
+        self.protected = protected;
+        self.safe_mode = self.base_config.safe_mode || safety.safe_mode;
+
+        let display = settings::load(&path)?;
+        let info = settings::load_info(&path)?;
+        let saved = views::load(&path)?;
+        let mut config = self.base_config.clone();
+        display.apply(&mut config);
+        self.base_config.cli_display.apply(&mut config);
+        info.apply(&mut config);
+        self.base_config.cli_info.apply(&mut config);
+        self.theme = config.theme;
+        self.selection_style = config.selection_style;
+        self.signal_order = config.signal_order;
+        self.tree_connectors = config.tree_style.connectors();
+        self.enter_action = config.enter_action;
+        self.memory_thresholds = config.memory_thresholds;
+        self.swap_warn_bytes = config.swap_warn_bytes;
+        self.runtime_colors = config.runtime_colors;
+        self.mouse = config.mouse;
+        self.info_max_maps = config.info_max_maps;
+        self.info_max_open_files = config.info_max_open_files;
+        self.info_max_environment = config.info_max_environment;
+        if config.columns != self.columns {
+            self.columns = config.columns;
+            self.locked_widths = None;
+            self.measure_widths = self.base_config.lock_widths;
+        }
+        self.saved_views = saved;
+        Ok(())
+    }
+
+    pub fn reload_config(&mut self) {
+        if self.config_file.is_none() {
+            self.set_status(StatusLevel::Warning, "no config file to reload");
+            return;
+        }
+        match self.load_config_file() {
+            Ok(()) => self.set_status(StatusLevel::Info, "config reloaded"),
+            Err(err) => self.set_status(StatusLevel::Error, err),
+        }
+        self.needs_refresh = true;
+    }
+
+    pub fn saved_views(&self) -> &[SavedView] {
+        &self.saved_views
+    }
+
+    pub fn views_popup_open(&self) -> bool {
+        self.views_popup_open
+    }
+
+    pub fn views_selected(&self) -> usize {
+        self.views_selected
+    }
+
+    fn open_views_popup(&mut self) {
+        if self.saved_views.is_empty() {
+            self.set_status(
+                StatusLevel::Info,
+                "no saved views; press V to save the current filter",
+            );
+            return;
+        }
+        self.views_popup_open = true;
+        self.views_selected = self
+            .saved_views
+            .iter()
+            .position(|view| view.query == self.search_query)
+            .unwrap_or(0);
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    fn close_views_popup(&mut self) {
+        self.views_popup_open = false;
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    fn handle_views_popup_input(&mut self, event: KeyEvent) -> Result<bool> {
+        let count = self.saved_views.len();
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('v') | KeyCode::Char('q') => self.close_views_popup(),
+            KeyCode::Up | KeyCode::Char('k') if count > 0 => {
+                self.views_selected = (self.views_selected + count - 1) % count;
+            }
+            KeyCode::Down | KeyCode::Char('j') if count > 0 => {
+                self.views_selected = (self.views_selected + 1) % count;
+            }
+            KeyCode::Enter => {
+                if let Some(view) = self.saved_views.get(self.views_selected).cloned() {
+                    self.close_views_popup();
+                    self.search_query = view.query.clone();
+                    self.apply_filters();
+                    self.set_status(
+                        StatusLevel::Info,
+                        format!("view '{}': {}", view.name, view.query),
+                    );
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Delete if self.views_selected < count => {
+                let removed = self.saved_views.remove(self.views_selected);
+                self.views_selected = self
+                    .views_selected
+                    .min(self.saved_views.len().saturating_sub(1));
+                self.persist_views(format!("deleted view '{}'", removed.name));
+                if self.saved_views.is_empty() {
+                    self.close_views_popup();
+                }
+            }
+            _ => {}
+        }
+        self.needs_refresh = true;
+        Ok(false)
+    }
+
+    pub fn users_popup_open(&self) -> bool {
+        self.users_popup_open
+    }
+
+    pub fn users_selected(&self) -> usize {
+        self.users_selected
+    }
+
+    pub fn users_scroll_offset(&self) -> usize {
+        self.users_scroll_offset
+    }
+
+    pub fn set_users_scroll_offset(&mut self, offset: usize) {
+        self.users_scroll_offset = offset;
+    }
+
+    pub fn users_sort(&self) -> UserSort {
+        self.users_sort
+    }
+
+    /// every listed process grouped by owner, so it follows `--all` but not the filter.
+    pub fn user_summaries(&self) -> Vec<UserSummary> {
+        let mut by_user: HashMap<&str, UserSummary> = HashMap::new();
+        for proc in &self.processes {
+            let summary = by_user
+                .entry(proc.user.as_str())
+                .or_insert_with(|| UserSummary {
+                    user: proc.user.clone(),
+                    processes: 0,
+                    cpu_percent: 0.0,
+                    memory_bytes: 0,
+                });
+            summary.processes += 1;
+            summary.cpu_percent += proc.cpu_percent;
+            summary.memory_bytes += proc.memory_bytes;
+        }
+        let mut summaries: Vec<UserSummary> = by_user.into_values().collect();
+        summaries.sort_by(|a, b| {
+            let order = match self.users_sort {
+                UserSort::Count => b.processes.cmp(&a.processes),
+                UserSort::Cpu => b.cpu_percent.total_cmp(&a.cpu_percent),
+                UserSort::Memory => b.memory_bytes.cmp(&a.memory_bytes),
+            };
+            order.then_with(|| a.user.cmp(&b.user))
+        });
+        summaries
+    }
+
+    fn open_users_popup(&mut self) {
+        self.users_popup_open = true;
+        self.users_selected = 0;
+        self.users_scroll_offset = 0;
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    fn close_users_popup(&mut self) {
+        self.users_popup_open = false;
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    fn handle_users_popup_input(&mut self, event: KeyEvent) -> Result<bool> {
+        let summaries = self.user_summaries();
+        let count = summaries.len();
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('U') | KeyCode::Char('q') => self.close_users_popup(),
+            KeyCode::Up | KeyCode::Char('k') if count > 0 => {
+                self.users_selected = (self.users_selected + count - 1) % count;
+            }
+            KeyCode::Down | KeyCode::Char('j') if count > 0 => {
+                self.users_selected = (self.users_selected + 1) % count;
+            }
+            KeyCode::Char('s') => {
+                self.users_sort = self.users_sort.next();
+                self.users_selected = 0;
+                self.users_scroll_offset = 0;
+            }
+            KeyCode::Enter => {
+                if let Some(summary) = summaries.get(self.users_selected) {
+                    self.close_users_popup();
+                    self.search_query = format!("user:{}", summary.user);
+                    self.apply_filters();
+                    self.set_status(
+                        StatusLevel::Info,
+                        format!("showing processes of {}", summary.user),
+                    );
+                }
+            }
+            _ => {}
+        }
+        self.needs_refresh = true;
+        Ok(false)
+    }
+
+    fn start_view_name_entry(&mut self) {
+        if self.search_query.trim().is_empty() {
+            self.set_status(StatusLevel::Warning, "nothing to save: the filter is empty");
+            return;
+        }
+        self.view_name_entry = Some(String::new());
+        self.set_status(StatusLevel::Info, "save view as: (Enter save, Esc cancel)");
     }
 
-    fn restore_mode_after_overlay(&mut self) {
-        if self.history_popup_open {
-            self.set_mode(AppMode::HistoryView);
-            return;
+    fn handle_view_name_input(&mut self, event: KeyEvent) -> bool {
+        let Some(buffer) = self.view_name_entry.as_mut() else {
+            return false;
+        };
+
+        match event.code {
+            KeyCode::Char(c) if c.is_ascii_alphanumeric() || c == '-' || c == '_' => {
+                buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Enter => {
+                let name = self.view_name_entry.take().unwrap_or_default();
+                if !views::is_valid_name(&name) {
+                    self.set_status(StatusLevel::Warning, "view name cannot be empty");
+                    return true;
+                }
+                let query = self.search_query.trim().to_string();
+                self.saved_views.retain(|view| view.name != name);
+                self.saved_views.push(SavedView {
+                    name: name.clone(),
+                    query: query.clone(),
+                });
+                self.persist_views(format!("saved view '{}' = {}", name, query));
+                return true;
+            }
+            KeyCode::Esc => {
+                self.view_name_entry = None;
+                self.set_status(StatusLevel::Info, "cancelled saving view");
+                return true;
+            }
+            _ => return true,
         }
+        let message = format!(
+            "save view as: {} (Enter save, Esc cancel)",
+            self.view_name_entry.as_deref().unwrap_or_default()
+        );
+        self.set_status(StatusLevel::Info, message);
+        true
+    }
 
-        if self.shell_confirm.is_some() {
-            self.refresh_pause_state();
-            self.needs_refresh = true;
-            return;
+    fn start_pid_list_entry(&mut self) {
+        self.pid_list_entry = Some(String::new());
+        self.set_status(
+            StatusLevel::Info,
+            "select PIDs: (type or paste, Enter select, Esc cancel)",
+        );
+    }
+
+    fn handle_pid_list_input(&mut self, event: KeyEvent) -> bool {
+        let Some(buffer) = self.pid_list_entry.as_mut() else {
+            return false;
+        };
+
+        match event.code {
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ' ' || c == ',' => buffer.push(c),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Enter => {
+                let text = self.pid_list_entry.take().unwrap_or_default();
+                self.select_pid_list(&text);
+                return true;
+            }
+            KeyCode::Esc => {
+                self.pid_list_entry = None;
+                self.set_status(StatusLevel::Info, "cancelled PID selection");
+                return true;
+            }
+            _ => return true,
         }
+        let message = format!(
+            "select PIDs: {} (Enter select, Esc cancel)",
+            self.pid_list_entry.as_deref().unwrap_or_default()
+        );
+        self.set_status(StatusLevel::Info, message);
+        true
+    }
 
-        if self.tree_view_open {
-            self.set_mode(AppMode::TreeView);
-            self.mode_before_popup = None;
+    /// replaces the selection with the listed pids that are still running.
+    fn select_pid_list(&mut self, text: &str) {
+        let requested: Vec<u32> = text
+            .split([' ', ','])
+            .filter_map(|pid| pid.parse().ok())
+            .collect();
+        if requested.is_empty() {
+            self.set_status(StatusLevel::Warning, "no PIDs given");
             return;
         }
+        let running: HashSet<u32> = self.processes.iter().map(|proc| proc.pid).collect();
+        let (found, missing): (Vec<u32>, Vec<u32>) =
+            requested.into_iter().partition(|pid| running.contains(pid));
+        self.selected_pids = found.into_iter().collect();
+        self.needs_refresh = true;
 
-        if self.info_pane_open && !matches!(self.mode, AppMode::Search | AppMode::SignalMenu) {
-            self.set_mode(AppMode::InfoPane);
-            self.mode_before_popup = None;
-        } else if let Some(previous) = self.mode_before_popup.take() {
-            self.set_mode(previous);
-        } else if !matches!(self.mode, AppMode::Search | AppMode::SignalMenu) {
-            self.set_mode(AppMode::Normal);
+        let count = self.selected_pids.len();
+        let selected = match count {
+            1 => "1 process selected".to_string(),
+            _ => format!("{count} processes selected"),
+        };
+        if missing.is_empty() {
+            self.set_status(StatusLevel::Info, selected);
         } else {
-            self.refresh_pause_state();
-            self.needs_refresh = true;
+            let missing: Vec<String> = missing.iter().map(|pid| pid.to_string()).collect();
+            self.set_status(
+                StatusLevel::Warning,
+                format!("{selected}; not running: {}", missing.join(" ")),
+            );
         }
     }
 
-    fn send_signal_from_menu(&mut self, signal: Signal) {
-        let target = self.signal_menu_target.or_else(|| {
-            if self.tree_view_open {
-                self.tree_selected_pid()
-            } else {
-                self.current_pid()
-            }
-        });
-
-        let Some(pid) = target else {
-            self.set_status(StatusLevel::Warning, "no process selected");
-            self.close_signal_menu();
+    fn persist_views(&mut self, success: String) {
+        let Some(path) = self.config_file.clone() else {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("{} (no config file location; not persisted)", success),
+            );
             return;
         };
-        let executed = self.dispatch_signal_targets(vec![pid], signal, KillMode::Direct, false);
-        self.close_signal_menu();
-        if executed {
-            self.invalidate_process_details();
+        match views::save(&path, &self.saved_views) {
+            Ok(()) => self.set_status(StatusLevel::Info, success),
+            Err(err) => self.set_status(StatusLevel::Error, err),
         }
     }
 
-    fn handle_history_popup_input(&mut self, _event: KeyEvent) -> Result<bool> {
-        self.close_history_popup();
-        Ok(false)
-    }
-
-    fn handle_help_popup_input(&mut self, _event: KeyEvent) -> Result<bool> {
-        self.close_help_popup();
-        Ok(false)
-    }
-
-    pub fn toggle_tree_view(&mut self) {
+    /// opens the tree (rooted at `root` when given) or closes it.
+    pub fn toggle_tree_view(&mut self, root: Option<u32>) {
         self.tree_view_open = !self.tree_view_open;
+        self.tree_root = root;
         if self.tree_view_open {
-            self.info_pane_open = false;
+            self.restore_info_pane = self.info_pane_open && !self.tree_with_info;
+            if !self.tree_with_info {
+                self.info_pane_open = false;
+            }
             self.info_focus = false;
             self.tree_collapsed.clear();
             self.tree_rows.clear();
@@ -1186,13 +3671,40 @@ impl App {
             self.tree_rows.clear();
             self.tree_collapsed.clear();
             self.tree_scroll_offset = 0;
-            self.set_mode(AppMode::Normal);
+            if std::mem::take(&mut self.restore_info_pane) {
+                self.info_pane_open = true;
+            }
+            self.invalidate_process_details();
+            if self.info_pane_open {
+                self.set_mode(AppMode::InfoPane);
+            } else {
+                self.set_mode(AppMode::Normal);
+            }
+        }
+        self.needs_refresh = true;
+    }
+
+    pub fn tree_root(&self) -> Option<u32> {
+        self.tree_root
+    }
+
+    fn set_tree_root(&mut self, root: Option<u32>) {
+        if self.tree_root == root {
+            return;
         }
+        self.tree_root = root;
+        self.tree_scroll_offset = 0;
+        self.rebuild_tree_nodes();
+        let message = match self.tree_root {
+            Some(pid) => format!("tree anchored at PID {} (Z to zoom out)", pid),
+            None => "showing the full process tree".to_string(),
+        };
+        self.set_status(StatusLevel::Info, message);
         self.needs_refresh = true;
     }
 
     fn handle_tree_input(&mut self, event: KeyEvent) -> Result<bool> {
-        if let Some(_) = self.tree_kill_prompt {
+        if self.tree_kill_prompt.is_some() {
             match event.code {
                 KeyCode::Char('y') => {
                     self.tree_kill_preview_confirm(true);
@@ -1206,13 +3718,17 @@ impl App {
             return Ok(false);
         }
 
+        if self.handle_pending_z(event) {
+            return Ok(false);
+        }
+
         match event.code {
             KeyCode::Char('q') => return Ok(true),
             KeyCode::Char('t') | KeyCode::Esc => {
-                self.toggle_tree_view();
+                self.toggle_tree_view(None);
             }
             KeyCode::Char('/') => {
-                self.toggle_tree_view();
+                self.toggle_tree_view(None);
                 self.set_mode(AppMode::Search);
             }
             KeyCode::Char('s') => {
@@ -1223,6 +3739,23 @@ impl App {
                 self.toggle_tree_collapse();
             }
             KeyCode::Char('x') => self.open_tree_kill_prompt(),
+            KeyCode::Char('p') => self.toggle_tree_job_ids(),
+            KeyCode::Char('r') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.reload_config();
+            }
+            KeyCode::Char('o') => self.toggle_tree_own_metrics(),
+            KeyCode::Char('i') if self.tree_with_info => self.toggle_info_pane(),
+            KeyCode::Char('[') => self.change_tree_depth(false),
+            KeyCode::Char(']') => self.change_tree_depth(true),
+            KeyCode::Char('l') => self.toggle_tree_hide_idle(),
+            KeyCode::Char('T') => {
+                let root = self.tree_selected_pid();
+                self.set_tree_root(root);
+            }
+            KeyCode::Char('Z') => self.set_tree_root(None),
+            KeyCode::Char('z') => self.pending_z = true,
+            KeyCode::Char('P') => self.tree_jump_to_relative(true),
+            KeyCode::Char('C') => self.tree_jump_to_relative(false),
             KeyCode::Char('h') => self.open_history_popup(),
             KeyCode::Char('?') => self.open_help_popup(),
             KeyCode::Char('j') => self.tree_select_next(),
@@ -1267,6 +3800,44 @@ impl App {
         self.needs_refresh = true;
     }
 
+    fn tree_jump_to_relative(&mut self, to_parent: bool) {
+        let Some(row) = self.tree_rows.get(self.tree_selected_index).cloned() else {
+            return;
+        };
+        let target = if to_parent {
+            row.parent_pid.and_then(|parent| {
+                self.tree_rows[..self.tree_selected_index]
+                    .iter()
+                    .rposition(|candidate| candidate.pid == parent)
+            })
+        } else {
+            self.tree_rows
+                .get(self.tree_selected_index + 1)
+                .filter(|next| next.depth == row.depth + 1)
+                .map(|_| self.tree_selected_index + 1)
+        };
+
+        match target {
+            Some(idx) => self.tree_selected_index = idx,
+            None if to_parent => {
+                let message = match row.parent_pid {
+                    Some(parent) => format!("parent PID {} is not in the tree view", parent),
+                    None => format!("PID {} has no parent", row.pid),
+                };
+                self.set_status(StatusLevel::Info, message);
+            }
+            None => {
+                let message = if row.has_children {
+                    format!("children of PID {} are folded", row.pid)
+                } else {
+                    format!("PID {} has no children", row.pid)
+                };
+                self.set_status(StatusLevel::Info, message);
+            }
+        }
+        self.needs_refresh = true;
+    }
+
     fn tree_select_top(&mut self) {
         if self.tree_rows.is_empty() {
             return;
@@ -1310,8 +3881,8 @@ impl App {
         };
 
         self.tree_kill_prompt = None;
-        let executed =
-            self.dispatch_signal_targets(vec![prompt.pid], prompt.signal, KillMode::Tree, true);
+        let pending = PendingKill::new(vec![prompt.pid], prompt.signal, KillMode::Tree);
+        let executed = self.dispatch_signal_targets(pending.shell_confirmed());
         if executed && self.tree_view_open {
             self.rebuild_tree_nodes();
         }
@@ -1326,11 +3897,36 @@ impl App {
             self.set_status(StatusLevel::Warning, "no processes in subtree");
             return;
         }
+        // a risky descendant needs the preview as much as a risky root does.
+        let everyone = self.process_source.get_processes(true);
+        let risk = first_affected(&everyone, &[pid], KillMode::Tree, |proc| {
+            self.assess_risk(proc)
+        })
+        .map(|(found, risk)| {
+            if found == pid {
+                risk
+            } else {
+                RiskInfo {
+                    reason: format!("PID {found}: {}", risk.reason),
+                    ..risk
+                }
+            }
+        });
+        let tracer = self.tracer_of(pid);
+        if lines.len() < self.tree_confirm_min && risk.is_none() && tracer.is_none() {
+            let pending = PendingKill::new(vec![pid], Signal::Sigterm, KillMode::Tree);
+            let executed = self.dispatch_signal_targets(pending);
+            if executed && self.tree_view_open {
+                self.rebuild_tree_nodes();
+            }
+            return;
+        }
         self.tree_kill_prompt = Some(TreeKillPrompt {
             pid,
             signal: Signal::Sigterm,
             lines,
-            risk: self.risk_for_pid(pid),
+            risk,
+            tracer,
         });
         self.needs_refresh = true;
     }
@@ -1340,8 +3936,24 @@ impl App {
             return;
         }
 
-        let processes = self.process_manager.get_processes(true);
-        let map: HashMap<u32, ProcessInfo> = processes.into_iter().map(|p| (p.pid, p)).collect();
+        let mut processes = match self.tree_root {
+            Some(root) => self.process_source.get_process_tree(root),
+            None => self.process_source.get_processes(true),
+        };
+        if processes.is_empty()
+            && let Some(root) = self.tree_root.take()
+        {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("PID {} exited; showing the full process tree", root),
+            );
+            processes = self.process_source.get_processes(true);
+        }
+        let map: HashMap<u32, ProcessInfo> = processes
+            .into_iter()
+            .filter(|p| p.thread_owner.is_none())
+            .map(|p| (p.pid, p))
+            .collect();
 
         self.tree_collapsed.retain(|pid| map.contains_key(pid));
 
@@ -1387,18 +3999,45 @@ impl App {
 
         let mut branch_stack = Vec::new();
         let mut visited = HashSet::new();
+        let mut hidden = 0;
 
         for root_pid in roots.iter() {
-            branch_stack.clear();
-            let _ =
-                self.flatten_tree_node(*root_pid, &mut branch_stack, &map, &children, &mut rows);
             visited.insert(*root_pid);
+            if self.tree_branch_idle(*root_pid, &map, &children) {
+                hidden += 1 + count_descendants(*root_pid, &children);
+                continue;
+            }
+            branch_stack.clear();
+            let _ = self.flatten_tree_node(
+                *root_pid,
+                &mut branch_stack,
+                &map,
+                &children,
+                &mut rows,
+                &mut hidden,
+            );
         }
 
         for pid in map.keys() {
-            if !visited.contains(pid) {
+            if !visited.contains(pid) && !self.tree_branch_idle(*pid, &map, &children) {
                 branch_stack.clear();
-                let _ = self.flatten_tree_node(*pid, &mut branch_stack, &map, &children, &mut rows);
+                let _ = self.flatten_tree_node(
+                    *pid,
+                    &mut branch_stack,
+                    &map,
+                    &children,
+                    &mut rows,
+                    &mut hidden,
+                );
+            }
+        }
+        self.tree_hidden_count = hidden;
+
+        // once the limit reaches past the deepest branch it no longer hides anything.
+        if self.tree_max_depth.is_some() && rows.iter().all(|row| row.hidden_descendants == 0) {
+            let deepest = rows.iter().map(|row| row.depth).max().unwrap_or(0);
+            if self.tree_max_depth.is_some_and(|limit| limit > deepest) {
+                self.tree_max_depth = None;
             }
         }
 
@@ -1408,14 +4047,16 @@ impl App {
             .map(|row| row.pid);
         self.tree_rows = rows;
 
-        if let Some(pid) = previous_pid {
+        // keep the selection on the same process, or on its nearest visible
+        // ancestor when a collapse or depth change hid it.
+        let mut candidate = previous_pid;
+        self.tree_selected_index = 0;
+        while let Some(pid) = candidate {
             if let Some(idx) = self.tree_rows.iter().position(|row| row.pid == pid) {
                 self.tree_selected_index = idx;
-            } else {
-                self.tree_selected_index = 0;
+                break;
             }
-        } else {
-            self.tree_selected_index = 0;
+            candidate = map.get(&pid).and_then(|info| info.parent_pid);
         }
 
         if self.tree_rows.is_empty() {
@@ -1434,19 +4075,26 @@ impl App {
         map: &HashMap<u32, ProcessInfo>,
         children: &HashMap<u32, Vec<u32>>,
         rows: &mut Vec<TreeRow>,
+        hidden: &mut usize,
     ) -> (f32, u64) {
         let Some(info) = map.get(&pid) else {
             return (0.0, 0);
         };
 
         let depth = branch_stack.len();
-        let prefix = build_tree_prefix(branch_stack);
+        let prefix = build_tree_prefix(branch_stack, self.tree_connectors);
         let has_children = children.get(&pid).map(|v| !v.is_empty()).unwrap_or(false);
         let collapsed = self.tree_collapsed.contains(&pid);
+        let cut_off = has_children && self.tree_max_depth.is_some_and(|limit| depth >= limit);
 
         let mut total_cpu = info.cpu_percent;
         let mut total_mem = info.memory_bytes;
         let risk = self.assess_risk(info);
+        let job_ids = if self.tree_show_job_ids {
+            read_job_ids(pid)
+        } else {
+            None
+        };
 
         let row_index = rows.len();
         rows.push(TreeRow {
@@ -1462,20 +4110,43 @@ impl App {
             collapsed,
             prefix,
             risk,
+            job_ids,
+            hidden_descendants: 0,
         });
 
         if let Some(child_list) = children.get(&pid) {
-            if collapsed {
+            if cut_off && let Some(row) = rows.get_mut(row_index) {
+                row.hidden_descendants = count_descendants(pid, children);
+            }
+            if collapsed || cut_off {
                 for child_pid in child_list {
                     let (child_cpu, child_mem) = self.subtree_totals(*child_pid, map, children);
                     total_cpu += child_cpu;
                     total_mem += child_mem;
                 }
             } else {
-                for (idx, child_pid) in child_list.iter().enumerate() {
-                    branch_stack.push(idx + 1 == child_list.len());
-                    let (child_cpu, child_mem) =
-                        self.flatten_tree_node(*child_pid, branch_stack, map, children, rows);
+                // prune idle branches before drawing so the last-child connectors stay right.
+                let mut shown = Vec::with_capacity(child_list.len());
+                for child_pid in child_list {
+                    if self.tree_branch_idle(*child_pid, map, children) {
+                        let (child_cpu, child_mem) = self.subtree_totals(*child_pid, map, children);
+                        total_cpu += child_cpu;
+                        total_mem += child_mem;
+                        *hidden += 1 + count_descendants(*child_pid, children);
+                    } else {
+                        shown.push(*child_pid);
+                    }
+                }
+                for (idx, child_pid) in shown.iter().enumerate() {
+                    branch_stack.push(idx + 1 == shown.len());
+                    let (child_cpu, child_mem) = self.flatten_tree_node(
+                        *child_pid,
+                        branch_stack,
+                        map,
+                        children,
+                        rows,
+                        hidden,
+                    );
                     total_cpu += child_cpu;
                     total_mem += child_mem;
                     branch_stack.pop();
@@ -1513,7 +4184,7 @@ impl App {
     }
 
     fn build_tree_preview_lines(&mut self, pid: u32) -> Vec<String> {
-        let mut processes = self.process_manager.get_process_tree(pid);
+        let mut processes = self.process_source.get_process_tree(pid);
         if processes.is_empty() {
             processes = get_process_tree(pid);
         }
@@ -1526,10 +4197,10 @@ impl App {
         let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
 
         for info in map.values() {
-            if let Some(parent) = info.parent_pid {
-                if map.contains_key(&parent) {
-                    children.entry(parent).or_default().push(info.pid);
-                }
+            if let Some(parent) = info.parent_pid
+                && map.contains_key(&parent)
+            {
+                children.entry(parent).or_default().push(info.pid);
             }
         }
 
@@ -1563,7 +4234,7 @@ impl App {
             return;
         };
 
-        let prefix = build_tree_prefix(stack);
+        let prefix = build_tree_prefix(stack, self.tree_connectors);
         let mut line = format!(
             "{}[{}] {} [CPU: {:>5.1}%] [MEM: {}]",
             prefix,
@@ -1590,12 +4261,71 @@ impl App {
         }
     }
 
+    fn handle_signal_entry_input(&mut self, event: KeyEvent) -> bool {
+        let Some(buffer) = self.signal_entry.as_mut() else {
+            return false;
+        };
+
+        match event.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let message = match push_signal_digit(buffer, c) {
+                    Some(signal) => format!(
+                        "signal #{buffer} ({}) — Enter send, Esc cancel",
+                        signal.name()
+                    ),
+                    None => format!("signal #{buffer} — not a valid signal"),
+                };
+                self.set_status(StatusLevel::Info, message);
+            }
+            KeyCode::Backspace => {
+                buffer.pop();
+                let message = format!("signal #{buffer} — Enter send, Esc cancel");
+                self.set_status(StatusLevel::Info, message);
+            }
+            KeyCode::Enter => {
+                let entry = self.signal_entry.take().unwrap_or_default();
+                match entry.parse().ok().and_then(Signal::from_number) {
+                    Some(signal) => self.kill_selected(signal),
+                    None => {
+                        let message = format!("'{entry}' is not a valid signal number");
+                        self.set_status(StatusLevel::Warning, message);
+                    }
+                }
+            }
+            _ => {
+                self.signal_entry = None;
+                self.set_status(StatusLevel::Info, "cancelled signal entry");
+            }
+        }
+        true
+    }
+
     fn handle_normal_input(&mut self, event: KeyEvent) -> Result<bool> {
+        if self.handle_signal_entry_input(event)
+            || self.handle_view_name_input(event)
+            || self.handle_pid_list_input(event)
+            || self.handle_pending_z(event)
+        {
+            return Ok(false);
+        }
+
+        let suggestion = self.tree_kill_suggestion.take();
+        if let (Some(pid), KeyCode::Char('x')) = (suggestion, event.code) {
+            self.dispatch_signal_targets(PendingKill::new(
+                vec![pid],
+                Signal::Sigterm,
+                KillMode::Tree,
+            ));
+            return Ok(false);
+        }
+
         match event.code {
             KeyCode::Char('q') => return Ok(true),
             KeyCode::Esc => {
                 if self.is_info_pane_open() {
                     self.toggle_info_pane();
+                } else if !self.search_query.is_empty() || !self.filter_stack.is_empty() {
+                    self.clear_filter();
                 } else {
                     self.set_status(StatusLevel::Info, "Press q to quit or ? for help");
                     self.needs_refresh = true;
@@ -1612,10 +4342,8 @@ impl App {
             KeyCode::Char('i') => {
                 self.toggle_info_pane();
             }
-            KeyCode::Tab => {
-                if self.is_info_pane_open() {
-                    self.toggle_info_focus();
-                }
+            KeyCode::Tab if self.is_info_pane_open() => {
+                self.toggle_info_focus();
             }
             KeyCode::Char('e') | KeyCode::Char('E') if self.is_info_pane_open() => {
                 self.toggle_info_env();
@@ -1629,11 +4357,49 @@ impl App {
             KeyCode::Char('n') | KeyCode::Char('N') if self.is_info_pane_open() => {
                 self.toggle_info_network();
             }
-            KeyCode::Char('c') | KeyCode::Char('C') if self.is_info_pane_open() => {
-                self.toggle_info_cgroups();
+            // lowercase only: C jumps to a child even while the pane is open.
+            KeyCode::Char('c') if self.is_info_pane_open() => self.toggle_info_cgroups(),
+            KeyCode::Char('A') if self.is_info_pane_open() => self.toggle_info_args(),
+            KeyCode::Char(digit @ '1'..='9') if self.is_info_pane_open() => {
+                let index = digit as usize - '1' as usize;
+                self.jump_to_info_section(InfoSection::ALL[index]);
+            }
+            KeyCode::Char(']') if self.is_info_pane_open() => self.jump_info_section(true),
+            KeyCode::Char('[') if self.is_info_pane_open() => self.jump_info_section(false),
+            KeyCode::Char('p') if self.is_info_pane_open() => self.cycle_info_path_display(),
+            KeyCode::Char('=') => self.toggle_env_reference(),
+            KeyCode::Char('+') if self.is_info_pane_open() => {
+                self.load_more_info_entries();
+            }
+            KeyCode::Char('P') => self.jump_to_relative(true),
+            KeyCode::Char('C') => self.jump_to_relative(false),
+            KeyCode::Char('z') => self.pending_z = true,
+            KeyCode::Char('v') => self.open_views_popup(),
+            KeyCode::Char('B') => self.request_kill_and_blacklist(),
+            KeyCode::Char('X') => self.open_batch_kill_prompt(),
+            KeyCode::Char('b') => self.open_same_name_kill_prompt(),
+            KeyCode::Char('w') => self.open_cgroup_kill_prompt(),
+            KeyCode::Backspace => self.pop_filter_layer(),
+            KeyCode::Char('t') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_tty_kill_prompt();
+            }
+            KeyCode::Char('V') => self.start_view_name_entry(),
+            KeyCode::Char('l') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.clear_filter();
+            }
+            KeyCode::Char('r') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.reload_config();
+            }
+            KeyCode::Char('u') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_refresh_age();
             }
             KeyCode::Char('t') => {
-                self.toggle_tree_view();
+                self.toggle_tree_view(None);
+            }
+            KeyCode::Char('T') => {
+                if let Some(pid) = self.current_pid() {
+                    self.toggle_tree_view(Some(pid));
+                }
             }
             KeyCode::Char('s') => {
                 let target = if self.tree_view_open {
@@ -1685,7 +4451,56 @@ impl App {
                 self.open_help_popup();
             }
             KeyCode::Char(' ') => self.toggle_selection(),
-            KeyCode::Enter => self.kill_selected(Signal::Sigterm),
+            KeyCode::Char('*') => self.invert_selection(),
+            KeyCode::Char('a') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.select_all_filtered();
+            }
+            KeyCode::Char('u') => self.clear_selection(),
+            KeyCode::Char('y') => self.yank_kill_command(),
+            KeyCode::Char('O') => self.request_pager(),
+            KeyCode::Char('U') => self.open_users_popup(),
+            KeyCode::Char('Y') => self.copy_selected_pids(),
+            KeyCode::Char('L') => self.start_pid_list_entry(),
+            KeyCode::Char('H') => self.toggle_threads(),
+            KeyCode::Char('a') => self.toggle_show_all(),
+            KeyCode::Char('I') => self.toggle_hide_idle(),
+            KeyCode::Char('J') => self.jump_to_heaviest(false),
+            KeyCode::Char('R') => self.jump_to_heaviest(true),
+            KeyCode::Char('W') => self.remeasure_column_widths(),
+            KeyCode::Char('.') => self.cycle_cpu_precision(),
+            KeyCode::Char(',') => self.cycle_mem_precision(),
+            KeyCode::Char('D') => self.jump_to_disk_sleep(),
+            KeyCode::Char('f') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_match_style();
+            }
+            KeyCode::Char('d') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.debug_scores = !self.debug_scores;
+                let message = if self.debug_scores {
+                    "debug: showing search scores"
+                } else {
+                    "debug: hiding search scores"
+                };
+                self.set_status(StatusLevel::Info, message);
+            }
+            KeyCode::Char('S') => {
+                self.signal_entry = Some(String::new());
+                self.set_status(
+                    StatusLevel::Info,
+                    "signal #: type a number, Enter send, Esc cancel",
+                );
+            }
+            KeyCode::Enter => match self.enter_action {
+                EnterAction::Kill => self.kill_selected(Signal::Sigterm),
+                EnterAction::SignalMenu => {
+                    let target = if self.tree_view_open {
+                        self.tree_selected_pid()
+                    } else {
+                        self.current_pid()
+                    };
+                    self.open_signal_menu(target);
+                }
+                EnterAction::Info => self.toggle_info_pane(),
+            },
             KeyCode::Up => {
                 if self.is_info_pane_open() && self.info_focus() {
                     self.scroll_info_pane(-1);
@@ -1700,15 +4515,11 @@ impl App {
                     self.select_next();
                 }
             }
-            KeyCode::PageUp => {
-                if self.is_info_pane_open() && self.info_focus() {
-                    self.scroll_info_pane(-5);
-                }
+            KeyCode::PageUp if self.is_info_pane_open() && self.info_focus() => {
+                self.scroll_info_pane(-5);
             }
-            KeyCode::PageDown => {
-                if self.is_info_pane_open() && self.info_focus() {
-                    self.scroll_info_pane(5);
-                }
+            KeyCode::PageDown if self.is_info_pane_open() && self.info_focus() => {
+                self.scroll_info_pane(5);
             }
             _ => {}
         }
@@ -1725,9 +4536,19 @@ impl App {
                 self.flush_search_filters();
                 self.set_mode(AppMode::Normal);
             }
+            KeyCode::Char('f') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_match_style();
+            }
+            KeyCode::Char('e') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_case_sensitive();
+            }
+            KeyCode::Tab => self.push_filter_layer(),
             KeyCode::Backspace => {
                 if self.search_query.pop().is_some() {
                     self.mark_search_dirty();
+                    self.hint_signal_search();
+                } else if !self.filter_stack.is_empty() {
+                    self.pop_filter_layer();
                 } else {
                     self.needs_refresh = true;
                 }
@@ -1738,19 +4559,82 @@ impl App {
             {
                 self.search_query.push(c);
                 self.mark_search_dirty();
+                self.hint_signal_search();
             }
             _ => {}
         }
         Ok(false)
     }
 
+    pub fn advanced_signals(&self) -> bool {
+        self.advanced_signals
+    }
+
+    pub fn signal_value_entry(&self) -> Option<&str> {
+        self.signal_value_entry.as_deref()
+    }
+
+    fn handle_signal_value_input(&mut self, event: KeyEvent, signal: Signal) {
+        let Some(entry) = self.signal_value_entry.as_mut() else {
+            return;
+        };
+        match event.code {
+            KeyCode::Esc => self.signal_value_entry = None,
+            KeyCode::Backspace => {
+                entry.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || (c == '-' && entry.is_empty()) => {
+                entry.push(c);
+            }
+            KeyCode::Enter => match parse_signal_value(entry) {
+                Ok(value) => self.queue_signal_from_menu(signal, value),
+                Err(err) => self.set_status(StatusLevel::Warning, err),
+            },
+            _ => {}
+        }
+        self.needs_refresh = true;
+    }
+
+    fn queue_signal_from_menu(&mut self, signal: Signal, value: i32) {
+        if self.kill_cooling_down() {
+            return;
+        }
+        let target = self.signal_menu_target.or_else(|| {
+            if self.tree_view_open {
+                self.tree_selected_pid()
+            } else {
+                self.current_pid()
+            }
+        });
+        self.close_signal_menu();
+        let Some(pid) = target else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        self.dispatch_signal_targets(PendingKill::queued(pid, signal, value));
+    }
+
     fn handle_signal_menu_input(&mut self, event: KeyEvent) -> Result<bool> {
-        let signals = Signal::all();
+        let signals = self.signal_menu_signals();
+        if self.signal_value_entry.is_some() && !signals.is_empty() {
+            let index = self.signal_menu_selected.min(signals.len() - 1);
+            self.handle_signal_value_input(event, signals[index]);
+            return Ok(false);
+        }
         if signals.is_empty() {
-            self.close_signal_menu();
+            // keep the menu open so a transiently empty list doesn't make it vanish.
+            if event.code == KeyCode::Esc {
+                self.close_signal_menu();
+            }
+            self.signal_menu_selected = 0;
+            self.needs_refresh = true;
             return Ok(false);
         }
 
+        // typed digits only build a number while nothing else is pressed in between.
+        if !matches!(event.code, KeyCode::Char(c) if c.is_ascii_digit()) {
+            self.signal_menu_digits.clear();
+        }
         match event.code {
             KeyCode::Esc => {
                 self.close_signal_menu();
@@ -1772,12 +4656,29 @@ impl App {
                 let signal = signals[index];
                 self.send_signal_from_menu(signal);
             }
-            KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
-                let digit = c.to_digit(10).unwrap() as i32;
-                if let Some(idx) = signals.iter().position(|sig| sig.number() == digit) {
-                    self.signal_menu_selected = idx;
-                    self.needs_refresh = true;
+            KeyCode::Char('v') if self.advanced_signals => {
+                self.signal_value_entry = Some(String::new());
+                self.needs_refresh = true;
+            }
+            KeyCode::Char('o') => self.toggle_signal_order(),
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                match push_signal_digit(&mut self.signal_menu_digits, c) {
+                    Some(signal) => {
+                        if let Some(idx) = signals.iter().position(|sig| *sig == signal) {
+                            self.signal_menu_selected = idx;
+                        }
+                        if !signal_number_continues(&self.signal_menu_digits) {
+                            self.signal_menu_digits.clear();
+                        }
+                    }
+                    None => {
+                        let message =
+                            format!("signal #{} is not a valid signal", self.signal_menu_digits);
+                        self.signal_menu_digits.clear();
+                        self.set_status(StatusLevel::Warning, message);
+                    }
                 }
+                self.needs_refresh = true;
             }
             _ => {}
         }
@@ -1792,14 +4693,7 @@ impl App {
         match event.code {
             KeyCode::Char('y') | KeyCode::Char('Y') => {
                 if let Some(pending) = self.shell_confirm.take() {
-                    match pending {
-                        PendingKill::Direct { targets, signal } => {
-                            self.dispatch_signal_targets(targets, signal, KillMode::Direct, true);
-                        }
-                        PendingKill::Tree { targets, signal } => {
-                            self.dispatch_signal_targets(targets, signal, KillMode::Tree, true);
-                        }
-                    }
+                    self.dispatch_signal_targets(pending.shell_confirmed());
                 }
                 self.refresh_pause_state();
                 Ok(Some(false))
@@ -1822,12 +4716,371 @@ impl App {
         }
     }
 
+    pub fn batch_kill_prompt(&self) -> Option<&BatchKillPrompt> {
+        self.batch_kill_prompt.as_ref()
+    }
+
+    /// previews every process matching the active search before signalling them all.
+    fn open_batch_kill_prompt(&mut self) {
+        let query = self.layered_query();
+        if query.is_empty() {
+            self.set_status(
+                StatusLevel::Warning,
+                "search first: X signals every process matching the filter",
+            );
+            return;
+        }
+        let targets: Vec<BatchTarget> = self
+            .filtered_processes
+            .iter()
+            .filter(|proc| proc.thread_owner.is_none())
+            .map(|proc| BatchTarget {
+                pid: proc.pid,
+                name: proc.name.clone(),
+                risk: self.assess_risk(proc),
+                tracer: self.tracer_of(proc.pid),
+            })
+            .collect();
+        if targets.is_empty() {
+            self.set_status(StatusLevel::Warning, "no processes match the filter");
+            return;
+        }
+        self.batch_kill_prompt = Some(BatchKillPrompt {
+            signal: Signal::Sigterm,
+            query,
+            targets,
+        });
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    /// previews a signal to every process sharing the current row's exact name, filter or not.
+    fn open_same_name_kill_prompt(&mut self) {
+        let Some(name) = self
+            .current_pid()
+            .and_then(|pid| self.process_snapshot(pid))
+            .map(|proc| proc.name)
+        else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        let described = format!("named '{name}'");
+        self.open_killable_batch_prompt(name.clone(), &described, |proc| proc.name == name);
+    }
+
+    /// previews every signalable process in the same cgroup as the current row, like a
+    /// service restart; `/` is refused since it holds everything outside a cgroup.
+    fn open_cgroup_kill_prompt(&mut self) {
+        let Some(pid) = self.current_pid() else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        let cgroups = self.cgroup_paths.get_or_insert_with(|| {
+            let pids: Vec<u32> = self.processes.iter().map(|proc| proc.pid).collect();
+            self.process_source.cgroup_paths(&pids)
+        });
+        let Some(path) = cgroups.get(&pid).cloned() else {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("no cgroup found for PID {pid}"),
+            );
+            return;
+        };
+        if path == "/" {
+            self.set_status(
+                StatusLevel::Warning,
+                "PID is in the root cgroup; refusing to signal every process in it",
+            );
+            return;
+        }
+        let members: HashSet<u32> = cgroups
+            .iter()
+            .filter(|(_, cgroup)| **cgroup == path)
+            .map(|(pid, _)| *pid)
+            .collect();
+        let described = format!("in cgroup {path}");
+        self.open_killable_batch_prompt(path, &described, |proc| members.contains(&proc.pid));
+    }
+
+    /// the batch preview for `matches`, leaving out processes this user can't signal.
+    /// previews every signalable process on the current row's terminal, e.g. what a dropped
+    /// ssh session left behind; pkillr's own terminal is refused.
+    fn open_tty_kill_prompt(&mut self) {
+        let Some(proc) = self
+            .current_pid()
+            .and_then(|pid| self.process_snapshot(pid))
+        else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        let Some(tty) = proc.tty else {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("PID {} has no controlling terminal", proc.pid),
+            );
+            return;
+        };
+        let name = tty_name(tty);
+        if self.own_tty == Some(tty) {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("{name} is pkillr's own terminal; refusing to signal it"),
+            );
+            return;
+        }
+        let described = format!("on {name}");
+        self.open_killable_batch_prompt(name, &described, |proc| proc.tty == Some(tty));
+    }
+
+    fn open_killable_batch_prompt(
+        &mut self,
+        query: String,
+        described: &str,
+        matches: impl Fn(&ProcessInfo) -> bool,
+    ) {
+        let (killable, unkillable): (Vec<&ProcessInfo>, Vec<&ProcessInfo>) = self
+            .processes
+            .iter()
+            .filter(|proc| proc.thread_owner.is_none() && matches(proc))
+            .partition(|proc| can_kill(proc).is_ok());
+        let skipped = unkillable.len();
+        let targets: Vec<BatchTarget> = killable
+            .into_iter()
+            .map(|proc| BatchTarget {
+                pid: proc.pid,
+                name: proc.name.clone(),
+                risk: self.assess_risk(proc),
+                tracer: self.tracer_of(proc.pid),
+            })
+            .collect();
+        if targets.is_empty() {
+            self.set_status(
+                StatusLevel::Warning,
+                format!("can't signal any process {described} without more privileges"),
+            );
+            return;
+        }
+        if skipped > 0 {
+            self.set_status(
+                StatusLevel::Warning,
+                format!(
+                    "left out {} {described} that need more privileges",
+                    process_count(skipped)
+                ),
+            );
+        }
+        self.batch_kill_prompt = Some(BatchKillPrompt {
+            signal: Signal::Sigterm,
+            query,
+            targets,
+        });
+        self.refresh_pause_state();
+        self.needs_refresh = true;
+    }
+
+    fn handle_batch_kill_input(&mut self, event: KeyEvent) -> bool {
+        let Some(prompt) = self.batch_kill_prompt.as_mut() else {
+            return false;
+        };
+        match event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if self.kill_cooling_down() {
+                    return true;
+                }
+                let prompt = self.batch_kill_prompt.take().expect("prompt checked above");
+                self.refresh_pause_state();
+                let pids = prompt.targets.iter().map(|target| target.pid).collect();
+                self.dispatch_signal_targets(PendingKill::new(
+                    pids,
+                    prompt.signal,
+                    KillMode::Direct,
+                ));
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.batch_kill_prompt = None;
+                self.set_status(StatusLevel::Info, "cancelled batch signal");
+                self.refresh_pause_state();
+            }
+            KeyCode::Tab => {
+                let current = BATCH_SIGNALS
+                    .iter()
+                    .position(|signal| *signal == prompt.signal)
+                    .unwrap_or(0);
+                prompt.signal = BATCH_SIGNALS[(current + 1) % BATCH_SIGNALS.len()];
+            }
+            _ => {}
+        }
+        self.needs_refresh = true;
+        true
+    }
+
+    /// asks before arming the respawn blacklist for the current process.
+    fn request_kill_and_blacklist(&mut self) {
+        let Some(proc) = self
+            .current_pid()
+            .and_then(|pid| self.process_snapshot(pid))
+        else {
+            self.set_status(StatusLevel::Warning, "no process selected");
+            return;
+        };
+        self.blacklist_confirm = Some(proc.pid);
+        self.set_status(
+            StatusLevel::Warning,
+            format!(
+                "AGGRESSIVE: kill {} (PID {}) and re-kill it whenever it respawns (max {} times within {}s)? (y/n)",
+                proc.name,
+                proc.pid,
+                RESPAWN_MAX_KILLS,
+                RESPAWN_WATCH_WINDOW.as_secs()
+            ),
+        );
+        self.refresh_pause_state();
+    }
+
+    fn handle_blacklist_confirm_input(&mut self, event: KeyEvent) -> bool {
+        let Some(pid) = self.blacklist_confirm else {
+            return false;
+        };
+        match event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.blacklist_confirm = None;
+                self.refresh_pause_state();
+                let Some(proc) = self.process_snapshot(pid) else {
+                    self.set_status(StatusLevel::Warning, format!("PID {} already exited", pid));
+                    return true;
+                };
+                // the original may linger briefly after the signal; don't count it.
+                let ignored = self
+                    .processes
+                    .iter()
+                    .filter(|other| other.name == proc.name)
+                    .map(|other| other.pid)
+                    .collect();
+                let signal = Signal::Sigterm;
+                if self.dispatch_signal_targets(PendingKill::new(
+                    vec![pid],
+                    signal,
+                    KillMode::Direct,
+                )) {
+                    self.respawn_watches.retain(|watch| watch.name != proc.name);
+                    self.respawn_watches.push(RespawnWatch {
+                        name: proc.name.clone(),
+                        cmdline: proc.cmdline.clone(),
+                        signal,
+                        kills: 0,
+                        expires: Instant::now() + RESPAWN_WATCH_WINDOW,
+                        ignored,
+                    });
+                    if let Some((message, _)) = self.status_message.as_mut() {
+                        message.push_str(&format!(
+                            " — watching for '{}' respawns for {}s",
+                            proc.name,
+                            RESPAWN_WATCH_WINDOW.as_secs()
+                        ));
+                    }
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.blacklist_confirm = None;
+                self.set_status(StatusLevel::Info, "cancelled kill and blacklist");
+                self.refresh_pause_state();
+            }
+            _ => {
+                self.set_status(StatusLevel::Warning, "Press y to continue or n to cancel");
+            }
+        }
+        true
+    }
+
+    /// re-kills processes matching an armed blacklist entry, within its limits.
+    fn check_respawns(&mut self) {
+        if self.respawn_watches.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        let mut watches = std::mem::take(&mut self.respawn_watches);
+
+        for watch in watches.iter_mut() {
+            let respawned: Vec<u32> = self
+                .processes
+                .iter()
+                .filter(|proc| {
+                    proc.name == watch.name
+                        && proc.cmdline == watch.cmdline
+                        && !watch.ignored.contains(&proc.pid)
+                })
+                .map(|proc| proc.pid)
+                .collect();
+            for pid in respawned {
+                if watch.kills >= RESPAWN_MAX_KILLS || now >= watch.expires {
+                    break;
+                }
+                watch.ignored.insert(pid);
+                watch.kills += 1;
+                // the original kill was already held for arm_delay; the rest of the guards still apply.
+                let pending =
+                    PendingKill::new(vec![pid], watch.signal, KillMode::Direct).announced();
+                if self.dispatch_signal_targets(pending) {
+                    let message = format!(
+                        "auto-killed respawned {} (PID {}) [{}/{}]",
+                        watch.name, pid, watch.kills, RESPAWN_MAX_KILLS
+                    );
+                    self.set_status(StatusLevel::Warning, message);
+                }
+            }
+        }
+
+        watches.retain(|watch| {
+            let done = watch.kills >= RESPAWN_MAX_KILLS || now >= watch.expires;
+            if done {
+                let message = format!(
+                    "stopped watching '{}' for respawns ({} auto-kills)",
+                    watch.name, watch.kills
+                );
+                self.status_message = Some((message, StatusLevel::Info));
+                self.needs_refresh = true;
+            }
+            !done
+        });
+        self.respawn_watches = watches;
+    }
+
+    pub fn respawn_watch_count(&self) -> usize {
+        self.respawn_watches.len()
+    }
+
     fn refresh_process_data(&mut self) {
-        self.processes = self.process_manager.get_processes(self.show_all_processes);
-        self.total_memory_bytes = self.process_manager.total_memory_bytes();
+        self.swap_collected = self.show_all_processes
+            || self
+                .search_query
+                .trim_start()
+                .to_ascii_lowercase()
+                .starts_with("swap>");
+        self.process_source.set_collect_swap(self.swap_collected);
+        self.processes = self.process_source.get_processes(self.show_all_processes);
+        self.refresh_generation += 1;
+        self.last_refresh = Instant::now();
+        self.shown_refresh_age = 0;
+        self.network_pids = None;
+        self.cgroup_paths = None;
+        self.metrics.set_tracked_processes(self.processes.len());
+        self.total_memory_bytes = self.process_source.total_memory_bytes();
+        self.pressure = read_pressure();
+        self.check_respawns();
         self.selected_pids
             .retain(|pid| self.processes.iter().any(|proc| proc.pid == *pid));
+        let pinned = self.pin_selection.then(|| self.current_pid()).flatten();
+        let screen_row = self.selected_index.saturating_sub(self.table_scroll_offset);
         self.apply_filters();
+        if let Some(pid) = pinned
+            && let Some(index) = self
+                .filtered_processes
+                .iter()
+                .position(|proc| proc.pid == pid)
+        {
+            self.selected_index = index;
+            self.table_scroll_offset = index.saturating_sub(screen_row);
+        }
         if self.tree_view_open {
             self.rebuild_tree_nodes();
         }
@@ -1842,6 +5095,7 @@ impl App {
 
     fn invalidate_process_details(&mut self) {
         self.info_details_cache = None;
+        self.info_exited = None;
         self.info_pane_scroll = 0;
     }
 
@@ -1856,6 +5110,8 @@ impl App {
             SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
             SortColumn::User => a.user.to_lowercase().cmp(&b.user.to_lowercase()),
             SortColumn::Runtime => a.runtime.cmp(&b.runtime),
+            SortColumn::CpuTime => a.cpu_time.cmp(&b.cpu_time),
+            SortColumn::MajorFaults => a.major_faults.cmp(&b.major_faults),
         };
 
         if self.sort_descending {
@@ -1877,10 +5133,10 @@ impl App {
             self.table_scroll_offset = self.selected_index;
         }
 
-        if let Some(last) = self.filtered_processes.len().checked_sub(1) {
-            if self.table_scroll_offset > last {
-                self.table_scroll_offset = last;
-            }
+        if let Some(last) = self.filtered_processes.len().checked_sub(1)
+            && self.table_scroll_offset > last
+        {
+            self.table_scroll_offset = last;
         }
     }
 
@@ -1920,8 +5176,12 @@ impl App {
     fn refresh_pause_state(&mut self) {
         self.paused = matches!(self.mode, AppMode::Search | AppMode::SignalMenu)
             || self.history_popup_open
+            || self.views_popup_open
+            || self.users_popup_open
             || self.help_popup_open
-            || self.shell_confirm.is_some();
+            || self.shell_confirm.is_some()
+            || self.blacklist_confirm.is_some()
+            || self.batch_kill_prompt.is_some();
     }
 
     fn set_status<T: Into<String>>(&mut self, level: StatusLevel, message: T) {
@@ -1938,13 +5198,39 @@ impl App {
         self.signal_history = deque;
     }
 
-    fn parse_search_mode(query: &str) -> Result<SearchMode, String> {
+    fn parse_search_mode(query: &str, style: MatchStyle) -> Result<SearchMode, String> {
         let trimmed = query.trim();
         if trimmed.is_empty() {
             return Ok(SearchMode::Fuzzy(String::new()));
         }
 
         let lowered = trimmed.to_ascii_lowercase();
+        if let Some(size) = lowered.strip_prefix("swap>") {
+            return parse_size(size.trim())
+                .map(SearchMode::Swap)
+                .ok_or_else(|| format!("invalid swap size '{}' (try swap>100M)", size.trim()));
+        }
+        if let Some(value) = lowered.strip_prefix("net:") {
+            return match value.trim() {
+                "true" | "yes" | "1" => Ok(SearchMode::Network(true)),
+                "false" | "no" | "0" => Ok(SearchMode::Network(false)),
+                other => Err(format!("invalid net filter '{other}' (try net:true)")),
+            };
+        }
+        if lowered.starts_with("user:") {
+            let user = trimmed["user:".len()..].trim();
+            if user.is_empty() {
+                return Err("user: needs a user name (try user:root)".to_string());
+            }
+            return Ok(SearchMode::User(user.to_string()));
+        }
+        if lowered.starts_with("exact:") {
+            let name = trimmed["exact:".len()..].trim();
+            if name.is_empty() {
+                return Err("exact: needs a process name (try exact:ssh)".to_string());
+            }
+            return Ok(SearchMode::Exact(name.to_string()));
+        }
         if lowered.starts_with("/killed") {
             let remainder = if lowered.len() >= 7 {
                 &trimmed[7..]
@@ -1955,34 +5241,36 @@ impl App {
             return Ok(SearchMode::History(filter.to_string()));
         }
 
-        if trimmed.starts_with('/') {
-            if let Some(end) = trimmed.rfind('/') {
-                if end > 0 {
-                    let pattern = &trimmed[1..end];
-                    let flags = trimmed[end + 1..].to_string();
-                    let mut builder = RegexBuilder::new(pattern);
-                    if flags.contains('i') {
-                        builder.case_insensitive(true);
-                    }
-                    if flags.contains('m') {
-                        builder.multi_line(true);
-                    }
-                    if flags.contains('s') {
-                        builder.dot_matches_new_line(true);
-                    }
-                    let matcher = builder
-                        .build()
-                        .map_err(|err| format!("invalid regex: {err}"))?;
-                    return Ok(SearchMode::Regex {
-                        pattern: pattern.to_string(),
-                        flags,
-                        matcher,
-                    });
-                }
+        if trimmed.starts_with('/')
+            && let Some(end) = trimmed.rfind('/')
+            && end > 0
+        {
+            let pattern = &trimmed[1..end];
+            let flags = trimmed[end + 1..].to_string();
+            let mut builder = RegexBuilder::new(pattern);
+            if flags.contains('i') {
+                builder.case_insensitive(true);
+            }
+            if flags.contains('m') {
+                builder.multi_line(true);
+            }
+            if flags.contains('s') {
+                builder.dot_matches_new_line(true);
             }
+            let matcher = builder
+                .build()
+                .map_err(|err| format!("invalid regex: {err}"))?;
+            return Ok(SearchMode::Regex {
+                pattern: pattern.to_string(),
+                flags,
+                matcher,
+            });
         }
 
-        Ok(SearchMode::Fuzzy(trimmed.to_string()))
+        Ok(match style {
+            MatchStyle::Fuzzy => SearchMode::Fuzzy(trimmed.to_string()),
+            MatchStyle::Substring => SearchMode::Substring(trimmed.to_string()),
+        })
     }
 
     fn filter_by_history(&mut self, processes: Vec<ProcessInfo>, filter: &str) -> Vec<ProcessInfo> {
@@ -2044,33 +5332,68 @@ impl App {
     }
 
     fn assess_risk(&self, info: &ProcessInfo) -> Option<RiskInfo> {
-        if info.pid == 1 {
-            return Some(RiskInfo {
-                level: RiskLevel::Critical,
-                reason: "init process".to_string(),
-            });
+        assess_risk(info, self.parent_pid)
+    }
+}
+
+/// the first target, or descendant for tree kills, that `check` objects to. pids missing
+/// from `everyone` are gone and pass.
+fn first_affected<T>(
+    everyone: &[ProcessInfo],
+    targets: &[u32],
+    mode: KillMode,
+    check: impl Fn(&ProcessInfo) -> Option<T>,
+) -> Option<(u32, T)> {
+    let mut pending = targets.to_vec();
+    let mut seen = HashSet::new();
+    while let Some(pid) = pending.pop() {
+        if !seen.insert(pid) {
+            continue;
         }
-        if info.pid == self.parent_pid {
-            return Some(RiskInfo {
-                level: RiskLevel::Critical,
-                reason: "current shell".to_string(),
-            });
+        if let Some(found) = everyone
+            .iter()
+            .find(|proc| proc.pid == pid)
+            .and_then(&check)
+        {
+            return Some((pid, found));
+        }
+        if matches!(mode, KillMode::Tree) {
+            pending.extend(
+                everyone
+                    .iter()
+                    .filter(|proc| proc.parent_pid == Some(pid))
+                    .map(|proc| proc.pid),
+            );
         }
+    }
+    None
+}
 
-        let name = info.name.to_ascii_lowercase();
-        let mut result: Option<RiskInfo> = None;
+fn guarded_name(everyone: &[ProcessInfo], pid: u32) -> String {
+    everyone
+        .iter()
+        .find(|proc| proc.pid == pid)
+        .map_or_else(|| format!("PID {pid}"), |proc| proc.name.clone())
+}
 
-        for (pattern, level, reason) in CRITICAL_NAME_PATTERNS.iter() {
-            if name.contains(pattern) {
-                result = combine_risk(result, *level, reason);
-            }
-        }
+/// appends a digit to a typed signal number; `None` when the combined number is not a known signal.
+fn push_signal_digit(buffer: &mut String, digit: char) -> Option<Signal> {
+    buffer.push(digit);
+    buffer.parse().ok().and_then(Signal::from_number)
+}
 
-        if info.user == "root" {
-            result = combine_risk(result, RiskLevel::Elevated, "root-owned process");
-        }
+/// whether another digit after `digits` can still make a signal number (`1` -> `15`).
+fn signal_number_continues(digits: &str) -> bool {
+    digits
+        .parse::<i32>()
+        .is_ok_and(|number| (0..=9).any(|digit| Signal::from_number(number * 10 + digit).is_some()))
+}
 
-        result
+fn paged_limit(page_size: usize, pages: usize) -> usize {
+    if page_size == 0 {
+        usize::MAX
+    } else {
+        page_size.saturating_mul(pages)
     }
 }
 
@@ -2078,7 +5401,75 @@ fn order_text(desc: bool) -> &'static str {
     if desc { "(desc)" } else { "(asc)" }
 }
 
-fn build_tree_prefix(stack: &[bool]) -> String {
+/// parses sizes like `100M`, `1.5G`, `512k` or plain bytes (binary units).
+fn parse_size(text: &str) -> Option<u64> {
+    let text = text.trim().to_ascii_lowercase();
+    let text = text
+        .strip_suffix("ib")
+        .or_else(|| text.strip_suffix('b'))
+        .unwrap_or(&text);
+    let (number, multiplier) = match text.chars().last()? {
+        'k' => (&text[..text.len() - 1], 1024_f64),
+        'm' => (&text[..text.len() - 1], 1024_f64.powi(2)),
+        'g' => (&text[..text.len() - 1], 1024_f64.powi(3)),
+        't' => (&text[..text.len() - 1], 1024_f64.powi(4)),
+        _ => (text, 1.0),
+    };
+    let value: f64 = number.trim().parse().ok()?;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    Some((value * multiplier) as u64)
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "K", "M", "G"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value.fract() == 0.0 {
+        format!("{}{}", value, UNITS[unit])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+fn centered_offset(selected: usize, len: usize, visible: usize) -> usize {
+    if visible == 0 || len <= visible {
+        return 0;
+    }
+    selected.saturating_sub(visible / 2).min(len - visible)
+}
+
+fn format_memory_threshold(bytes: u64) -> String {
+    format!("{} MiB", bytes / (1024 * 1024))
+}
+
+fn count_descendants(pid: u32, children: &HashMap<u32, Vec<u32>>) -> usize {
+    let mut count = 0;
+    let mut stack = vec![pid];
+    while let Some(current) = stack.pop() {
+        if let Some(kids) = children.get(&current) {
+            count += kids.len();
+            stack.extend(kids.iter().copied());
+        }
+    }
+    count
+}
+
+/// "1 process", "3 processes".
+pub fn process_count(count: usize) -> String {
+    if count == 1 {
+        "1 process".to_string()
+    } else {
+        format!("{count} processes")
+    }
+}
+
+fn build_tree_prefix(stack: &[bool], connectors: TreeConnectors) -> String {
     if stack.is_empty() {
         return String::new();
     }
@@ -2086,9 +5477,17 @@ fn build_tree_prefix(stack: &[bool]) -> String {
     let mut prefix = String::new();
     for (idx, is_last) in stack.iter().enumerate() {
         if idx + 1 == stack.len() {
-            prefix.push_str(if *is_last { "└─ " } else { "├─ " });
+            prefix.push_str(if *is_last {
+                connectors.last
+            } else {
+                connectors.branch
+            });
+            prefix.push(' ');
+        } else if *is_last {
+            prefix.push_str("   ");
         } else {
-            prefix.push_str(if *is_last { "   " } else { "│  " });
+            prefix.push_str(connectors.pipe);
+            prefix.push_str("  ");
         }
     }
     prefix
@@ -2112,93 +5511,136 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
-const CRITICAL_NAME_PATTERNS: &[(&str, RiskLevel, &str)] = &[
-    ("systemd", RiskLevel::Critical, "system init"),
-    ("dbus-daemon", RiskLevel::Elevated, "dbus session"),
-    ("dbus-broker", RiskLevel::Elevated, "dbus broker"),
-    ("gnome-shell", RiskLevel::Critical, "desktop shell"),
-    ("plasmashell", RiskLevel::Critical, "desktop shell"),
-    ("kwin", RiskLevel::Critical, "window manager"),
-    ("mutter", RiskLevel::Critical, "window manager"),
-    ("sway", RiskLevel::Critical, "window manager"),
-    ("hyprland", RiskLevel::Critical, "window manager"),
-    ("wayfire", RiskLevel::Critical, "window manager"),
-    ("i3", RiskLevel::Critical, "window manager"),
-    ("xfce4-session", RiskLevel::Elevated, "desktop session"),
-    ("xorg", RiskLevel::Critical, "display server"),
-    ("xwayland", RiskLevel::Elevated, "display bridge"),
-    ("pipewire", RiskLevel::Elevated, "media service"),
-    ("pulseaudio", RiskLevel::Elevated, "audio server"),
-    ("tmux", RiskLevel::Elevated, "terminal multiplexer"),
-    ("wezterm", RiskLevel::Elevated, "terminal host"),
-    ("alacritty", RiskLevel::Elevated, "terminal host"),
-    ("kitty", RiskLevel::Elevated, "terminal host"),
-];
+const MAX_ENV_MATCHES: usize = 16;
 
-fn combine_risk(current: Option<RiskInfo>, level: RiskLevel, reason: &str) -> Option<RiskInfo> {
-    match current {
-        Some(existing) if existing.level >= level => Some(existing),
-        _ => Some(RiskInfo {
-            level,
-            reason: reason.to_string(),
-        }),
-    }
+/// why cpu figures update slower than the rest of the table, when they do.
+fn cpu_interval_note(refresh_rate_ms: u64, source: CpuSource) -> Option<String> {
+    let cpu_interval = MINIMUM_CPU_UPDATE_INTERVAL.as_millis() as u64;
+    (source == CpuSource::Sysinfo && refresh_rate_ms < cpu_interval).then(|| {
+        format!(
+            "refreshing every {refresh_rate_ms}ms; CPU% updates every {cpu_interval}ms (--cpu-source stat follows the refresh rate)"
+        )
+    })
 }
 
-const SCORE_NAME: i64 = 900_000;
-const SCORE_CAMEL: i64 = 880_000;
-const SCORE_CMDLINE: i64 = 700_000;
-const SCORE_CWD: i64 = 660_000;
-const SCORE_ENV: i64 = 640_000;
-const MAX_ENV_MATCHES: usize = 16;
+/// the skim matcher is quadratic-ish in the haystack, so huge values are cut down first.
+fn fuzzy_input(text: &str) -> &str {
+    const MAX_FUZZY_INPUT_CHARS: usize = 1_024;
+    text.char_indices()
+        .nth(MAX_FUZZY_INPUT_CHARS)
+        .map_or(text, |(cut, _)| &text[..cut])
+}
 
 fn fuzzy_match_process(
     proc: &ProcessInfo,
     query: &str,
     matcher: &SkimMatcherV2,
+    weights: &SearchWeights,
 ) -> Option<SearchHit> {
+    // the name highlight always follows the winning field: its matched characters mapped
+    // back to byte offsets in the name, or nothing when that field isn't the name.
     let mut best_score: Option<i64> = None;
     let mut name_indices: Vec<usize> = Vec::new();
 
     if let Some((score, indices)) = matcher.fuzzy_indices(&proc.name, query) {
-        let weighted = SCORE_NAME + score;
+        let weighted = weights.name + score;
         best_score = Some(weighted);
-        name_indices = indices;
+        name_indices = char_byte_offsets(&proc.name, &indices);
     }
 
-    let camel = split_camel_case(&proc.name);
-    if !camel.is_empty() {
-        if let Some(score) = matcher.fuzzy_match(&camel, query) {
-            let weighted = SCORE_CAMEL + score;
-            if best_score.map_or(true, |current| weighted > current) {
-                best_score = Some(weighted);
-            }
+    if let Some(argv0) = argv0_basename(&proc.cmdline).filter(|argv0| *argv0 != proc.name)
+        && let Some(score) = matcher.fuzzy_match(argv0, query)
+    {
+        let weighted = weights.name + score;
+        if best_score.is_none_or(|current| weighted > current) {
+            best_score = Some(weighted);
+            name_indices.clear();
+        }
+    }
+
+    let (camel, camel_offsets) = split_camel_case(&proc.name);
+    if !camel.is_empty()
+        && let Some((score, indices)) = matcher.fuzzy_indices(&camel, query)
+    {
+        let weighted = weights.camel + score;
+        if best_score.is_none_or(|current| weighted > current) {
+            best_score = Some(weighted);
+            name_indices = indices
+                .iter()
+                .filter_map(|idx| camel_offsets.get(*idx).copied().flatten())
+                .collect();
         }
     }
 
     if !proc.cmdline.is_empty() {
         let cmdline = proc.cmdline.join(" ");
-        if let Some(score) = matcher.fuzzy_match(&cmdline, query) {
-            let weighted = SCORE_CMDLINE + score;
-            if best_score.map_or(true, |current| weighted > current) {
+        let haystack = fuzzy_input(&cmdline);
+        if let Some((score, indices)) = matcher.fuzzy_indices(haystack, query) {
+            let weighted = weights.cmdline + score;
+            if best_score.is_none_or(|current| weighted > current) {
                 best_score = Some(weighted);
+                name_indices = cmdline_name_offsets(proc, haystack, &indices);
             }
         }
     }
 
-    if let Some(cwd) = proc.cwd.as_ref() {
-        if let Some(score) = matcher.fuzzy_match(cwd, query) {
-            let weighted = SCORE_CWD + score;
-            if best_score.map_or(true, |current| weighted > current) {
+    if let Some(cwd) = proc.cwd.as_ref()
+        && let Some(score) = matcher.fuzzy_match(cwd, query)
+    {
+        let weighted = weights.cwd + score;
+        if best_score.is_none_or(|current| weighted > current) {
+            best_score = Some(weighted);
+            name_indices.clear();
+        }
+    }
+
+    for entry in proc.environment.iter().take(MAX_ENV_MATCHES) {
+        if let Some(score) = matcher.fuzzy_match(fuzzy_input(entry), query) {
+            let weighted = weights.env + score;
+            if best_score.is_none_or(|current| weighted > current) {
                 best_score = Some(weighted);
+                name_indices.clear();
             }
         }
     }
 
-    for entry in proc.environment.iter().take(MAX_ENV_MATCHES) {
-        if let Some(score) = matcher.fuzzy_match(entry, query) {
-            let weighted = SCORE_ENV + score;
-            if best_score.map_or(true, |current| weighted > current) {
+    best_score.map(|score| SearchHit {
+        score,
+        name_indices,
+    })
+}
+
+fn substring_match_process(
+    proc: &ProcessInfo,
+    query: &str,
+    case_sensitive: bool,
+    weights: &SearchWeights,
+) -> Option<SearchHit> {
+    let mut best_score: Option<i64> = None;
+    let mut name_indices: Vec<usize> = Vec::new();
+
+    if let Some(indices) = substring_indices(&proc.name, query, case_sensitive) {
+        let position = indices.first().copied().unwrap_or(0) as i64;
+        best_score = Some(weights.name - position);
+        name_indices = indices;
+    }
+
+    if let Some(argv0) = argv0_basename(&proc.cmdline).filter(|argv0| *argv0 != proc.name)
+        && let Some(indices) = substring_indices(argv0, query, case_sensitive)
+    {
+        let position = indices.first().copied().unwrap_or(0) as i64;
+        let weighted = weights.name - position;
+        if best_score.is_none_or(|current| weighted > current) {
+            best_score = Some(weighted);
+        }
+    }
+
+    if !proc.cmdline.is_empty() {
+        let cmdline = proc.cmdline.join(" ");
+        if let Some(indices) = substring_indices(&cmdline, query, case_sensitive) {
+            let position = indices.first().copied().unwrap_or(0) as i64;
+            let weighted = weights.cmdline - position;
+            if best_score.is_none_or(|current| weighted > current) {
                 best_score = Some(weighted);
             }
         }
@@ -2210,39 +5652,66 @@ fn fuzzy_match_process(
     })
 }
 
-fn regex_match_process(proc: &ProcessInfo, regex: &Regex) -> Option<SearchHit> {
+/// substring search returning the byte indices of the first contiguous match.
+fn substring_indices(text: &str, query: &str, case_sensitive: bool) -> Option<Vec<usize>> {
+    let fold = |ch: char| {
+        if case_sensitive {
+            ch
+        } else {
+            ch.to_lowercase().next().unwrap_or(ch)
+        }
+    };
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return None;
+    }
+    let haystack: Vec<(usize, char)> = text
+        .char_indices()
+        .map(|(idx, ch)| (idx, fold(ch)))
+        .collect();
+    haystack
+        .windows(needle.len())
+        .find(|window| window.iter().map(|(_, ch)| *ch).eq(needle.iter().copied()))
+        .map(|window| window.iter().map(|(idx, _)| *idx).collect())
+}
+
+fn regex_match_process(
+    proc: &ProcessInfo,
+    regex: &Regex,
+    weights: &SearchWeights,
+) -> Option<SearchHit> {
     let mut best_score: Option<i64> = None;
     let mut name_indices: Vec<usize> = Vec::new();
 
     if regex.is_match(&proc.name) {
         name_indices = regex_indices(&proc.name, regex);
-        let weighted = SCORE_NAME + name_indices.len() as i64;
+        let weighted = weights.name + name_indices.len() as i64;
         best_score = Some(weighted);
     }
 
     if !proc.cmdline.is_empty() {
         let cmdline = proc.cmdline.join(" ");
         if regex.is_match(&cmdline) {
-            let weighted = SCORE_CMDLINE + cmdline.len() as i64;
-            if best_score.map_or(true, |current| weighted > current) {
+            let weighted = weights.cmdline + cmdline.len() as i64;
+            if best_score.is_none_or(|current| weighted > current) {
                 best_score = Some(weighted);
             }
         }
     }
 
-    if let Some(cwd) = proc.cwd.as_ref() {
-        if regex.is_match(cwd) {
-            let weighted = SCORE_CWD + cwd.len() as i64;
-            if best_score.map_or(true, |current| weighted > current) {
-                best_score = Some(weighted);
-            }
+    if let Some(cwd) = proc.cwd.as_ref()
+        && regex.is_match(cwd)
+    {
+        let weighted = weights.cwd + cwd.len() as i64;
+        if best_score.is_none_or(|current| weighted > current) {
+            best_score = Some(weighted);
         }
     }
 
     for entry in proc.environment.iter().take(MAX_ENV_MATCHES) {
         if regex.is_match(entry) {
-            let weighted = SCORE_ENV + entry.len() as i64;
-            if best_score.map_or(true, |current| weighted > current) {
+            let weighted = weights.env + entry.len() as i64;
+            if best_score.is_none_or(|current| weighted > current) {
                 best_score = Some(weighted);
             }
         }
@@ -2272,30 +5741,83 @@ fn full_match_indices(text: &str) -> Vec<usize> {
     text.char_indices().map(|(idx, _)| idx).collect()
 }
 
-fn split_camel_case(value: &str) -> String {
-    if value.is_empty() {
-        return String::new();
-    }
-
+/// `NetworkManager` -> `Network Manager`, plus the byte offset in `value` each output char
+/// came from (`None` for the spaces that were inserted or replaced a separator).
+fn split_camel_case(value: &str) -> (String, Vec<Option<usize>>) {
     let mut result = String::with_capacity(value.len() * 2);
+    let mut offsets = Vec::with_capacity(value.len() * 2);
     let mut prev_lower_or_digit = false;
 
-    for ch in value.chars() {
+    for (offset, ch) in value.char_indices() {
         if ch == '_' || ch == '-' {
             result.push(' ');
+            offsets.push(None);
             prev_lower_or_digit = false;
             continue;
         }
 
         if ch.is_uppercase() && prev_lower_or_digit {
             result.push(' ');
+            offsets.push(None);
         }
 
         result.push(ch);
+        offsets.push(Some(offset));
         prev_lower_or_digit = ch.is_lowercase() || ch.is_ascii_digit();
     }
 
-    result
+    (result, offsets)
+}
+
+/// skim reports char positions; the table highlights by byte offset.
+fn char_byte_offsets(text: &str, char_indices: &[usize]) -> Vec<usize> {
+    text.char_indices()
+        .enumerate()
+        .filter(|(idx, _)| char_indices.contains(idx))
+        .map(|(_, (offset, _))| offset)
+        .collect()
+}
+
+/// the part of a cmdline match that landed on the name: argv[0]'s basename when it starts
+/// with the (possibly truncated) comm name. `cmdline` is the joined argv the match ran on.
+fn cmdline_name_offsets(proc: &ProcessInfo, cmdline: &str, char_indices: &[usize]) -> Vec<usize> {
+    let Some(argv0) = proc.cmdline.first() else {
+        return Vec::new();
+    };
+    let path_end = argv0.find(char::is_whitespace).unwrap_or(argv0.len());
+    let start = argv0[..path_end].rfind('/').map_or(0, |slash| slash + 1);
+    if proc.name.is_empty() || !argv0[start..].starts_with(&proc.name) {
+        return Vec::new();
+    }
+    let end = start + proc.name.len();
+    char_byte_offsets(cmdline, char_indices)
+        .into_iter()
+        .filter(|offset| (start..end).contains(offset))
+        .map(|offset| offset - start)
+        .collect()
+}
+
+/// a query that reads as a signal name rather than a process: `SIG`-prefixed, or a bare name
+/// of four letters or more (`kill`, `term`). numbers are left alone, they're usually pids.
+fn signal_like_query(query: &str) -> Option<Signal> {
+    let query = query.trim();
+    let prefixed = query
+        .get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("sig"));
+    if !prefixed && (query.len() < 4 || !query.chars().all(|ch| ch.is_ascii_alphanumeric())) {
+        return None;
+    }
+    if query.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    Signal::parse(query)
+}
+
+fn is_termination_request(signal: Signal) -> bool {
+    matches!(
+        signal,
+        Signal::Sigterm | Signal::Sigint | Signal::Sighup | Signal::Sigquit
+    )
 }
 
 fn is_dangerous_signal(signal: Signal) -> bool {
@@ -1,20 +1,20 @@
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
 use clap::ValueEnum;
 use ratatui::style::{Color, Style};
 
 use crate::process::ProcessInfo;
+use crate::protect::ProtectedTarget;
+use crate::settings::{DisplaySettings, InfoSettings};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
 pub enum Theme {
+    #[default]
     Pink,
     Serious,
 }
 
-impl Default for Theme {
-    fn default() -> Self {
-        Theme::Pink
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 pub struct Palette {
     pub background: Color,
@@ -28,6 +28,8 @@ pub struct Palette {
     pub mem_yellow: Color,
     pub mem_red: Color,
     pub kill_accent: Color,
+    /// runtime of processes started within `RuntimeThresholds::fresh`.
+    pub runtime_fresh: Color,
     pub status_info: Color,
     pub status_warning: Color,
     pub status_error: Color,
@@ -35,8 +37,6 @@ pub struct Palette {
 
 const CPU_YELLOW_THRESHOLD: f32 = 40.0;
 const CPU_RED_THRESHOLD: f32 = 80.0;
-const MEM_YELLOW_THRESHOLD: u64 = 500 * 1024 * 1024;
-const MEM_RED_THRESHOLD: u64 = 2 * 1024 * 1024 * 1024;
 
 impl Theme {
     pub fn palette(self) -> Palette {
@@ -53,6 +53,7 @@ impl Theme {
                 mem_yellow: Color::Rgb(255, 105, 180),
                 mem_red: Color::Rgb(255, 0, 120),
                 kill_accent: Color::Rgb(255, 20, 147),
+                runtime_fresh: Color::Rgb(255, 182, 193),
                 status_info: Color::Rgb(255, 20, 147),
                 status_warning: Color::Rgb(255, 105, 180),
                 status_error: Color::Rgb(255, 0, 120),
@@ -69,6 +70,7 @@ impl Theme {
                 mem_yellow: Color::Yellow,
                 mem_red: Color::Red,
                 kill_accent: Color::Red,
+                runtime_fresh: Color::LightGreen,
                 status_info: Color::Blue,
                 status_warning: Color::Yellow,
                 status_error: Color::Red,
@@ -87,21 +89,44 @@ impl Theme {
         }
     }
 
-    pub fn get_memory_color(self, bytes: u64) -> Color {
+    pub fn get_memory_color(
+        self,
+        bytes: u64,
+        thresholds: MemoryThresholds,
+        total_memory_bytes: u64,
+    ) -> Color {
         let palette = self.palette();
-        if bytes >= MEM_RED_THRESHOLD {
+        let (warn, critical) = thresholds.limits(total_memory_bytes);
+        if bytes >= critical {
             palette.mem_red
-        } else if bytes >= MEM_YELLOW_THRESHOLD {
+        } else if bytes >= warn {
             palette.mem_yellow
         } else {
             palette.text_normal
         }
     }
 
-    pub fn style_for_process(self, proc: &ProcessInfo) -> Style {
+    /// bright while fresh, dim once old, the normal text color in between.
+    pub fn get_runtime_color(self, runtime: Duration, thresholds: RuntimeThresholds) -> Color {
+        let palette = self.palette();
+        if runtime < thresholds.fresh {
+            palette.runtime_fresh
+        } else if runtime >= thresholds.old {
+            palette.text_dim
+        } else {
+            palette.text_normal
+        }
+    }
+
+    pub fn style_for_process(
+        self,
+        proc: &ProcessInfo,
+        thresholds: MemoryThresholds,
+        total_memory_bytes: u64,
+    ) -> Style {
         let palette = self.palette();
         let cpu_color = self.get_cpu_color(proc.cpu_percent);
-        let mem_color = self.get_memory_color(proc.memory_bytes);
+        let mem_color = self.get_memory_color(proc.memory_bytes, thresholds, total_memory_bytes);
         let fg = if cpu_color == palette.cpu_red || mem_color == palette.mem_red {
             palette.cpu_red
         } else if cpu_color == palette.cpu_yellow || mem_color == palette.mem_yellow {
@@ -113,26 +138,485 @@ impl Theme {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+/// how the selected row stands out from the rest.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum SelectionStyle {
+    /// the theme's highlight color behind the row.
+    #[default]
+    Background,
+    /// a `▶` marker in the left gutter, for terminals that drop background colors.
+    Gutter,
+    /// reversed, bold text.
+    Reverse,
+}
+
+/// where per-process cpu percentages come from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum CpuSource {
+    /// sysinfo's usage, updated at most once per `MINIMUM_CPU_UPDATE_INTERVAL`.
+    #[default]
+    Sysinfo,
+    /// utime + stime from `/proc/<pid>/stat`, diffed against the previous sample like top.
+    Stat,
+}
+
+/// the order signals are listed in the signal menu.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum SignalOrder {
+    #[default]
+    Number,
+    /// alphabetical by `SIG` name.
+    Name,
+}
+
+impl SignalOrder {
+    pub fn toggle(self) -> Self {
+        match self {
+            SignalOrder::Number => SignalOrder::Name,
+            SignalOrder::Name => SignalOrder::Number,
+        }
+    }
+}
+
+/// what Enter does on a process row.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum EnterAction {
+    /// SIGTERM the selection, like `k`.
+    #[default]
+    Kill,
+    /// open the signal menu, so nothing is sent without a second keypress.
+    SignalMenu,
+    /// toggle the info pane.
+    Info,
+}
+
+/// which characters draw the process tree.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum TreeStyle {
+    /// unicode unless the locale says the terminal isn't utf-8.
+    #[default]
+    Auto,
+    /// box drawing: `├─`, `└─`, `│`.
+    Unicode,
+    /// `+-`, `` `- ``, `|` for legacy terminals and serial consoles.
+    Ascii,
+}
+
+impl TreeStyle {
+    /// settles `Auto` from `LC_ALL`, `LC_CTYPE` and `LANG`, in the order libc reads them.
+    pub fn resolve(self) -> TreeStyle {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty());
+        self.resolve_for_locale(locale.as_deref())
+    }
+
+    /// no locale at all keeps unicode; only a locale naming another charset falls back to ascii.
+    pub fn resolve_for_locale(self, locale: Option<&str>) -> TreeStyle {
+        match (self, locale) {
+            (TreeStyle::Auto, Some(locale)) => {
+                let locale = locale.to_ascii_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    TreeStyle::Unicode
+                } else {
+                    TreeStyle::Ascii
+                }
+            }
+            (TreeStyle::Auto, None) => TreeStyle::Unicode,
+            (style, _) => style,
+        }
+    }
+
+    pub fn connectors(self) -> TreeConnectors {
+        match self.resolve() {
+            TreeStyle::Ascii => TreeConnectors {
+                branch: "+-",
+                last: "`-",
+                pipe: "|",
+            },
+            _ => TreeConnectors {
+                branch: "├─",
+                last: "└─",
+                pipe: "│",
+            },
+        }
+    }
+}
+
+/// the branch characters of one tree style; every glyph is a single column wide.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TreeConnectors {
+    pub branch: &'static str,
+    pub last: &'static str,
+    pub pipe: &'static str,
+}
+
+/// where the Name column takes its text from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum NameSource {
+    /// the kernel's comm name.
+    #[default]
+    Comm,
+    /// the argv[0] basename, which daemons such as postgres rewrite into a title.
+    Argv0,
+}
+
+/// how the info pane prints the working directory and executable.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum PathDisplay {
+    #[default]
+    Full,
+    /// `~` for the home directory and `…` for the middle of deep paths.
+    Short,
+    /// the last component only.
+    Basename,
+}
+
+/// components kept at the end of a shortened path.
+const SHORT_PATH_TAIL: usize = 2;
+
+impl PathDisplay {
+    pub fn next(self) -> Self {
+        match self {
+            PathDisplay::Full => PathDisplay::Short,
+            PathDisplay::Short => PathDisplay::Basename,
+            PathDisplay::Basename => PathDisplay::Full,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PathDisplay::Full => "full",
+            PathDisplay::Short => "short",
+            PathDisplay::Basename => "basename",
+        }
+    }
+
+    /// renders `path`, abbreviating `home` to `~` in the short style.
+    pub fn render(self, path: &Path, home: Option<&Path>) -> String {
+        match self {
+            PathDisplay::Full => path.to_string_lossy().into_owned(),
+            PathDisplay::Basename => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.to_string_lossy().into_owned()),
+            PathDisplay::Short => {
+                let (prefix, rest) = match home.and_then(|home| path.strip_prefix(home).ok()) {
+                    Some(rest) => ("~".to_string(), rest),
+                    None if path.has_root() => (String::new(), path),
+                    None => return path.to_string_lossy().into_owned(),
+                };
+                let parts: Vec<String> = rest
+                    .components()
+                    .filter_map(|component| match component {
+                        Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+                        _ => None,
+                    })
+                    .collect();
+                let kept: Vec<&str> = if parts.len() > SHORT_PATH_TAIL + 2 {
+                    std::iter::once(parts[0].as_str())
+                        .chain(std::iter::once("…"))
+                        .chain(
+                            parts[parts.len() - SHORT_PATH_TAIL..]
+                                .iter()
+                                .map(String::as_str),
+                        )
+                        .collect()
+                } else {
+                    parts.iter().map(String::as_str).collect()
+                };
+                if kept.is_empty() {
+                    return if prefix.is_empty() {
+                        "/".to_string()
+                    } else {
+                        prefix
+                    };
+                }
+                format!("{}/{}", prefix, kept.join("/"))
+            }
+        }
+    }
+}
+
+/// what memory color thresholds are measured against.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum MemoryScale {
+    /// percent of total ram, so colors mean the same on small and large machines.
+    #[default]
+    Percent,
+    /// fixed byte sizes.
+    Absolute,
+}
+
+/// `mib` in bytes; absurdly large settings stop at `u64::MAX` instead of wrapping.
+pub fn mib_to_bytes(mib: u64) -> u64 {
+    mib.saturating_mul(1024 * 1024)
+}
+
+/// usage at which memory cells turn yellow (warn) and red (critical).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryThresholds {
+    pub scale: MemoryScale,
+    pub warn_percent: f32,
+    pub critical_percent: f32,
+    pub warn_bytes: u64,
+    pub critical_bytes: u64,
+}
+
+impl Default for MemoryThresholds {
+    fn default() -> Self {
+        Self {
+            scale: MemoryScale::Percent,
+            warn_percent: 5.0,
+            critical_percent: 20.0,
+            warn_bytes: 500 * 1024 * 1024,
+            critical_bytes: 2 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl MemoryThresholds {
+    /// warn and critical limits in bytes; percent mode falls back to the
+    /// absolute sizes while total ram is unknown.
+    pub fn limits(self, total_memory_bytes: u64) -> (u64, u64) {
+        match self.scale {
+            MemoryScale::Percent if total_memory_bytes > 0 => {
+                let of_total =
+                    |percent: f32| (total_memory_bytes as f64 * percent as f64 / 100.0) as u64;
+                (of_total(self.warn_percent), of_total(self.critical_percent))
+            }
+            _ => (self.warn_bytes, self.critical_bytes),
+        }
+    }
+}
+
+/// ages at which the Runtime column stops counting as new, and starts counting as old.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeThresholds {
+    pub fresh: Duration,
+    pub old: Duration,
+}
+
+impl Default for RuntimeThresholds {
+    fn default() -> Self {
+        Self {
+            fresh: Duration::from_secs(60),
+            old: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// the most decimals the percent columns will show.
+pub const MAX_PERCENT_PRECISION: usize = 2;
+
+/// cells needed for a percentage up to `100` with `precision` decimals and the `%` sign.
+pub fn percent_width(precision: usize) -> u16 {
+    let fraction = if precision == 0 { 0 } else { precision + 1 };
+    (4 + fraction) as u16
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
 pub enum SortField {
+    #[default]
     Cpu,
     Mem,
     Pid,
     Name,
+    /// cumulative cpu time.
+    Time,
+    /// major page faults.
+    Faults,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum Column {
+    Pid,
+    Name,
+    Cpu,
+    Mem,
+    User,
+    Runtime,
+    /// cumulative cpu time, like ps's TIME; not shown unless asked for.
+    Time,
+    /// major page faults; not shown unless asked for.
+    Faults,
+}
+
+impl Column {
+    pub const DEFAULT_ORDER: [Column; 6] = [
+        Column::Pid,
+        Column::Name,
+        Column::Cpu,
+        Column::Mem,
+        Column::User,
+        Column::Runtime,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Column::Pid => "PID",
+            Column::Name => "Name",
+            Column::Cpu => "CPU%",
+            Column::Mem => "MEM%",
+            Column::User => "User",
+            Column::Runtime => "Runtime",
+            Column::Time => "TIME",
+            Column::Faults => "MAJFLT",
+        }
+    }
+
+    pub fn width(self) -> u16 {
+        match self {
+            Column::Pid => 8,
+            Column::Name => 20,
+            Column::Cpu => 6,
+            Column::Mem => 6,
+            Column::User => 12,
+            Column::Runtime => 10,
+            Column::Time => 9,
+            Column::Faults => 8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum, Default)]
+pub enum MatchStyle {
+    #[default]
+    Fuzzy,
+    Substring,
+}
+
+impl MatchStyle {
+    pub fn toggled(self) -> Self {
+        match self {
+            MatchStyle::Fuzzy => MatchStyle::Substring,
+            MatchStyle::Substring => MatchStyle::Fuzzy,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MatchStyle::Fuzzy => "fuzzy",
+            MatchStyle::Substring => "substring",
+        }
+    }
 }
 
-impl Default for SortField {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchWeights {
+    pub name: i64,
+    pub camel: i64,
+    pub cmdline: i64,
+    pub cwd: i64,
+    pub env: i64,
+}
+
+impl Default for SearchWeights {
     fn default() -> Self {
-        SortField::Cpu
+        Self {
+            name: 900_000,
+            camel: 880_000,
+            cmdline: 700_000,
+            cwd: 660_000,
+            env: 640_000,
+        }
+    }
+}
+
+impl SearchWeights {
+    /// applies a `field=value` override such as `cmdline=950000`.
+    pub fn apply(&mut self, spec: &str) -> Result<(), String> {
+        let (field, value) = spec
+            .split_once('=')
+            .ok_or_else(|| format!("expected field=value, got '{spec}'"))?;
+        let value: i64 = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid weight '{}' for {}", value.trim(), field.trim()))?;
+        match field.trim() {
+            "name" => self.name = value,
+            "camel" => self.camel = value,
+            "cmdline" => self.cmdline = value,
+            "cwd" => self.cwd = value,
+            "env" => self.env = value,
+            other => {
+                return Err(format!(
+                    "unknown search field '{other}' (expected name, camel, cmdline, cwd or env)"
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub theme: Theme,
+    pub selection_style: SelectionStyle,
+    pub signal_order: SignalOrder,
+    pub name_source: NameSource,
     pub show_all_processes: bool,
+    pub cpu_source: CpuSource,
     pub refresh_rate_ms: u64,
     pub initial_filter: Option<String>,
     pub initial_sort: SortField,
     pub sort_descending: bool,
+    pub match_style: MatchStyle,
+    pub debug_scores: bool,
+    pub search_weights: SearchWeights,
+    pub case_sensitive: bool,
+    pub columns: Vec<Column>,
+    /// size text columns to the first process list and keep them, instead of fixed widths.
+    pub lock_widths: bool,
+    /// decimals shown in the CPU% column.
+    pub cpu_precision: usize,
+    /// decimals shown in the MEM% column.
+    pub mem_precision: usize,
+    pub info_path_display: PathDisplay,
+    pub info_max_maps: usize,
+    pub info_max_open_files: usize,
+    pub info_max_environment: usize,
+    /// environment entries longer than this many characters are cut short in the info pane (0 keeps them whole).
+    pub info_env_entry_max: usize,
+    pub tree_style: TreeStyle,
+    pub tree_max_depth: Option<usize>,
+    pub tree_min_cpu: f32,
+    pub tree_min_memory_bytes: u64,
+    /// keep the info pane beside the tree instead of giving the tree the full width.
+    pub tree_with_info: bool,
+    /// subtrees with fewer processes than this are sent SIGTERM without a preview (0 always previews).
+    pub tree_confirm_min: usize,
+    pub enter_action: EnterAction,
+    /// show the refresh count and time since the last refresh in the header.
+    pub show_refresh_age: bool,
+    /// refreshes keep the selected process, on the same screen row, rather than the selected index.
+    pub pin_selection: bool,
+    /// start with processes under `idle_cpu_threshold` hidden from the list.
+    pub hide_idle: bool,
+    pub idle_cpu_threshold: f32,
+    pub swap_warn_bytes: u64,
+    pub memory_thresholds: MemoryThresholds,
+    /// color the Runtime column by age; `None` leaves it plain.
+    pub runtime_colors: Option<RuntimeThresholds>,
+    /// refuse to signal anything rated critical, confirmation or not.
+    pub safe_mode: bool,
+    /// pids and name globs that are never signalled without an explicit override.
+    pub protected: Vec<ProtectedTarget>,
+    /// kill keys are ignored for this long after a kill, so a held key can't walk down the list.
+    pub kill_cooldown: Duration,
+    /// kills of at least `arm_min_targets` processes are announced and held this long, so Esc
+    /// can still abort them; zero sends at once.
+    pub arm_delay: Duration,
+    pub arm_min_targets: usize,
+    /// exposes rarely needed signal options such as sigqueue payloads.
+    pub advanced_signals: bool,
+    /// capture the mouse for clicks and the wheel; off leaves text selection to the terminal.
+    pub mouse: bool,
+    /// settings file holding display options and saved views; `None` disables it.
+    pub config_file: Option<PathBuf>,
+    /// display options given explicitly on the command line; they beat the config file.
+    pub cli_display: DisplaySettings,
+    /// info pane limits given explicitly on the command line; they beat the `[info]` table.
+    pub cli_info: InfoSettings,
 }
@@ -0,0 +1,84 @@
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal};
+
+use crate::process::ProcessInfo;
+use crate::ui::tree_view::format_bytes;
+
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// the rows the tui last showed, as a plain table for the scrollback once it has closed.
+pub fn render(processes: &[ProcessInfo], color: bool) -> String {
+    let user_width = processes
+        .iter()
+        .map(|proc| proc.user.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("USER".len());
+    let mut out = String::new();
+    let header = format!(
+        "{:>7}  {:<user_width$}  {:>5}  {:>9}  COMMAND",
+        "PID", "USER", "CPU%", "MEM"
+    );
+    if color {
+        let _ = writeln!(out, "{BOLD}{header}{RESET}");
+    } else {
+        let _ = writeln!(out, "{header}");
+    }
+    for proc in processes {
+        let command = if proc.cmdline.is_empty() {
+            format!("[{}]", proc.name)
+        } else {
+            proc.cmdline.join(" ")
+        };
+        let _ = writeln!(
+            out,
+            "{:>7}  {:<user_width$}  {:>5.1}  {:>9}  {}",
+            proc.pid,
+            proc.user,
+            proc.cpu_percent,
+            format_bytes(proc.memory_bytes),
+            command
+        );
+    }
+    out
+}
+
+/// bold headers only on a terminal, and never when `NO_COLOR` is set to a non-empty value.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && io::stdout().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::process::{ProcessInfo, ProcessState};
+
+    fn process(pid: u32, name: &str, user: &str, cmdline: &[&str]) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cpu_percent: 12.25,
+            memory_bytes: 3 * 1024 * 1024,
+            user: user.to_string(),
+            cmdline: cmdline.iter().map(|arg| arg.to_string()).collect(),
+            state: ProcessState::Sleeping,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rows_line_up_under_the_header_without_color() {
+        let processes = vec![
+            process(812, "vim", "alice", &["vim", "notes.md"]),
+            process(2, "kthreadd", "root", &[]),
+        ];
+        let table = render(&processes, false);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "    PID  USER    CPU%        MEM  COMMAND");
+        assert_eq!(lines[1], "    812  alice   12.2     3.0 MB  vim notes.md");
+        assert_eq!(lines[2], "      2  root    12.2     3.0 MB  [kthreadd]");
+        assert!(!table.contains('\x1b'));
+        assert!(render(&processes, true).starts_with("\x1b[1m"));
+    }
+}
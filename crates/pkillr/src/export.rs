@@ -0,0 +1,153 @@
+use std::fmt::Write as _;
+
+use crate::json::{self, Json, quote};
+use crate::process::ProcessInfo;
+
+/// bumped whenever a field is renamed, removed or changes meaning; adding one doesn't.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// the on-disk shape of one process. `ProcessInfo` can change freely; this only
+/// changes together with `SCHEMA_VERSION`. the environment is left out on purpose.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProcessDto {
+    pub schema_version: u32,
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub user: String,
+    pub state: String,
+    /// written as `null` when it isn't finite, which reads back as 0.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "finite_or_null"))]
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub runtime_secs: u64,
+    pub cpu_time_ms: u64,
+    pub cmdline: Vec<String>,
+    pub cwd: Option<String>,
+}
+
+impl From<&ProcessInfo> for ProcessDto {
+    fn from(proc: &ProcessInfo) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            pid: proc.pid,
+            parent_pid: proc.parent_pid,
+            name: proc.name.clone(),
+            user: proc.user.clone(),
+            state: proc.state.as_str().to_string(),
+            cpu_percent: proc.cpu_percent,
+            memory_bytes: proc.memory_bytes,
+            runtime_secs: proc.runtime.as_secs(),
+            cpu_time_ms: proc.cpu_time.as_millis() as u64,
+            cmdline: proc.cmdline.clone(),
+            cwd: proc.cwd.clone(),
+        }
+    }
+}
+
+impl ProcessDto {
+    /// one json object on a single line, so exports can be written as json lines.
+    pub fn to_json(&self) -> String {
+        let optional_number =
+            |value: Option<u32>| value.map_or("null".to_string(), |v| v.to_string());
+        let cmdline: Vec<String> = self.cmdline.iter().map(|arg| quote(arg)).collect();
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "{{\"schema_version\": {}, \"pid\": {}, \"parent_pid\": {}, \"name\": {}, \"user\": {}, \
+             \"state\": {}, \"cpu_percent\": {}, \"memory_bytes\": {}, \"runtime_secs\": {}, \
+             \"cpu_time_ms\": {}, \"cmdline\": [{}], \"cwd\": {}}}",
+            self.schema_version,
+            self.pid,
+            optional_number(self.parent_pid),
+            quote(&self.name),
+            quote(&self.user),
+            quote(&self.state),
+            finite_number(self.cpu_percent),
+            self.memory_bytes,
+            self.runtime_secs,
+            self.cpu_time_ms,
+            cmdline.join(", "),
+            self.cwd.as_deref().map_or("null".to_string(), quote),
+        );
+        out
+    }
+
+    /// reads a record written by `to_json`; newer schema versions are refused rather than misread.
+    pub fn from_json(text: &str) -> Result<ProcessDto, String> {
+        Self::from_value(&json::parse(text)?)
+    }
+
+    /// `from_json` for a record already parsed, e.g. one element of a snapshot's array.
+    pub fn from_value(value: &Json) -> Result<ProcessDto, String> {
+        let version = value
+            .field("schema_version")
+            .and_then(Json::as_number)
+            .ok_or("missing schema_version")? as u32;
+        if version > SCHEMA_VERSION {
+            return Err(format!(
+                "schema version {version} is newer than the supported {SCHEMA_VERSION}"
+            ));
+        }
+        let number = |name: &str| {
+            value
+                .field(name)
+                .and_then(Json::as_number)
+                .ok_or_else(|| format!("missing number {name}"))
+        };
+        let text = |name: &str| {
+            value
+                .field(name)
+                .and_then(Json::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| format!("missing string {name}"))
+        };
+        let optional = |name: &str| value.field(name).filter(|field| **field != Json::Null);
+        Ok(ProcessDto {
+            schema_version: version,
+            pid: number("pid")? as u32,
+            parent_pid: optional("parent_pid")
+                .and_then(Json::as_number)
+                .map(|pid| pid as u32),
+            name: text("name")?,
+            user: text("user")?,
+            state: text("state")?,
+            cpu_percent: if value.field("cpu_percent") == Some(&Json::Null) {
+                0.0
+            } else {
+                number("cpu_percent")? as f32
+            },
+            memory_bytes: number("memory_bytes")? as u64,
+            runtime_secs: number("runtime_secs")? as u64,
+            cpu_time_ms: number("cpu_time_ms")? as u64,
+            cmdline: value
+                .field("cmdline")
+                .and_then(Json::as_array)
+                .ok_or("missing array cmdline")?
+                .iter()
+                .map(|arg| arg.as_str().map(str::to_string))
+                .collect::<Option<_>>()
+                .ok_or("cmdline holds a non-string")?,
+            cwd: optional("cwd").and_then(Json::as_str).map(str::to_string),
+        })
+    }
+}
+
+/// json has no NaN or infinity, so those become `null`.
+fn finite_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+fn finite_or_null<S: serde::Serializer>(value: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    if value.is_finite() {
+        serializer.serialize_f32(*value)
+    } else {
+        serializer.serialize_none()
+    }
+}
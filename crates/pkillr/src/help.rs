@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use crate::signals::Signal;
+
+/// one row of the keyboard reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpEntry {
+    pub section: &'static str,
+    pub keys: Cow<'static, str>,
+    pub description: Cow<'static, str>,
+}
+
+impl HelpEntry {
+    /// case-insensitive substring match on the keys, description or section.
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_lowercase();
+        filter.is_empty()
+            || self.description.to_lowercase().contains(&filter)
+            || self.section.to_lowercase().contains(&filter)
+            || self.keys.to_lowercase().contains(&filter)
+    }
+}
+
+const fn entry(section: &'static str, keys: &'static str, description: &'static str) -> HelpEntry {
+    HelpEntry {
+        section,
+        keys: Cow::Borrowed(keys),
+        description: Cow::Borrowed(description),
+    }
+}
+
+pub const ENTRIES: &[HelpEntry] = &[
+    entry("NAVIGATION", "↑↓ / j k", "move selection"),
+    entry("NAVIGATION", "g G", "jump top/bottom"),
+    entry("NAVIGATION", "J / R", "jump to top cpu / memory"),
+    entry("NAVIGATION", "D", "next process stuck in disk sleep"),
+    entry("NAVIGATION", "< >", "cycle sort column"),
+    entry("NAVIGATION", "Esc", "close info/tree"),
+    entry("ACTIONS", "/", "fuzzy search"),
+    entry("ACTIONS", "Ctrl+F", "fuzzy / substring match"),
+    entry("ACTIONS", "Tab", "search: push the query as a filter layer"),
+    entry("ACTIONS", "Backspace", "remove the last filter layer"),
+    entry("ACTIONS", "Ctrl+E", "toggle case-sensitive"),
+    entry("ACTIONS", "/^...$/", "regex filter"),
+    entry("ACTIONS", "/killed", "history filter"),
+    entry("ACTIONS", "swap>100M", "swap usage filter"),
+    entry("ACTIONS", "exact:ssh", "name matches exactly"),
+    entry("ACTIONS", "net:true", "only processes with tcp/udp sockets"),
+    entry("ACTIONS", "user:root", "processes owned by a user"),
+    entry("ACTIONS", "U", "processes by user (Enter filters)"),
+    entry("ACTIONS", "Space", "select / toggle"),
+    entry("ACTIONS", "*", "invert selection"),
+    entry("ACTIONS", "Ctrl+A", "select all filtered"),
+    entry("ACTIONS", "u", "clear selection"),
+    entry("ACTIONS", "k", "kill (SIGTERM)"),
+    entry(
+        "ACTIONS",
+        "Enter",
+        "kill, signal menu or info (--enter-action)",
+    ),
+    entry("ACTIONS", "K", "force kill (SIGKILL)"),
+    entry("ACTIONS", "x", "kill tree (preview)"),
+    entry("ACTIONS", "X", "signal all filtered (preview)"),
+    entry("ACTIONS", "b", "signal all with this name (preview)"),
+    entry(
+        "ACTIONS",
+        "w",
+        "signal this process's whole cgroup (preview)",
+    ),
+    entry("ACTIONS", "s", "open signal menu"),
+    entry("ACTIONS", "S <n>", "send signal number n"),
+    entry("ACTIONS", "!", "override a protected-process refusal"),
+    entry("ACTIONS", "v (menu)", "sigqueue with a value (--advanced)"),
+    entry("ACTIONS", "o (menu)", "order signals by number / name"),
+    entry("ACTIONS", "y", "copy kill command"),
+    entry("ACTIONS", "Y", "copy selected PIDs"),
+    entry("ACTIONS", "L", "select PIDs from a typed list"),
+    entry(
+        "ACTIONS",
+        "O",
+        "page /proc status, stat and limits ($PAGER)",
+    ),
+    entry("ACTIONS", "B", "kill + re-kill respawns"),
+    entry(
+        "ACTIONS",
+        "Ctrl+T",
+        "preview killing everything on this row's tty",
+    ),
+    entry("VIEWS", "i", "toggle info pane"),
+    entry("VIEWS", "Tab", "switch info focus"),
+    entry("VIEWS", "e/f/m/n/c", "toggle info sections"),
+    entry("VIEWS", "A", "info: one argument per line / joined"),
+    entry("VIEWS", "1-9", "info: jump to section"),
+    entry("VIEWS", "[ / ]", "info: previous / next section"),
+    entry("VIEWS", "p", "info: full / short / basename paths"),
+    entry("VIEWS", "+", "load more info entries"),
+    entry("VIEWS", "=", "mark / clear environment diff reference"),
+    entry("VIEWS", "t", "toggle process tree"),
+    entry("VIEWS", "P / C", "jump to parent / child"),
+    entry("VIEWS", "H", "toggle thread rows"),
+    entry("VIEWS", "a", "toggle other users' processes"),
+    entry("VIEWS", "I", "hide idle processes (--idle-threshold)"),
+    entry("VIEWS", "p", "tree: show pgid/sid"),
+    entry("VIEWS", "i", "tree: info pane (--tree-info-pane)"),
+    entry("VIEWS", "o", "tree: own vs subtree cpu/mem"),
+    entry("VIEWS", "[ / ]", "tree: less / more depth"),
+    entry("VIEWS", "l", "tree: hide idle branches"),
+    entry("VIEWS", "T", "tree rooted at selection"),
+    entry("VIEWS", "Z", "tree: zoom out to all"),
+    entry("VIEWS", "zz", "center the selected row"),
+    entry("VIEWS", "W", "lock column widths to the current rows"),
+    entry("VIEWS", ".", "cycle CPU% decimals (0-2)"),
+    entry("VIEWS", ",", "cycle MEM% decimals (0-2)"),
+    entry("VIEWS", "Esc/C-l", "clear the active filter"),
+    entry("VIEWS", "v / V", "pick / save a view"),
+    entry("VIEWS", "h", "signal history"),
+    entry("GENERAL", "Ctrl+R", "reload the config file"),
+    entry("GENERAL", "Ctrl+U", "show time since the last refresh"),
+    entry("GENERAL", "?", "this help"),
+    entry("GENERAL", "q", "quit"),
+    entry("GENERAL", "Ctrl+C", "quit instantly"),
+];
+
+/// every signal as `15 SIGTERM`, built from `Signal::all()` so it can't drift from the enum.
+pub fn signal_entries() -> Vec<HelpEntry> {
+    Signal::all()
+        .iter()
+        .map(|signal| HelpEntry {
+            section: "SIGNALS",
+            keys: Cow::Owned(format!("{:>2} {}", signal.number(), signal.name())),
+            description: Cow::Borrowed(signal.description()),
+        })
+        .collect()
+}
+
+/// key bindings then the signal reference, keeping those matching `filter`.
+pub fn filtered(filter: &str) -> Vec<HelpEntry> {
+    ENTRIES
+        .iter()
+        .cloned()
+        .chain(signal_entries())
+        .filter(|entry| entry.matches(filter))
+        .collect()
+}
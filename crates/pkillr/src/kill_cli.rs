@@ -0,0 +1,141 @@
+use std::collections::{HashMap, HashSet};
+
+use nix::unistd::getppid;
+
+use crate::process::{ProcessInfo, ProcessManager};
+use crate::risk::{RiskLevel, assess_risk};
+use crate::signals::{Signal, safe_kill};
+use pkillr::{ProtectedTarget, protecting};
+
+/// every pid was signalled.
+const EXIT_OK: i32 = 0;
+/// a pid was refused or the signal failed for another reason.
+const EXIT_FAILED: i32 = 1;
+/// at least one pid did not exist.
+const EXIT_NOT_FOUND: i32 = 3;
+/// at least one pid needed more privileges.
+const EXIT_DENIED: i32 = 4;
+
+/// parses one entry of `--kill`, rejecting zero and negative pids.
+pub fn parse_pid(text: &str) -> Result<u32, String> {
+    let text = text.trim();
+    let value: i64 = text.parse().map_err(|_| format!("'{text}' is not a pid"))?;
+    if value <= 0 || value > i64::from(i32::MAX) {
+        return Err(format!(
+            "'{text}' is not a valid pid (must be 1..={})",
+            i32::MAX
+        ));
+    }
+    Ok(value as u32)
+}
+
+pub fn parse_signal(text: &str) -> Result<Signal, String> {
+    Signal::parse(text).ok_or_else(|| format!("unknown signal '{text}'"))
+}
+
+/// signals each pid through the same guards as the tui and prints one line per
+/// pid. returns the exit code: the most severe failure wins.
+pub fn run(pids: &[u32], signal: Signal, safe_mode: bool, protected: &[ProtectedTarget]) -> i32 {
+    let processes: HashMap<u32, ProcessInfo> = if safe_mode || !protected.is_empty() {
+        ProcessManager::new()
+            .get_processes(true)
+            .into_iter()
+            .map(|proc| (proc.pid, proc))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+    let shell_pid = getppid().as_raw() as u32;
+
+    let mut code = EXIT_OK;
+    let mut seen = HashSet::new();
+    for &pid in pids.iter().filter(|pid| seen.insert(**pid)) {
+        let refused = processes
+            .get(&pid)
+            .and_then(|proc| refusal(proc, shell_pid, safe_mode, protected));
+        if let Some(reason) = refused {
+            eprintln!("{pid}: refused: {reason}");
+            code = code.max(EXIT_FAILED);
+            continue;
+        }
+        match safe_kill(pid, signal) {
+            Ok(()) => println!("{pid}: sent {}", signal.name()),
+            Err(err) => {
+                let lowered = err.to_ascii_lowercase();
+                let (label, failure) = if lowered.contains("not found") {
+                    ("not found", EXIT_NOT_FOUND)
+                } else if lowered.contains("permission") {
+                    ("permission denied", EXIT_DENIED)
+                } else {
+                    ("failed", EXIT_FAILED)
+                };
+                eprintln!("{pid}: {label}: {err}");
+                code = code.max(failure);
+            }
+        }
+    }
+    code
+}
+
+/// why `proc` must not be signalled, if anything; the tui's own checks follow in `safe_kill`.
+/// there is no `!` override here, a protected target has to come off the list first.
+fn refusal(
+    proc: &ProcessInfo,
+    shell_pid: u32,
+    safe_mode: bool,
+    protected: &[ProtectedTarget],
+) -> Option<String> {
+    if let Some(target) = protecting(protected, proc) {
+        return Some(format!("{} is protected by {target}", proc.name));
+    }
+    if !safe_mode {
+        return None;
+    }
+    assess_risk(proc, shell_pid)
+        .filter(|risk| risk.level == RiskLevel::Critical)
+        .map(|risk| format!("safe mode: {} is critical ({})", proc.name, risk.reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::refusal;
+    use crate::process::ProcessInfo;
+    use pkillr::ProtectedTarget;
+
+    fn named(pid: u32, name: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            user: "tester".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn safe_mode_refuses_critical_processes() {
+        let shell = named(4242, "gnome-shell");
+        assert_eq!(
+            refusal(&shell, 1, true, &[]).as_deref(),
+            Some("safe mode: gnome-shell is critical (desktop shell)")
+        );
+        assert_eq!(refusal(&shell, 1, false, &[]), None);
+        assert_eq!(refusal(&named(4243, "sleep"), 1, true, &[]), None);
+    }
+
+    #[test]
+    fn protected_targets_are_refused() {
+        let protected = [
+            ProtectedTarget::Pid(4250),
+            ProtectedTarget::Name("postgres*".to_string()),
+        ];
+        assert_eq!(
+            refusal(&named(4251, "postgres"), 1, false, &protected).as_deref(),
+            Some("postgres is protected by 'postgres*'")
+        );
+        assert_eq!(
+            refusal(&named(4250, "sleep"), 1, false, &protected).as_deref(),
+            Some("sleep is protected by PID 4250")
+        );
+        assert_eq!(refusal(&named(4252, "sleep"), 1, false, &protected), None);
+    }
+}
@@ -0,0 +1,46 @@
+//! process inspection and signalling used by the pkillr tui.
+//!
+//! the binary is a thin terminal front end over these modules; they can also
+//! be used directly to enumerate processes, rate how risky a kill is and send
+//! signals with the same safety guards.
+
+/// application state and key handling behind the tui.
+pub(crate) mod app;
+/// cli-facing configuration, themes and table columns.
+pub mod config;
+/// versioned process records for json export, independent of `ProcessInfo`'s layout.
+pub(crate) mod export;
+/// searchable keyboard reference.
+pub(crate) mod help;
+/// the small json reader and string quoting shared by snapshots, exports and saved views.
+pub(crate) mod json;
+/// signal counters in the prometheus text format, served with the `metrics` feature.
+pub(crate) mod metrics;
+/// process enumeration and `/proc` detail readers.
+pub mod process;
+/// pids and name globs that signals are refused for, from the `[safety]` table.
+pub(crate) mod protect;
+/// risk assessment for kill targets.
+pub mod risk;
+/// display and info pane options from the `[display]` and `[info]` tables of the config file.
+pub(crate) mod settings;
+/// signal definitions and guarded signal delivery.
+pub mod signals;
+/// named search queries saved in the config file.
+pub(crate) mod views;
+
+// the few items of the tui-only modules above that the binary and its tests reach for.
+pub use app::{
+    App, AppMode, EnvReference, InfoSection, SortColumn, StatusLevel, TableGeometry,
+    TreeKillPrompt, TreeRow, UserSort, process_count,
+};
+pub use export::{ProcessDto, SCHEMA_VERSION};
+pub use json::{Json, parse as parse_json, quote as quote_json};
+pub use metrics::Metrics;
+#[cfg(feature = "metrics")]
+pub use metrics::serve as serve_metrics;
+pub use protect::{ProtectedTarget, Safety, load as load_safety, protecting};
+pub use settings::{DisplaySettings, InfoSettings};
+pub use views::{
+    SavedView, default_path as default_config_path, load as load_views, save as save_views,
+};
@@ -1,26 +1,42 @@
-mod app;
-mod config;
-mod process;
-mod signals;
+mod clipboard;
+mod exit_table;
+mod kill_cli;
+mod pager;
+mod snapshot;
 mod ui;
+mod watch;
 
-use std::io::{self, Stdout};
+use pkillr::{config, process, risk, signals};
+
+use std::io::{self, Stdout, Write};
+use std::panic;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use clap::builder::styling::{Style, Styles};
-use clap::{ColorChoice, CommandFactory, FromArgMatches, Parser};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, ColorChoice, CommandFactory, FromArgMatches, Parser};
 use crossterm::{
     cursor::{Hide, Show},
-    event::{self, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 
-use app::App;
-use config::{Config, SortField, Theme};
+use config::{
+    Column, Config, CpuSource, EnterAction, MatchStyle, MemoryScale, MemoryThresholds, NameSource,
+    PathDisplay, RuntimeThresholds, SearchWeights, SelectionStyle, SignalOrder, SortField, Theme,
+    TreeStyle, mib_to_bytes,
+};
+use pkillr::{App, DisplaySettings, InfoSettings, ProtectedTarget, Safety};
+use signals::Signal;
 const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// queued events handled before the next redraw, so a held key can't starve the screen.
+const MAX_EVENTS_PER_FRAME: usize = 64;
 
 #[derive(Debug, Parser)]
 #[command(name = "pkillr", about = "Interactive TUI process killer", version)]
@@ -41,9 +57,285 @@ pub struct Cli {
     #[arg(long = "theme", value_enum, default_value_t = Theme::Pink)]
     pub theme: Theme,
 
+    /// how the selected row is marked: background color, gutter marker or reversed text.
+    #[arg(long = "selection-style", value_enum, default_value_t = SelectionStyle::Background)]
+    pub selection_style: SelectionStyle,
+
+    /// list the signal menu by signal number or alphabetically by name.
+    #[arg(long = "signal-order", value_enum, default_value_t = SignalOrder::Number)]
+    pub signal_order: SignalOrder,
+
+    /// draw the tree with unicode box characters or plain ascii (auto follows the locale).
+    #[arg(long = "tree-style", value_enum, default_value_t = TreeStyle::Auto)]
+    pub tree_style: TreeStyle,
+
+    /// what Enter does on a row: kill with SIGTERM, open the signal menu, or toggle the info pane.
+    #[arg(long = "enter-action", value_enum, default_value_t = EnterAction::Kill)]
+    pub enter_action: EnterAction,
+
+    /// fill the Name column from the comm name or the argv[0] basename.
+    #[arg(long = "name-from", value_enum, default_value_t = NameSource::Comm)]
+    pub name_from: NameSource,
+
+    /// default matching used by the search box.
+    #[arg(long = "match", value_enum, default_value_t = MatchStyle::Fuzzy)]
+    pub match_style: MatchStyle,
+
+    /// table columns in display order, e.g. `name,cpu,pid`; `time` and `faults` are opt-in.
+    #[arg(long = "columns", value_enum, value_delimiter = ',', num_args = 1..)]
+    pub columns: Vec<Column>,
+
+    /// make fuzzy and substring search case-sensitive.
+    #[arg(long = "case-sensitive")]
+    pub case_sensitive: bool,
+
+    /// override a search ranking weight, e.g. `cmdline=950000` (repeatable).
+    #[arg(long = "search-weight", value_name = "field=value")]
+    pub search_weights: Vec<String>,
+
+    /// signal these comma-separated pids without starting the tui. exits 3 if a
+    /// pid was not found, 4 on permission errors and 1 on any other failure,
+    /// including a protected process or one refused by safe mode.
+    #[arg(
+        long = "kill",
+        value_name = "PIDS",
+        value_delimiter = ',',
+        value_parser = kill_cli::parse_pid
+    )]
+    pub kill: Vec<u32>,
+
+    /// signal sent by --kill, by name or number.
+    #[arg(
+        long = "signal",
+        value_name = "SIGNAL",
+        value_parser = kill_cli::parse_signal,
+        default_value = "SIGTERM",
+        requires = "kill"
+    )]
+    pub signal: Signal,
+
+    /// print the last table shown to stdout after quitting, so it stays in the scrollback.
+    #[arg(long = "print-on-exit")]
+    pub print_on_exit: bool,
+
+    /// leave the mouse to the terminal, so text can be selected; clicks and the wheel do nothing.
+    #[arg(long = "no-mouse")]
+    pub no_mouse: bool,
+
+    /// write every process (pid, name, user, cpu, memory, command) to FILE as json and exit.
+    #[arg(long = "snapshot", value_name = "FILE", conflicts_with = "diff")]
+    pub snapshot: Option<PathBuf>,
+
+    /// compare two --snapshot files and print +/-/~ lines. exits 0 when they
+    /// match, 1 when processes differ and 2 when a file can't be read.
+    #[arg(long = "diff", value_names = ["BEFORE", "AFTER"], num_args = 2)]
+    pub diff: Vec<PathBuf>,
+
+    /// wait until no process of any user matches FILTER, then exit without starting the tui.
+    #[arg(
+        long = "wait-gone",
+        value_name = "FILTER",
+        conflicts_with = "wait_appear"
+    )]
+    pub wait_gone: Option<String>,
+
+    /// wait until a process matches FILTER, then print its pids and exit.
+    #[arg(long = "wait-appear", value_name = "FILTER")]
+    pub wait_appear: Option<String>,
+
+    /// give up waiting after this long, e.g. `30s` or `2m` (exit code 1).
+    #[arg(long = "timeout", value_name = "duration", value_parser = watch::parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// how often to re-check while waiting; must be above zero.
+    #[arg(
+        long = "poll-interval",
+        value_name = "duration",
+        value_parser = watch::parse_poll_interval,
+        default_value = "500ms"
+    )]
+    pub poll_interval: Duration,
+
     /// refresh interval in milliseconds.
     #[arg(long = "refresh-rate", value_name = "ms", default_value_t = 800)]
     pub refresh_rate: u64,
+
+    /// read cpu% from sysinfo, or diff /proc/<pid>/stat between refreshes for top-like figures.
+    #[arg(long = "cpu-source", value_enum, default_value_t = CpuSource::Sysinfo)]
+    pub cpu_source: CpuSource,
+
+    /// size the name, user and runtime columns to the first process list and keep
+    /// them steady (re-measure with W).
+    #[arg(long = "lock-widths")]
+    pub lock_widths: bool,
+
+    /// decimals in the CPU% column, 0 to 2 (cycle with . at runtime).
+    #[arg(long = "cpu-decimals", value_name = "n", default_value_t = 1, value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub cpu_decimals: u8,
+
+    /// decimals in the MEM% column, 0 to 2 (cycle with , at runtime).
+    #[arg(long = "mem-decimals", value_name = "n", default_value_t = 1, value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub mem_decimals: u8,
+
+    /// deepest tree level to expand; deeper branches are folded (adjust with [ and ]).
+    #[arg(long = "tree-depth", value_name = "levels")]
+    pub tree_depth: Option<usize>,
+
+    /// cpu percent below which the tree's `l` filter hides a branch.
+    #[arg(long = "tree-min-cpu", value_name = "percent", default_value_t = 1.0)]
+    pub tree_min_cpu: f32,
+
+    /// memory in MiB below which the tree's `l` filter hides a branch.
+    #[arg(long = "tree-min-mem", value_name = "MiB", default_value_t = 100)]
+    pub tree_min_mem: u64,
+
+    /// keep the info pane open beside the process tree (toggle it there with i).
+    #[arg(long = "tree-info-pane")]
+    pub tree_info_pane: bool,
+
+    /// kill subtrees smaller than this many processes without a preview; risky ones still ask (0 always previews).
+    #[arg(long = "tree-confirm-min", value_name = "count", default_value_t = 0)]
+    pub tree_confirm_min: usize,
+
+    /// start with idle processes hidden (toggle with I).
+    #[arg(long = "hide-idle")]
+    pub hide_idle: bool,
+
+    /// show how long ago the list was refreshed (toggle with Ctrl+U).
+    #[arg(long = "show-refresh-age")]
+    pub show_refresh_age: bool,
+
+    /// keep the selected process on the same screen row while rows above it come and go.
+    #[arg(long = "pin-selection")]
+    pub pin_selection: bool,
+
+    /// cpu percent below which a process counts as idle.
+    #[arg(long = "idle-threshold", value_name = "percent", default_value_t = 0.5)]
+    pub idle_threshold: f32,
+
+    /// highlight processes with at least this many MiB swapped out.
+    #[arg(long = "swap-warn", value_name = "MiB", default_value_t = 100)]
+    pub swap_warn: u64,
+
+    /// measure memory color thresholds against total ram (percent) or fixed sizes (absolute).
+    #[arg(long = "mem-scale", value_enum, default_value_t = MemoryScale::Percent)]
+    pub mem_scale: MemoryScale,
+
+    /// percent of total ram at which memory turns yellow (with --mem-scale percent).
+    #[arg(long = "mem-warn-pct", value_name = "percent", default_value_t = 5.0)]
+    pub mem_warn_pct: f32,
+
+    /// percent of total ram at which memory turns red (with --mem-scale percent).
+    #[arg(long = "mem-crit-pct", value_name = "percent", default_value_t = 20.0)]
+    pub mem_crit_pct: f32,
+
+    /// MiB at which memory turns yellow (with --mem-scale absolute).
+    #[arg(long = "mem-warn-mib", value_name = "MiB", default_value_t = 500)]
+    pub mem_warn_mib: u64,
+
+    /// MiB at which memory turns red (with --mem-scale absolute).
+    #[arg(long = "mem-crit-mib", value_name = "MiB", default_value_t = 2048)]
+    pub mem_crit_mib: u64,
+
+    /// color the Runtime column: new processes bright, long-lived ones dim.
+    #[arg(long = "runtime-colors")]
+    pub runtime_colors: bool,
+
+    /// seconds a process counts as new for --runtime-colors.
+    #[arg(long = "runtime-fresh", value_name = "secs", default_value_t = 60)]
+    pub runtime_fresh: u64,
+
+    /// seconds after which a process counts as long-lived for --runtime-colors.
+    #[arg(long = "runtime-old", value_name = "secs", default_value_t = 86_400)]
+    pub runtime_old: u64,
+
+    /// refuse to signal critical processes (init, the desktop shell, ...), including inside
+    /// trees and with --kill. `safe_mode = true` under [safety] in the config does the same.
+    #[arg(long = "safe")]
+    pub safe: bool,
+
+    /// never signal this pid or process-name glob without pressing ! to override (repeatable).
+    /// --kill refuses it outright.
+    #[arg(long = "protect", value_name = "pid|glob", value_parser = ProtectedTarget::parse)]
+    pub protect: Vec<ProtectedTarget>,
+
+    /// enable advanced signal options (sigqueue with a value via `v` in the signal menu).
+    #[arg(long = "advanced")]
+    pub advanced: bool,
+
+    /// serve prometheus metrics on this port at /metrics.
+    #[cfg(feature = "metrics")]
+    #[arg(long = "metrics-port", value_name = "port")]
+    pub metrics_port: Option<u16>,
+
+    /// address the metrics endpoint listens on.
+    #[cfg(feature = "metrics")]
+    #[arg(long = "metrics-addr", value_name = "ip", default_value = "127.0.0.1")]
+    pub metrics_addr: std::net::IpAddr,
+
+    /// how the info pane prints paths: full, short (~ and …) or basename (cycle with p).
+    #[arg(long = "paths", value_enum, default_value_t = PathDisplay::Full)]
+    pub paths: PathDisplay,
+
+    /// memory map entries shown per page in the info pane (0 shows all); overrides `max_maps` in [info].
+    #[arg(long = "max-maps", value_name = "count", default_value_t = 64)]
+    pub max_maps: usize,
+
+    /// open file entries shown per page in the info pane (0 shows all); overrides `max_open_files` in [info].
+    #[arg(long = "max-open-files", value_name = "count", default_value_t = 64)]
+    pub max_open_files: usize,
+
+    /// environment entries shown per page in the info pane (0 shows all); overrides `max_env` in [info].
+    #[arg(long = "max-env", value_name = "count", default_value_t = 64)]
+    pub max_env: usize,
+
+    /// characters shown of each environment entry before it is cut short (0 shows all).
+    #[arg(long = "env-max-len", value_name = "chars", default_value_t = 512)]
+    pub env_max_len: usize,
+
+    /// milliseconds after a kill during which kill keys are ignored (0 disables).
+    #[arg(long = "kill-cooldown", value_name = "ms", default_value_t = 300)]
+    pub kill_cooldown: u64,
+
+    /// announce a kill and hold it this many milliseconds, abortable with Esc (0 sends at once).
+    #[arg(long = "arm-delay", value_name = "ms", default_value_t = 0)]
+    pub arm_delay: u64,
+
+    /// only hold kills of at least this many processes (see --arm-delay).
+    #[arg(long = "arm-min-targets", value_name = "count", default_value_t = 2)]
+    pub arm_min_targets: usize,
+}
+
+/// display flags the user actually passed, so they keep precedence over the config file.
+fn explicit_display_settings(matches: &ArgMatches, args: &Cli) -> DisplaySettings {
+    let explicit = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+    DisplaySettings {
+        theme: explicit("theme").then_some(args.theme),
+        selection_style: explicit("selection_style").then_some(args.selection_style),
+        signal_order: explicit("signal_order").then_some(args.signal_order),
+        tree_style: explicit("tree_style").then_some(args.tree_style),
+        enter_action: explicit("enter_action").then_some(args.enter_action),
+        mem_scale: explicit("mem_scale").then_some(args.mem_scale),
+        mem_warn_pct: explicit("mem_warn_pct").then_some(args.mem_warn_pct),
+        mem_crit_pct: explicit("mem_crit_pct").then_some(args.mem_crit_pct),
+        mem_warn_mib: explicit("mem_warn_mib").then_some(args.mem_warn_mib),
+        mem_crit_mib: explicit("mem_crit_mib").then_some(args.mem_crit_mib),
+        swap_warn_mib: explicit("swap_warn").then_some(args.swap_warn),
+        runtime_colors: explicit("runtime_colors").then_some(true),
+        runtime_fresh: explicit("runtime_fresh").then_some(args.runtime_fresh),
+        runtime_old: explicit("runtime_old").then_some(args.runtime_old),
+        mouse: args.no_mouse.then_some(false),
+        columns: (!args.columns.is_empty()).then(|| args.columns.clone()),
+    }
+}
+
+fn explicit_info_settings(matches: &ArgMatches, args: &Cli) -> InfoSettings {
+    let explicit = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+    InfoSettings {
+        max_maps: explicit("max_maps").then_some(args.max_maps),
+        max_open_files: explicit("max_open_files").then_some(args.max_open_files),
+        max_env: explicit("max_env").then_some(args.max_env),
+    }
 }
 
 fn main() -> Result<()> {
@@ -52,26 +344,146 @@ fn main() -> Result<()> {
         .styles(clap_styles())
         .get_matches();
     let args = Cli::from_arg_matches(&matches).expect("cli parse failure");
+    let mut search_weights = SearchWeights::default();
+    for spec in &args.search_weights {
+        search_weights
+            .apply(spec)
+            .map_err(|err| anyhow!(err))
+            .context("invalid --search-weight")?;
+    }
     let config = Config {
         theme: args.theme,
+        selection_style: args.selection_style,
+        signal_order: args.signal_order,
+        name_source: args.name_from,
         show_all_processes: args.all,
+        cpu_source: args.cpu_source,
         refresh_rate_ms: args.refresh_rate,
         initial_filter: args.filter.clone(),
         initial_sort: args.sort_by,
         sort_descending: true,
+        match_style: args.match_style,
+        debug_scores: std::env::var("PKILLR_DEBUG").is_ok_and(|value| value == "1"),
+        search_weights,
+        case_sensitive: args.case_sensitive,
+        columns: if args.columns.is_empty() {
+            Column::DEFAULT_ORDER.to_vec()
+        } else {
+            args.columns.clone()
+        },
+        lock_widths: args.lock_widths,
+        cpu_precision: args.cpu_decimals as usize,
+        mem_precision: args.mem_decimals as usize,
+        info_path_display: args.paths,
+        info_max_maps: args.max_maps,
+        info_max_open_files: args.max_open_files,
+        info_max_environment: args.max_env,
+        info_env_entry_max: args.env_max_len,
+        kill_cooldown: Duration::from_millis(args.kill_cooldown),
+        arm_delay: Duration::from_millis(args.arm_delay),
+        arm_min_targets: args.arm_min_targets,
+        tree_style: args.tree_style,
+        tree_max_depth: args.tree_depth,
+        tree_min_cpu: args.tree_min_cpu,
+        tree_min_memory_bytes: mib_to_bytes(args.tree_min_mem),
+        tree_with_info: args.tree_info_pane,
+        tree_confirm_min: args.tree_confirm_min,
+        enter_action: args.enter_action,
+        show_refresh_age: args.show_refresh_age,
+        pin_selection: args.pin_selection,
+        hide_idle: args.hide_idle,
+        idle_cpu_threshold: args.idle_threshold,
+        swap_warn_bytes: mib_to_bytes(args.swap_warn),
+        memory_thresholds: MemoryThresholds {
+            scale: args.mem_scale,
+            warn_percent: args.mem_warn_pct,
+            critical_percent: args.mem_crit_pct,
+            warn_bytes: mib_to_bytes(args.mem_warn_mib),
+            critical_bytes: mib_to_bytes(args.mem_crit_mib),
+        },
+        runtime_colors: args.runtime_colors.then_some(RuntimeThresholds {
+            fresh: Duration::from_secs(args.runtime_fresh),
+            old: Duration::from_secs(args.runtime_old),
+        }),
+        safe_mode: args.safe,
+        protected: args.protect.clone(),
+        advanced_signals: args.advanced,
+        mouse: !args.no_mouse,
+        config_file: pkillr::default_config_path(),
+        cli_display: explicit_display_settings(&matches, &args),
+        cli_info: explicit_info_settings(&matches, &args),
     };
 
+    if let Some(path) = &args.snapshot {
+        std::process::exit(snapshot::write(path));
+    }
+    if let [before, after] = args.diff.as_slice() {
+        std::process::exit(snapshot::diff_files(before, after));
+    }
+
+    // a [safety] table that doesn't parse would leave everything unguarded, so don't start.
+    let safety = match &config.config_file {
+        Some(path) => pkillr::load_safety(path)
+            .map_err(|err| anyhow!(err))
+            .context("invalid [safety] config")?,
+        None => Safety::default(),
+    };
+
+    if !args.kill.is_empty() {
+        let safe_mode = config.safe_mode || safety.safe_mode;
+        let mut protected = safety.protected;
+        protected.extend(config.protected.iter().cloned());
+        std::process::exit(kill_cli::run(
+            &args.kill,
+            args.signal,
+            safe_mode,
+            &protected,
+        ));
+    }
+
+    let wait = match (&args.wait_gone, &args.wait_appear) {
+        (Some(filter), _) => Some((filter, watch::WaitCondition::Gone)),
+        (None, Some(filter)) => Some((filter, watch::WaitCondition::Appear)),
+        (None, None) => None,
+    };
+    if let Some((filter, condition)) = wait {
+        let code = watch::run(config, filter, condition, args.timeout, args.poll_interval)?;
+        std::process::exit(code);
+    }
+
     let mut app = App::new(config);
-    let mut terminal = setup_terminal().context("failed to initialize terminal")?;
-    let _guard = TerminalGuard::new();
+    #[cfg(feature = "metrics")]
+    if let Some(port) = args.metrics_port {
+        let addr = std::net::SocketAddr::new(args.metrics_addr, port);
+        pkillr::serve_metrics(addr, app.metrics())
+            .map_err(|err| anyhow!(err))
+            .context("failed to start the metrics endpoint")?;
+    }
+    let mut terminal =
+        setup_terminal(app.mouse_enabled()).context("failed to initialize terminal")?;
+    let guard = TerminalGuard::new();
 
-    ctrlc::set_handler(|| {
-        cleanup_terminal();
-        std::process::exit(0);
+    // the first SIGINT asks the loop to stop so the normal shutdown path and
+    // Drop run; a second one forces the exit in case the loop is stuck.
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&interrupted);
+    ctrlc::set_handler(move || {
+        if flag.swap(true, Ordering::SeqCst) {
+            cleanup_terminal();
+            std::process::exit(130);
+        }
     })
     .context("failed to install ctrl+c handler")?;
 
-    run_app(&mut terminal, &mut app)?;
+    run_app(&mut terminal, &mut app, &interrupted)?;
+    // the alternate screen has to be gone before anything printed can stay visible.
+    drop(guard);
+    if args.print_on_exit {
+        print!(
+            "{}",
+            exit_table::render(app.filtered_processes(), exit_table::use_color())
+        );
+    }
     Ok(())
 }
 
@@ -92,40 +504,105 @@ fn clap_styles() -> Styles {
         .error(style.bold())
 }
 
-fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
+fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode().context("failed to enable raw mode")?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, Hide).context("failed to enter alternate screen")?;
+    set_mouse_capture(&mut stdout, mouse).context("failed to enable mouse capture")?;
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend).context("failed to create terminal")
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<Stdout>>, app: &mut App) -> Result<()> {
+fn set_mouse_capture(out: &mut impl Write, enabled: bool) -> io::Result<()> {
+    if enabled {
+        execute!(out, EnableMouseCapture)
+    } else {
+        execute!(out, DisableMouseCapture)
+    }
+}
+
+/// hands the terminal to a child program until `resume_terminal`.
+fn suspend_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<(), String> {
+    disable_raw_mode().map_err(|err| format!("failed to leave raw mode: {err}"))?;
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        Show
+    )
+    .map_err(|err| format!("failed to leave the alternate screen: {err}"))
+}
+
+fn resume_terminal(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    mouse: bool,
+) -> Result<(), String> {
+    enable_raw_mode().map_err(|err| format!("failed to enable raw mode: {err}"))?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen, Hide)
+        .map_err(|err| format!("failed to enter the alternate screen: {err}"))?;
+    set_mouse_capture(terminal.backend_mut(), mouse)
+        .map_err(|err| format!("failed to enable mouse capture: {err}"))?;
+    terminal
+        .clear()
+        .map_err(|err| format!("failed to redraw: {err}"))
+}
+
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    app: &mut App,
+    interrupted: &AtomicBool,
+) -> Result<()> {
     terminal.hide_cursor()?;
     let mut refresh_timer = Instant::now();
     let refresh_interval = Duration::from_millis(app.refresh_rate_ms());
+    let mut mouse_captured = app.mouse_enabled();
 
     loop {
+        if interrupted.load(Ordering::SeqCst) {
+            break;
+        }
         app.tick(Instant::now());
+        // a config reload can turn the mouse on or off.
+        if app.mouse_enabled() != mouse_captured {
+            mouse_captured = app.mouse_enabled();
+            set_mouse_capture(terminal.backend_mut(), mouse_captured)?;
+        }
+        if let Some(text) = app.take_clipboard_request()
+            && let Err(err) = clipboard::copy_to_clipboard(&text)
+        {
+            app.clipboard_failed(&text, err.to_string());
+        }
+        if let Some(pid) = app.take_pager_request() {
+            let result = pager::proc_report(pid).and_then(|report| {
+                suspend_terminal(terminal)?;
+                let paged = pager::page(&report);
+                resume_terminal(terminal, app.mouse_enabled())?;
+                paged
+            });
+            app.finish_pager(result);
+        }
         if app.needs_refresh() {
             terminal.draw(|frame| ui::render(frame, app))?;
             app.clear_refresh_flag();
         }
 
         if event::poll(INPUT_POLL_INTERVAL)? {
-            match event::read()? {
-                Event::Key(key) => {
-                    if key.modifiers.contains(KeyModifiers::CONTROL)
-                        && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'))
-                    {
-                        break;
-                    }
-                    if app.handle_input(key)? {
-                        break;
-                    }
+            // drain everything key-repeat has queued before drawing again; every
+            // event is still handled in order, only the redraws are coalesced.
+            let mut handled = 0;
+            let mut quit = false;
+            loop {
+                if handle_event(app, event::read()?)? {
+                    quit = true;
+                    break;
+                }
+                handled += 1;
+                if handled >= MAX_EVENTS_PER_FRAME || !event::poll(Duration::ZERO)? {
+                    break;
                 }
-                Event::Resize(_, _) => app.request_redraw(),
-                _ => {}
+            }
+            if quit {
+                break;
             }
         }
 
@@ -139,16 +616,52 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<Stdout>>, app: &mut App) ->
     Ok(())
 }
 
+/// applies one terminal event; returns true when the app should quit.
+fn handle_event(app: &mut App, event: Event) -> Result<bool> {
+    match event {
+        Event::Key(key) => {
+            if key.modifiers.contains(KeyModifiers::CONTROL)
+                && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'))
+            {
+                return Ok(true);
+            }
+            return app.handle_input(key);
+        }
+        Event::Mouse(mouse) => app.handle_mouse(mouse),
+        Event::Resize(_, _) => app.request_redraw(),
+        _ => {}
+    }
+    Ok(false)
+}
+
 fn cleanup_terminal() {
     let _ = disable_raw_mode();
     let mut stdout = io::stdout();
-    let _ = execute!(stdout, LeaveAlternateScreen, Show);
+    let _ = execute!(stdout, DisableMouseCapture, LeaveAlternateScreen, Show);
+}
+
+/// restores the terminal before the panic message prints, so a crash on any
+/// thread (or one that aborts instead of unwinding) doesn't leave raw mode on.
+fn install_panic_hook(cleanup: fn()) {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        cleanup();
+        default_hook(info);
+    }));
+}
+
+/// puts back the standard hook; not allowed while unwinding, when it no longer matters.
+fn restore_panic_hook() {
+    if !std::thread::panicking() {
+        let _ = panic::take_hook();
+    }
 }
 
 struct TerminalGuard;
 
 impl TerminalGuard {
     fn new() -> Self {
+        install_panic_hook(cleanup_terminal);
         TerminalGuard
     }
 }
@@ -156,5 +669,29 @@ impl TerminalGuard {
 impl Drop for TerminalGuard {
     fn drop(&mut self) {
         cleanup_terminal();
+        restore_panic_hook();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::{install_panic_hook, restore_panic_hook};
+
+    static CLEANED_UP: AtomicBool = AtomicBool::new(false);
+
+    fn mark_cleaned_up() {
+        CLEANED_UP.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn panic_hook_restores_the_terminal_first() {
+        install_panic_hook(mark_cleaned_up);
+        let outcome = std::thread::spawn(|| panic!("deliberate render panic")).join();
+        restore_panic_hook();
+
+        assert!(outcome.is_err());
+        assert!(CLEANED_UP.load(Ordering::SeqCst));
     }
 }
@@ -0,0 +1,147 @@
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::signals::Signal;
+
+const SIGNAL_COUNT: usize = Signal::all().len();
+
+/// counters and gauges exported in the prometheus text format.
+#[derive(Debug)]
+pub struct Metrics {
+    signals_sent: [AtomicU64; SIGNAL_COUNT],
+    kills_succeeded: AtomicU64,
+    kills_failed: AtomicU64,
+    tracked_processes: AtomicU64,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub const fn new() -> Self {
+        Self {
+            signals_sent: [const { AtomicU64::new(0) }; SIGNAL_COUNT],
+            kills_succeeded: AtomicU64::new(0),
+            kills_failed: AtomicU64::new(0),
+            tracked_processes: AtomicU64::new(0),
+        }
+    }
+
+    /// counts `count` deliveries of `signal` that succeeded or failed.
+    pub fn record_signal(&self, signal: Signal, succeeded: bool, count: u64) {
+        if succeeded {
+            if let Some(idx) = Signal::all().iter().position(|known| *known == signal) {
+                self.signals_sent[idx].fetch_add(count, Ordering::Relaxed);
+            }
+            self.kills_succeeded.fetch_add(count, Ordering::Relaxed);
+        } else {
+            self.kills_failed.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    pub fn set_tracked_processes(&self, count: usize) {
+        self.tracked_processes
+            .store(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn signals_sent(&self, signal: Signal) -> u64 {
+        Signal::all()
+            .iter()
+            .position(|known| *known == signal)
+            .map_or(0, |idx| self.signals_sent[idx].load(Ordering::Relaxed))
+    }
+
+    /// the exposition text; signals never sent are left out.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP pkillr_signals_sent_total signals delivered, by signal.\n");
+        out.push_str("# TYPE pkillr_signals_sent_total counter\n");
+        for (signal, counter) in Signal::all().iter().zip(&self.signals_sent) {
+            let value = counter.load(Ordering::Relaxed);
+            if value > 0 {
+                let _ = writeln!(
+                    out,
+                    "pkillr_signals_sent_total{{signal=\"{}\"}} {}",
+                    signal.name(),
+                    value
+                );
+            }
+        }
+        for (name, kind, help, value) in [
+            (
+                "pkillr_kills_succeeded_total",
+                "counter",
+                "signals that reached their target.",
+                &self.kills_succeeded,
+            ),
+            (
+                "pkillr_kills_failed_total",
+                "counter",
+                "signals that could not be delivered.",
+                &self.kills_failed,
+            ),
+            (
+                "pkillr_tracked_processes",
+                "gauge",
+                "processes seen on the last refresh.",
+                &self.tracked_processes,
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            let _ = writeln!(out, "{name} {}", value.load(Ordering::Relaxed));
+        }
+        out
+    }
+}
+
+/// how long a scrape connection may stall before it is dropped, so one idle
+/// client can't hold up the single serving thread.
+#[cfg(feature = "metrics")]
+const CLIENT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// serves `GET /metrics` on `addr` from a background thread.
+#[cfg(feature = "metrics")]
+pub fn serve(
+    addr: std::net::SocketAddr,
+    metrics: std::sync::Arc<Metrics>,
+) -> Result<std::thread::JoinHandle<()>, String> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener =
+        TcpListener::bind(addr).map_err(|err| format!("failed to bind {}: {}", addr, err))?;
+    Ok(std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            if stream.set_read_timeout(Some(CLIENT_TIMEOUT)).is_err()
+                || stream.set_write_timeout(Some(CLIENT_TIMEOUT)).is_err()
+            {
+                continue;
+            }
+            let mut request_line = String::new();
+            if BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .is_err()
+            {
+                continue;
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("");
+            let (status, body) = if path == "/metrics" {
+                ("200 OK", metrics.render())
+            } else {
+                ("404 Not Found", "not found\n".to_string())
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    }))
+}
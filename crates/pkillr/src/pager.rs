@@ -0,0 +1,76 @@
+use std::fs;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+const DEFAULT_PAGER: &str = "less";
+
+/// the raw `/proc` files the info pane summarizes, one titled block each.
+pub fn proc_report(pid: u32) -> Result<String, String> {
+    if !cfg!(target_os = "linux") {
+        return Err("raw /proc files are only available on linux".to_string());
+    }
+    let status = fs::read_to_string(format!("/proc/{pid}/status"))
+        .map_err(|_| format!("PID {pid} exited before the pager opened"))?;
+    let mut report = format!("==> /proc/{pid}/status <==\n{status}");
+    for file in ["stat", "limits"] {
+        let path = format!("/proc/{pid}/{file}");
+        let contents =
+            fs::read_to_string(&path).unwrap_or_else(|err| format!("<unreadable: {err}>\n"));
+        report.push_str(&format!("\n==> {path} <==\n{contents}"));
+    }
+    Ok(report)
+}
+
+/// `$PAGER` when it's set to something, otherwise less.
+pub fn pager_command() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string())
+}
+
+/// pipes `text` into the pager through the shell, so `PAGER="less -S"` works; blocks until it exits.
+pub fn page(text: &str) -> Result<(), String> {
+    let pager = pager_command();
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(&pager)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to start {pager}: {err}"))?;
+    // dropping stdin after the write is what tells the pager the text is complete.
+    let written = match child.stdin.take() {
+        Some(mut stdin) => stdin.write_all(text.as_bytes()),
+        None => Ok(()),
+    };
+    let status = child
+        .wait()
+        .map_err(|err| format!("failed to wait for {pager}: {err}"))?;
+    match written {
+        // the pager quitting before reading everything closes the pipe; that's fine.
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("failed to write to {pager}: {err}"))
+        }
+        _ if !status.success() => Err(format!("{pager} exited with {status}")),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::proc_report;
+
+    #[test]
+    fn report_has_a_block_per_proc_file() {
+        let report = proc_report(std::process::id()).unwrap();
+        assert!(report.starts_with(&format!("==> /proc/{}/status <==", std::process::id())));
+        assert!(report.contains("/stat <==") && report.contains("/limits <=="));
+        assert!(report.contains("Max open files"));
+    }
+
+    #[test]
+    fn vanished_processes_are_reported() {
+        let err = proc_report(4_199_995).unwrap_err();
+        assert_eq!(err, "PID 4199995 exited before the pager opened");
+    }
+}
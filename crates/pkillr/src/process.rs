@@ -1,6 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::io::{BufRead, BufReader};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
@@ -10,7 +12,9 @@ use sysinfo::{
     System,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::config::CpuSource;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ProcessState {
     Running,
     Sleeping,
@@ -23,6 +27,7 @@ pub enum ProcessState {
     Locked,
     Waking,
     Parked,
+    #[default]
     Unknown,
 }
 
@@ -64,7 +69,7 @@ impl ProcessState {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub name: String,
@@ -72,11 +77,31 @@ pub struct ProcessInfo {
     pub memory_bytes: u64,
     pub user: String,
     pub runtime: Duration,
+    /// user plus system cpu time consumed over the process lifetime.
+    pub cpu_time: Duration,
     pub cmdline: Vec<String>,
     pub cwd: Option<String>,
     pub environment: Vec<String>,
     pub parent_pid: Option<u32>,
     pub state: ProcessState,
+    pub thread_owner: Option<u32>,
+    /// VmSwap; only collected while something needs it.
+    pub swap_bytes: Option<u64>,
+    /// page faults that had to go to disk; linux only.
+    pub major_faults: u64,
+    /// controlling terminal device (`tty_nr`), `None` without one; linux only.
+    pub tty: Option<u32>,
+    /// process group id; linux only.
+    pub pgid: Option<u32>,
+    /// foreground process group of the controlling terminal, `None` without one; linux only.
+    pub tpgid: Option<u32>,
+}
+
+impl ProcessInfo {
+    /// in the group its terminal delivers Ctrl-C to.
+    pub fn is_foreground(&self) -> bool {
+        matches!((self.pgid, self.tpgid), (Some(pgid), Some(tpgid)) if pgid == tpgid)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -94,22 +119,58 @@ pub struct ProcessDetails {
     pub thread_count: usize,
     pub cmdline: Vec<String>,
     pub cwd: Option<PathBuf>,
+    pub exe: Option<PathBuf>,
     pub environment: Vec<String>,
     pub children: Vec<ChildProcess>,
     pub capabilities: Vec<String>,
+    /// cpus the scheduler may run the process on, as a kernel cpu list such as `0-3,8`.
+    pub cpu_affinity: Option<String>,
+    /// io scheduling class and level, as `ionice` would print them.
+    pub io_priority: Option<String>,
+    /// the debugger or tracer attached through ptrace, if any.
+    pub tracer_pid: Option<u32>,
+    pub tracer_name: Option<String>,
     pub open_files: Vec<String>,
     pub open_ports: Vec<String>,
     pub cgroups: Vec<String>,
     pub namespaces: Vec<String>,
     pub memory_maps: Vec<String>,
+    pub swap_bytes: Option<u64>,
+    /// page faults served from memory and from disk, from `/proc/<pid>/stat`.
+    pub minor_faults: Option<u64>,
+    pub major_faults: Option<u64>,
+    /// time on a cpu and time runnable but waiting on the runqueue, from `/proc/<pid>/schedstat`;
+    /// `None` on kernels built without `CONFIG_SCHEDSTATS`.
+    pub sched_run: Option<Duration>,
+    pub sched_wait: Option<Duration>,
+}
+
+/// shortest wall-clock window a `/proc/<pid>/stat` cpu rate is computed over;
+/// shorter ones are dominated by clock-tick rounding.
+const STAT_CPU_MIN_WINDOW: Duration = Duration::from_millis(100);
+
+struct CpuSample {
+    cpu_time: Duration,
+    taken: Instant,
+    percent: f32,
 }
 
 pub struct ProcessManager {
     system: System,
     cpu_cache: HashMap<u32, f32>,
+    cpu_source: CpuSource,
+    stat_samples: HashMap<u32, CpuSample>,
     username_cache: HashMap<u32, String>,
     last_refresh: Instant,
     process_refresh: ProcessRefreshKind,
+    include_threads: bool,
+    collect_swap: bool,
+}
+
+impl Default for ProcessManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ProcessManager {
@@ -119,14 +180,32 @@ impl ProcessManager {
         let mut manager = Self {
             system,
             cpu_cache: HashMap::new(),
+            cpu_source: CpuSource::default(),
+            stat_samples: HashMap::new(),
             username_cache: HashMap::new(),
             last_refresh: Instant::now() - MINIMUM_CPU_UPDATE_INTERVAL,
             process_refresh,
+            include_threads: false,
+            collect_swap: false,
         };
         manager.force_refresh();
         manager
     }
 
+    pub fn set_cpu_source(&mut self, source: CpuSource) {
+        self.cpu_source = source;
+        self.stat_samples.clear();
+    }
+
+    /// reading swap means an extra `/proc/<pid>/status` read per process.
+    pub fn set_collect_swap(&mut self, collect: bool) {
+        self.collect_swap = collect;
+    }
+
+    pub fn set_include_threads(&mut self, include: bool) {
+        self.include_threads = include;
+    }
+
     pub fn get_processes(&mut self, show_all: bool) -> Vec<ProcessInfo> {
         let refreshed = self.refresh_if_needed();
         let current_uid = NixUid::current();
@@ -134,6 +213,15 @@ impl ProcessManager {
         let mut seen = HashSet::new();
 
         let pids: Vec<Pid> = self.system.processes().keys().copied().collect();
+        // sysinfo lists threads next to processes; remember which task belongs to whom.
+        let mut thread_owners: HashMap<Pid, u32> = HashMap::new();
+        for (pid, process) in self.system.processes() {
+            if let Some(tasks) = process.tasks() {
+                for task in tasks.iter().filter(|task| *task != pid) {
+                    thread_owners.insert(*task, pid.as_u32());
+                }
+            }
+        }
 
         for pid in pids {
             if let Some(process) = self.system.process(pid) {
@@ -141,6 +229,10 @@ impl ProcessManager {
                 if !show_all && !visible_to_user(process, current_uid) {
                     continue;
                 }
+                let thread_owner = thread_owners.get(&pid).copied();
+                if thread_owner.is_some() && !self.include_threads {
+                    continue;
+                }
 
                 let snapshot = {
                     let cpu_sample = normalize_cpu(process.cpu_usage());
@@ -154,7 +246,7 @@ impl ProcessManager {
                     let parent_pid = process.parent().map(|p| p.as_u32());
                     let state = ProcessState::from(process.status());
                     let name = process.name().to_string();
-                    let user_uid = process.user_id().map(|uid| raw_uid(uid));
+                    let user_uid = process.user_id().map(raw_uid);
                     (
                         cpu_sample,
                         memory_bytes,
@@ -182,7 +274,30 @@ impl ProcessManager {
                     user_uid,
                 ) = snapshot;
 
-                let cpu_percent = self.cpu_percent(pid_u32, cpu_sample, refreshed);
+                let stat = read_stat(pid_u32);
+                let cpu_time = stat
+                    .as_deref()
+                    .and_then(|stat| parse_cpu_time(stat, clock_ticks()));
+                let (pgid, tpgid) = stat
+                    .as_deref()
+                    .and_then(parse_terminal_group)
+                    .map_or((None, None), |(pgid, tpgid)| (Some(pgid), tpgid));
+                let tty = stat.as_deref().and_then(parse_tty_nr);
+                let major_faults = stat
+                    .as_deref()
+                    .and_then(parse_page_faults)
+                    .map_or(0, |(_, major)| major);
+                let cpu_percent = match (self.cpu_source, cpu_time) {
+                    (CpuSource::Stat, Some(cpu_time)) => {
+                        self.stat_cpu_percent(pid_u32, cpu_time, cpu_sample)
+                    }
+                    _ => self.cpu_percent(pid_u32, cpu_sample, refreshed),
+                };
+                let swap_bytes = if self.collect_swap {
+                    read_swap_bytes(pid_u32)
+                } else {
+                    None
+                };
                 let user = user_uid
                     .map(|uid| self.username_from_uid(uid))
                     .unwrap_or_else(|| "unknown".to_string());
@@ -194,11 +309,18 @@ impl ProcessManager {
                     memory_bytes,
                     user,
                     runtime,
+                    cpu_time: cpu_time.unwrap_or_default(),
                     cmdline,
                     cwd,
                     environment,
                     parent_pid,
                     state,
+                    thread_owner,
+                    swap_bytes,
+                    major_faults,
+                    tty,
+                    pgid,
+                    tpgid,
                 };
 
                 seen.insert(pid_u32);
@@ -207,35 +329,12 @@ impl ProcessManager {
         }
 
         self.cpu_cache.retain(|pid, _| seen.contains(pid));
+        self.stat_samples.retain(|pid, _| seen.contains(pid));
         results
     }
 
     pub fn get_process_tree(&mut self, pid: u32) -> Vec<ProcessInfo> {
-        let processes = self.get_processes(true);
-        let mut by_pid: HashMap<u32, ProcessInfo> =
-            processes.into_iter().map(|info| (info.pid, info)).collect();
-        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
-
-        for (child_pid, info) in &by_pid {
-            if let Some(parent) = info.parent_pid {
-                children.entry(parent).or_default().push(*child_pid);
-            }
-        }
-
-        let mut stack = vec![pid];
-        let mut tree = Vec::new();
-        while let Some(current) = stack.pop() {
-            if let Some(info) = by_pid.remove(&current) {
-                if let Some(kids) = children.get(&current) {
-                    for child in kids.iter().rev() {
-                        stack.push(*child);
-                    }
-                }
-                tree.push(info);
-            }
-        }
-
-        tree
+        collect_subtree(self.get_processes(true), pid)
     }
 
     pub fn get_details(&mut self, pid: u32) -> Option<ProcessDetails> {
@@ -248,6 +347,7 @@ impl ProcessManager {
         let thread_count = process.tasks().map(|tasks| tasks.len()).unwrap_or(1);
         let cmdline = process.cmd().to_vec();
         let cwd = process.cwd().map(|path| path.to_path_buf());
+        let exe = process.exe().map(|path| path.to_path_buf());
         let environment = process.environ().to_vec();
 
         let children = self
@@ -267,12 +367,23 @@ impl ProcessManager {
             })
             .collect();
 
-        let capabilities = read_capabilities(pid);
-        let open_files = read_open_files(pid);
+        // capabilities, affinity, tracer and swap all come from the one status read.
+        let status = read_status(pid);
+        let status = status.as_deref();
+        let capabilities = status.map(parse_capabilities).unwrap_or_default();
+        let open_files = label_socket_fds(read_open_files(pid), &read_socket_peers(pid));
         let open_ports = read_open_ports(pid);
         let cgroups = read_cgroups(pid);
         let namespaces = read_namespaces(pid);
         let memory_maps = read_memory_maps(pid);
+        let tracer_pid = status.and_then(parse_tracer_pid);
+        let tracer_name = tracer_pid.and_then(|tracer| {
+            self.system
+                .process(Pid::from_u32(tracer))
+                .map(|process| process.name().to_string())
+        });
+        let faults = read_stat(pid).as_deref().and_then(parse_page_faults);
+        let schedstat = read_schedstat(pid);
 
         Some(ProcessDetails {
             pid,
@@ -281,14 +392,24 @@ impl ProcessManager {
             thread_count,
             cmdline,
             cwd,
+            exe,
             environment,
             children,
             capabilities,
+            cpu_affinity: status.and_then(parse_cpus_allowed),
+            io_priority: read_io_priority(pid),
+            tracer_pid,
+            tracer_name,
             open_files,
             open_ports,
             cgroups,
             namespaces,
             memory_maps,
+            swap_bytes: status.and_then(parse_swap_bytes),
+            minor_faults: faults.map(|(minor, _)| minor),
+            major_faults: faults.map(|(_, major)| major),
+            sched_run: schedstat.map(|(run, _)| run),
+            sched_wait: schedstat.map(|(_, wait)| wait),
         })
     }
 
@@ -298,6 +419,10 @@ impl ProcessManager {
             self.force_refresh();
             true
         } else {
+            // sysinfo can't sample cpu this soon, but the process list, memory and
+            // states can still follow a faster refresh rate.
+            self.system
+                .refresh_processes_specifics(self.process_refresh.without_cpu());
             false
         }
     }
@@ -308,6 +433,32 @@ impl ProcessManager {
         self.last_refresh = Instant::now();
     }
 
+    /// cpu time used since the previous sample over the wall time between them;
+    /// a pid seen for the first time falls back to sysinfo's figure.
+    fn stat_cpu_percent(&mut self, pid: u32, cpu_time: Duration, fallback: f32) -> f32 {
+        let now = Instant::now();
+        let percent = match self.stat_samples.get(&pid) {
+            Some(previous) if now.duration_since(previous.taken) < STAT_CPU_MIN_WINDOW => {
+                return previous.percent;
+            }
+            Some(previous) => {
+                let used = cpu_time.saturating_sub(previous.cpu_time).as_secs_f32();
+                let elapsed = now.duration_since(previous.taken).as_secs_f32();
+                normalize_cpu(used / elapsed * 100.0)
+            }
+            None => fallback,
+        };
+        self.stat_samples.insert(
+            pid,
+            CpuSample {
+                cpu_time,
+                taken: now,
+                percent,
+            },
+        );
+        percent
+    }
+
     fn cpu_percent(&mut self, pid: u32, sample: f32, refreshed: bool) -> f32 {
         if refreshed {
             self.cpu_cache.insert(pid, sample);
@@ -340,8 +491,101 @@ impl ProcessManager {
     }
 }
 
+/// where the app reads process snapshots from. `ProcessManager` reads the live
+/// system; tests can supply a fixed list instead.
+pub trait ProcessSource {
+    fn get_processes(&mut self, show_all: bool) -> Vec<ProcessInfo>;
+
+    fn get_details(&mut self, pid: u32) -> Option<ProcessDetails>;
+
+    fn total_memory_bytes(&self) -> u64;
+
+    fn set_include_threads(&mut self, _include: bool) {}
+
+    fn set_collect_swap(&mut self, _collect: bool) {}
+
+    /// whether each of `pids` holds at least one tcp or udp socket; pids whose
+    /// descriptors can't be read are left out, as unknown.
+    fn network_pids(&mut self, pids: &[u32]) -> HashMap<u32, bool> {
+        let inodes = read_network_socket_inodes();
+        pids.iter()
+            .filter_map(|pid| has_network_socket(*pid, &inodes).map(|found| (*pid, found)))
+            .collect()
+    }
+
+    /// each pid's cgroup path (see `parse_cgroup_path`); pids without one are left out.
+    fn cgroup_paths(&mut self, pids: &[u32]) -> HashMap<u32, String> {
+        pids.iter()
+            .filter_map(|pid| {
+                parse_cgroup_path(&read_cgroups(*pid).join("\n")).map(|path| (*pid, path))
+            })
+            .collect()
+    }
+
+    fn get_process_tree(&mut self, pid: u32) -> Vec<ProcessInfo> {
+        collect_subtree(self.get_processes(true), pid)
+    }
+}
+
+impl ProcessSource for ProcessManager {
+    fn get_processes(&mut self, show_all: bool) -> Vec<ProcessInfo> {
+        ProcessManager::get_processes(self, show_all)
+    }
+
+    fn get_details(&mut self, pid: u32) -> Option<ProcessDetails> {
+        ProcessManager::get_details(self, pid)
+    }
+
+    fn total_memory_bytes(&self) -> u64 {
+        ProcessManager::total_memory_bytes(self)
+    }
+
+    fn set_include_threads(&mut self, include: bool) {
+        ProcessManager::set_include_threads(self, include);
+    }
+
+    fn set_collect_swap(&mut self, collect: bool) {
+        ProcessManager::set_collect_swap(self, collect);
+    }
+
+    fn get_process_tree(&mut self, pid: u32) -> Vec<ProcessInfo> {
+        ProcessManager::get_process_tree(self, pid)
+    }
+}
+
+/// `pid` and its descendants in depth-first order, skipping thread rows.
+fn collect_subtree(processes: Vec<ProcessInfo>, pid: u32) -> Vec<ProcessInfo> {
+    let mut by_pid: HashMap<u32, ProcessInfo> = processes
+        .into_iter()
+        .filter(|info| info.thread_owner.is_none())
+        .map(|info| (info.pid, info))
+        .collect();
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+
+    for (child_pid, info) in &by_pid {
+        if let Some(parent) = info.parent_pid {
+            children.entry(parent).or_default().push(*child_pid);
+        }
+    }
+
+    let mut stack = vec![pid];
+    let mut tree = Vec::new();
+    while let Some(current) = stack.pop() {
+        if let Some(info) = by_pid.remove(&current) {
+            if let Some(kids) = children.get(&current) {
+                for child in kids.iter().rev() {
+                    stack.push(*child);
+                }
+            }
+            tree.push(info);
+        }
+    }
+
+    tree
+}
+
 fn raw_uid(uid: &sysinfo::Uid) -> u32 {
-    (**uid) as u32
+    **uid
 }
 
 fn normalize_cpu(value: f32) -> f32 {
@@ -353,23 +597,220 @@ fn normalize_cpu(value: f32) -> f32 {
 }
 
 #[cfg(target_os = "linux")]
-fn read_capabilities(pid: u32) -> Vec<String> {
-    let path = format!("/proc/{pid}/status");
-    let file = match fs::File::open(path) {
-        Ok(file) => file,
-        Err(_) => return Vec::new(),
-    };
-    let reader = BufReader::new(file);
-    reader
+fn read_status(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{pid}/status")).ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_status(_pid: u32) -> Option<String> {
+    None
+}
+
+/// the `Cap*` lines of a `/proc/<pid>/status`.
+fn parse_capabilities(status: &str) -> Vec<String> {
+    status
         .lines()
-        .filter_map(|line| line.ok())
         .filter(|line| line.starts_with("Cap"))
+        .map(str::to_string)
         .collect()
 }
 
+/// `VmSwap` from a `/proc/<pid>/status`, in bytes.
+fn parse_swap_bytes(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmSwap:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1_024)
+}
+
+/// the `Cpus_allowed_list` value from `/proc/<pid>/status`.
+pub fn parse_cpus_allowed(status: &str) -> Option<String> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+        .map(|list| list.trim().to_string())
+        .filter(|list| !list.is_empty())
+}
+
+/// the `TracerPid:` of a `/proc/<pid>/status`; 0 means nothing is attached.
+pub fn parse_tracer_pid(status: &str) -> Option<u32> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("TracerPid:"))
+        .and_then(|pid| pid.trim().parse().ok())
+        .filter(|pid| *pid != 0)
+}
+
+pub fn read_tracer_pid(pid: u32) -> Option<u32> {
+    read_status(pid).as_deref().and_then(parse_tracer_pid)
+}
+
+/// renders an `ioprio_get` value; class "none" follows the cpu nice value, as the kernel does.
+pub fn describe_io_priority(ioprio: i32, nice: Option<i32>) -> String {
+    const IOPRIO_CLASS_SHIFT: i32 = 13;
+    let class = ioprio >> IOPRIO_CLASS_SHIFT;
+    let level = ioprio & 0xff;
+    match class {
+        1 => format!("realtime {level}"),
+        2 => format!("best-effort {level}"),
+        3 => "idle".to_string(),
+        _ => match nice {
+            Some(nice) => format!("best-effort {} (from nice)", (nice.clamp(-20, 19) + 20) / 5),
+            None => "none".to_string(),
+        },
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_io_priority(pid: u32) -> Option<String> {
+    const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+    // SAFETY: ioprio_get only reads the scheduling attributes of the given pid.
+    let ioprio =
+        unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid as libc::c_int) };
+    if ioprio < 0 {
+        return None;
+    }
+    // nice is field 19 of stat, the 17th after the command name.
+    let nice = fs::read_to_string(format!("/proc/{pid}/stat"))
+        .ok()
+        .and_then(|stat| {
+            let rest = stat.get(stat.rfind(')')? + 1..)?;
+            rest.split_whitespace().nth(16)?.parse().ok()
+        });
+    Some(describe_io_priority(ioprio as i32, nice))
+}
+
 #[cfg(not(target_os = "linux"))]
-fn read_capabilities(_pid: u32) -> Vec<String> {
-    Vec::new()
+fn read_io_priority(_pid: u32) -> Option<String> {
+    None
+}
+
+/// appended to environment entries cut short for display.
+pub const TRUNCATED_MARKER: &str = "… (truncated)";
+
+/// `entry` cut to `max_chars` characters for display; 0 keeps it whole.
+pub fn truncate_env_entry(entry: &str, max_chars: usize) -> Cow<'_, str> {
+    if max_chars == 0 {
+        return Cow::Borrowed(entry);
+    }
+    match entry.char_indices().nth(max_chars) {
+        Some((cut, _)) => Cow::Owned(format!("{}{}", &entry[..cut], TRUNCATED_MARKER)),
+        None => Cow::Borrowed(entry),
+    }
+}
+
+/// the fields of a `/proc/<pid>/stat` line after the command name, starting at `state`.
+/// the name may contain spaces and parentheses, so they resume after the last `)`.
+fn stat_fields(stat: &str) -> Option<Vec<&str>> {
+    Some(
+        stat.get(stat.rfind(')')? + 1..)?
+            .split_whitespace()
+            .collect(),
+    )
+}
+
+/// utime + stime from a `/proc/<pid>/stat` line, converted from clock ticks.
+pub fn parse_cpu_time(stat: &str, ticks_per_second: u64) -> Option<Duration> {
+    let fields = stat_fields(stat)?;
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    let ticks = utime + stime;
+    let ticks_per_second = ticks_per_second.max(1);
+    Some(
+        Duration::from_secs(ticks / ticks_per_second)
+            + Duration::from_nanos((ticks % ticks_per_second) * 1_000_000_000 / ticks_per_second),
+    )
+}
+
+/// the process group and the terminal's foreground group (`tpgid`, -1 without a terminal) from a stat line.
+pub fn parse_terminal_group(stat: &str) -> Option<(u32, Option<u32>)> {
+    let fields = stat_fields(stat)?;
+    let pgid = fields.get(2)?.parse().ok()?;
+    let tpgid: i64 = fields.get(5)?.parse().ok()?;
+    Some((pgid, u32::try_from(tpgid).ok().filter(|tpgid| *tpgid > 0)))
+}
+
+/// `(minflt, majflt)` from a stat line.
+pub fn parse_page_faults(stat: &str) -> Option<(u64, u64)> {
+    let fields = stat_fields(stat)?;
+    let minor = fields.get(7)?.parse().ok()?;
+    let major = fields.get(9)?.parse().ok()?;
+    Some((minor, major))
+}
+
+/// the controlling terminal's device number from a stat line; `None` when there isn't one.
+pub fn parse_tty_nr(stat: &str) -> Option<u32> {
+    let fields = stat_fields(stat)?;
+    let tty_nr: u32 = fields.get(4)?.parse().ok()?;
+    (tty_nr != 0).then_some(tty_nr)
+}
+
+/// a `tty_nr` as a name under /dev: `pts/3`, `tty2`, `ttyS0`, or `major:minor` otherwise.
+pub fn tty_name(tty_nr: u32) -> String {
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+    match major {
+        136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+        4 if minor < 64 => format!("tty{minor}"),
+        4 => format!("ttyS{}", minor - 64),
+        _ => format!("{major}:{minor}"),
+    }
+}
+
+/// the terminal pkillr itself runs on.
+pub fn own_tty() -> Option<u32> {
+    read_stat(std::process::id())
+        .as_deref()
+        .and_then(parse_tty_nr)
+}
+
+/// `None` when the stat file can't be read, e.g. because the process exited.
+pub fn has_controlling_tty(pid: u32) -> Option<bool> {
+    read_stat(pid).map(|stat| parse_tty_nr(&stat).is_some())
+}
+
+#[cfg(target_os = "linux")]
+fn read_stat(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{pid}/stat")).ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_stat(_pid: u32) -> Option<String> {
+    None
+}
+
+fn clock_ticks() -> u64 {
+    /// the usual USER_HZ, used if sysconf can't say.
+    const FALLBACK_CLOCK_TICKS: u64 = 100;
+    // SAFETY: sysconf only reads a configuration value.
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    u64::try_from(ticks)
+        .ok()
+        .filter(|ticks| *ticks > 0)
+        .unwrap_or(FALLBACK_CLOCK_TICKS)
+}
+
+/// `(on cpu, waiting on the runqueue)` from a schedstat line: both in nanoseconds, followed
+/// by the number of timeslices, which isn't needed.
+pub fn parse_schedstat(text: &str) -> Option<(Duration, Duration)> {
+    let mut fields = text.split_whitespace();
+    let run: u64 = fields.next()?.parse().ok()?;
+    let wait: u64 = fields.next()?.parse().ok()?;
+    fields.next()?;
+    Some((Duration::from_nanos(run), Duration::from_nanos(wait)))
+}
+
+#[cfg(target_os = "linux")]
+fn read_schedstat(pid: u32) -> Option<(Duration, Duration)> {
+    parse_schedstat(&fs::read_to_string(format!("/proc/{pid}/schedstat")).ok()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_schedstat(_pid: u32) -> Option<(Duration, Duration)> {
+    None
+}
+
+pub fn read_swap_bytes(pid: u32) -> Option<u64> {
+    read_status(pid).as_deref().and_then(parse_swap_bytes)
 }
 
 #[cfg(target_os = "linux")]
@@ -431,14 +872,181 @@ fn read_open_ports(_pid: u32) -> Vec<String> {
 
 #[cfg(target_os = "linux")]
 fn parse_tcp_line(line: &str) -> Option<String> {
+    let row = parse_socket_row(line)?;
+    Some(format!(
+        "{} -> {} ({})",
+        row.local,
+        row.remote,
+        tcp_state_name(row.state)
+    ))
+}
+
+/// one row of `/proc/PID/net/{tcp,udp}{,6}` with decoded endpoints.
+#[cfg(target_os = "linux")]
+struct SocketRow<'a> {
+    local: String,
+    remote: String,
+    state: &'a str,
+    inode: u64,
+}
+
+#[cfg(target_os = "linux")]
+fn parse_socket_row(line: &str) -> Option<SocketRow<'_>> {
     let columns: Vec<&str> = line.split_whitespace().collect();
     if columns.len() < 4 {
         return None;
     }
-    let local = columns[1];
-    let remote = columns[2];
-    let state = tcp_state_name(columns[3]);
-    Some(format!("{local} -> {remote} ({state})"))
+    Some(SocketRow {
+        local: decode_socket_address(columns[1]),
+        remote: decode_socket_address(columns[2]),
+        state: columns[3],
+        inode: columns
+            .get(9)
+            .and_then(|inode| inode.parse().ok())
+            .unwrap_or(0),
+    })
+}
+
+/// turns the kernel's hex `ADDR:PORT` into `10.0.0.5:5432` or `[::1]:80`;
+/// addresses are stored as native-endian 32-bit words.
+pub fn decode_socket_address(hex: &str) -> String {
+    let Some((addr, port)) = hex.split_once(':') else {
+        return hex.to_string();
+    };
+    let Ok(port) = u16::from_str_radix(port, 16) else {
+        return hex.to_string();
+    };
+    let words: Option<Vec<u32>> = (0..addr.len() / 8)
+        .map(|idx| u32::from_str_radix(&addr[idx * 8..idx * 8 + 8], 16).ok())
+        .collect();
+    let Some(words) = words.filter(|_| addr.len() % 8 == 0) else {
+        return hex.to_string();
+    };
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_ne_bytes()).collect();
+    match bytes.len() {
+        4 => format!(
+            "{}:{}",
+            Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]),
+            port
+        ),
+        16 => {
+            let octets: [u8; 16] = bytes.try_into().unwrap_or([0; 16]);
+            format!("[{}]:{}", Ipv6Addr::from(octets), port)
+        }
+        _ => hex.to_string(),
+    }
+}
+
+/// socket inode -> human description, from the pid's network namespace view.
+#[cfg(target_os = "linux")]
+fn read_socket_peers(pid: u32) -> HashMap<u64, String> {
+    let mut peers = HashMap::new();
+    for table in ["tcp", "tcp6", "udp", "udp6"] {
+        let Ok(contents) = fs::read_to_string(format!("/proc/{pid}/net/{table}")) else {
+            continue;
+        };
+        for line in contents.lines().skip(1) {
+            let Some(row) = parse_socket_row(line) else {
+                continue;
+            };
+            if row.inode == 0 {
+                continue;
+            }
+            let description = if table.starts_with("tcp") {
+                format!(
+                    "{table} {} -> {} ({})",
+                    row.local,
+                    row.remote,
+                    tcp_state_name(row.state)
+                )
+            } else {
+                format!("{table} {} -> {}", row.local, row.remote)
+            };
+            peers.insert(row.inode, description);
+        }
+    }
+    if let Ok(contents) = fs::read_to_string(format!("/proc/{pid}/net/unix")) {
+        for line in contents.lines().skip(1) {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let Some(inode) = columns.get(6).and_then(|inode| inode.parse::<u64>().ok()) else {
+                continue;
+            };
+            let path = columns.get(7).copied().unwrap_or("(unnamed)");
+            peers.insert(inode, format!("unix {path}"));
+        }
+    }
+    peers
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_socket_peers(_pid: u32) -> HashMap<u64, String> {
+    HashMap::new()
+}
+
+/// tcp and udp socket inodes in `/proc/net`, which covers pkillr's own network namespace.
+#[cfg(target_os = "linux")]
+pub fn read_network_socket_inodes() -> HashSet<u64> {
+    let mut inodes = HashSet::new();
+    for table in ["tcp", "tcp6", "udp", "udp6"] {
+        let Ok(contents) = fs::read_to_string(format!("/proc/net/{table}")) else {
+            continue;
+        };
+        inodes.extend(
+            contents
+                .lines()
+                .skip(1)
+                .filter_map(parse_socket_row)
+                .map(|row| row.inode)
+                .filter(|inode| *inode != 0),
+        );
+    }
+    inodes
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_network_socket_inodes() -> HashSet<u64> {
+    HashSet::new()
+}
+
+/// whether one of `pid`'s descriptors is a socket in `inodes`; stops at the first hit.
+/// `None` when the descriptors can't be read, usually another user's process.
+#[cfg(target_os = "linux")]
+pub fn has_network_socket(pid: u32, inodes: &HashSet<u64>) -> Option<bool> {
+    if inodes.is_empty() {
+        return Some(false);
+    }
+    let entries = fs::read_dir(format!("/proc/{pid}/fd")).ok()?;
+    Some(entries.flatten().any(|entry| {
+        fs::read_link(entry.path()).is_ok_and(|link| {
+            link.to_str()
+                .and_then(|target| target.strip_prefix("socket:["))
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|inode| inode.parse::<u64>().ok())
+                .is_some_and(|inode| inodes.contains(&inode))
+        })
+    }))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn has_network_socket(_pid: u32, _inodes: &HashSet<u64>) -> Option<bool> {
+    None
+}
+
+/// appends the resolved endpoint to `socket:[inode]` fd entries; unknown inodes stay raw.
+pub fn label_socket_fds(entries: Vec<String>, peers: &HashMap<u64, String>) -> Vec<String> {
+    entries
+        .into_iter()
+        .map(|entry| {
+            let inode = entry
+                .split_once("socket:[")
+                .and_then(|(_, rest)| rest.strip_suffix(']'))
+                .and_then(|inode| inode.parse::<u64>().ok());
+            match inode.and_then(|inode| peers.get(&inode)) {
+                Some(peer) => format!("{entry} {peer}"),
+                None => entry,
+            }
+        })
+        .collect()
 }
 
 #[cfg(target_os = "linux")]
@@ -465,6 +1073,28 @@ fn tcp_state_name(_code: &str) -> &'static str {
     "UNKNOWN"
 }
 
+/// the unified (v2) hierarchy's path from `/proc/<pid>/cgroup`, falling back to the
+/// systemd v1 hierarchy, then the first listed one.
+pub fn parse_cgroup_path(contents: &str) -> Option<String> {
+    let entries: Vec<(&str, &str)> = contents
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(':')?;
+            rest.split_once(':')
+        })
+        .collect();
+    entries
+        .iter()
+        .find(|(controllers, _)| controllers.is_empty())
+        .or_else(|| {
+            entries
+                .iter()
+                .find(|(controllers, _)| *controllers == "name=systemd")
+        })
+        .or_else(|| entries.first())
+        .map(|(_, path)| path.to_string())
+}
+
 #[cfg(target_os = "linux")]
 fn read_cgroups(pid: u32) -> Vec<String> {
     let path = format!("/proc/{pid}/cgroup");
@@ -472,10 +1102,7 @@ fn read_cgroups(pid: u32) -> Vec<String> {
         Ok(file) => file,
         Err(_) => return Vec::new(),
     };
-    BufReader::new(file)
-        .lines()
-        .filter_map(|line| line.ok())
-        .collect()
+    BufReader::new(file).lines().map_while(Result::ok).collect()
 }
 
 #[cfg(not(target_os = "linux"))]
@@ -512,21 +1139,12 @@ fn read_namespaces(_pid: u32) -> Vec<String> {
 
 #[cfg(target_os = "linux")]
 fn read_memory_maps(pid: u32) -> Vec<String> {
-    const MAP_LIMIT: usize = 64;
     let path = format!("/proc/{pid}/maps");
     let file = match fs::File::open(path) {
         Ok(file) => file,
         Err(_) => return Vec::new(),
     };
-    let mut lines: Vec<String> = BufReader::new(file)
-        .lines()
-        .filter_map(|line| line.ok())
-        .take(MAP_LIMIT)
-        .collect();
-    if lines.len() == MAP_LIMIT {
-        lines.push("...".to_string());
-    }
-    lines
+    BufReader::new(file).lines().map_while(Result::ok).collect()
 }
 
 #[cfg(not(target_os = "linux"))]
@@ -534,6 +1152,160 @@ fn read_memory_maps(_pid: u32) -> Vec<String> {
     Vec::new()
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobIds {
+    pub pgid: u32,
+    pub sid: u32,
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_job_ids(pid: u32) -> Option<JobIds> {
+    let stat = fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    // the comm field may contain spaces and parens, so parse after the last ')'.
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    let pgid = fields.get(2)?.parse().ok()?;
+    let sid = fields.get(3)?.parse().ok()?;
+    Some(JobIds { pgid, sid })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_job_ids(_pid: u32) -> Option<JobIds> {
+    None
+}
+
+/// "some" avg10 stall percentages from `/proc/pressure`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pressure {
+    pub cpu: Option<f32>,
+    pub memory: Option<f32>,
+    pub io: Option<f32>,
+}
+
+/// reads system pressure stall info; `None` when the kernel has no psi.
+#[cfg(target_os = "linux")]
+pub fn read_pressure() -> Option<Pressure> {
+    let pressure = Pressure {
+        cpu: read_pressure_avg10("cpu"),
+        memory: read_pressure_avg10("memory"),
+        io: read_pressure_avg10("io"),
+    };
+    if pressure == Pressure::default() {
+        None
+    } else {
+        Some(pressure)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_pressure() -> Option<Pressure> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn read_pressure_avg10(resource: &str) -> Option<f32> {
+    let contents = fs::read_to_string(format!("/proc/pressure/{resource}")).ok()?;
+    let line = contents.lines().find(|line| line.starts_with("some "))?;
+    line.split_whitespace()
+        .find_map(|field| field.strip_prefix("avg10="))?
+        .parse()
+        .ok()
+}
+
+/// open descriptors grouped by what their `/proc/PID/fd` link points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FdSummary {
+    pub files: usize,
+    pub sockets: usize,
+    pub pipes: usize,
+    pub anon_inodes: usize,
+    pub other: usize,
+}
+
+impl FdSummary {
+    /// counts `fd N -> target` entries as listed in `ProcessDetails::open_files`.
+    pub fn from_entries(entries: &[String]) -> Self {
+        let mut summary = Self::default();
+        for entry in entries {
+            let target = entry
+                .split_once(" -> ")
+                .map_or(entry.as_str(), |(_, target)| target);
+            if target.starts_with("socket:[") {
+                summary.sockets += 1;
+            } else if target.starts_with("pipe:[") {
+                summary.pipes += 1;
+            } else if target.starts_with("anon_inode:") {
+                summary.anon_inodes += 1;
+            } else if target.starts_with('/') {
+                summary.files += 1;
+            } else {
+                summary.other += 1;
+            }
+        }
+        summary
+    }
+
+    /// e.g. "files: 12, sockets: 5, pipes: 2", skipping empty groups.
+    pub fn describe(&self) -> String {
+        let parts: Vec<String> = [
+            ("files", self.files),
+            ("sockets", self.sockets),
+            ("pipes", self.pipes),
+            ("anon_inode", self.anon_inodes),
+            ("other", self.other),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(label, count)| format!("{label}: {count}"))
+        .collect();
+        if parts.is_empty() {
+            "none".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// one variable compared against a reference environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvChange {
+    Same(String),
+    Added(String),
+    Removed(String),
+    Changed {
+        name: String,
+        reference: String,
+        current: String,
+    },
+}
+
+/// compares `KEY=value` lists, ordered by variable name.
+pub fn diff_environment(reference: &[String], current: &[String]) -> Vec<EnvChange> {
+    fn split(entry: &str) -> (&str, &str) {
+        entry.split_once('=').unwrap_or((entry, ""))
+    }
+    let reference: BTreeMap<&str, &str> = reference.iter().map(|entry| split(entry)).collect();
+    let current: BTreeMap<&str, &str> = current.iter().map(|entry| split(entry)).collect();
+
+    let mut names: Vec<&str> = reference.keys().chain(current.keys()).copied().collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| match (reference.get(name), current.get(name)) {
+            (Some(old), Some(new)) if old == new => Some(EnvChange::Same(format!("{name}={new}"))),
+            (Some(old), Some(new)) => Some(EnvChange::Changed {
+                name: name.to_string(),
+                reference: old.to_string(),
+                current: new.to_string(),
+            }),
+            (None, Some(new)) => Some(EnvChange::Added(format!("{name}={new}"))),
+            (Some(old), None) => Some(EnvChange::Removed(format!("{name}={old}"))),
+            (None, None) => None,
+        })
+        .collect()
+}
+
 fn visible_to_user(process: &Process, current_uid: NixUid) -> bool {
     let Some(uid) = process.user_id() else {
         return false;
@@ -542,6 +1314,18 @@ fn visible_to_user(process: &Process, current_uid: NixUid) -> bool {
     raw == current_uid.as_raw()
 }
 
+/// argv[0] without its directory, keeping any title a daemon wrote after it.
+/// `None` for kernel threads, which have no argv.
+pub fn argv0_basename(cmdline: &[String]) -> Option<&str> {
+    let argv0 = cmdline
+        .first()
+        .map(|arg| arg.trim())
+        .filter(|arg| !arg.is_empty())?;
+    let path_end = argv0.find(char::is_whitespace).unwrap_or(argv0.len());
+    let start = argv0[..path_end].rfind('/').map_or(0, |slash| slash + 1);
+    Some(&argv0[start..])
+}
+
 pub fn is_system_process(proc: &ProcessInfo) -> bool {
     proc.pid <= 1 || proc.user == "root" || proc.parent_pid.is_none()
 }
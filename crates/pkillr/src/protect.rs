@@ -0,0 +1,168 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::process::ProcessInfo;
+use crate::views::{section_lines, unquote};
+
+const SAFETY_SECTION: &str = "[safety]";
+
+/// a pid or process-name glob that signals are refused for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtectedTarget {
+    Pid(u32),
+    /// `*` matches any run of characters and `?` a single one.
+    Name(String),
+}
+
+impl ProtectedTarget {
+    /// a bare number is a pid; anything else is a name glob.
+    pub fn parse(text: &str) -> Result<ProtectedTarget, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("expected a pid or a process name".to_string());
+        }
+        Ok(match text.parse::<u32>() {
+            Ok(pid) => ProtectedTarget::Pid(pid),
+            Err(_) => ProtectedTarget::Name(text.to_string()),
+        })
+    }
+
+    pub fn matches(&self, proc: &ProcessInfo) -> bool {
+        match self {
+            ProtectedTarget::Pid(pid) => proc.pid == *pid,
+            ProtectedTarget::Name(pattern) => glob_match(pattern, &proc.name),
+        }
+    }
+}
+
+impl fmt::Display for ProtectedTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtectedTarget::Pid(pid) => write!(f, "PID {pid}"),
+            ProtectedTarget::Name(pattern) => write!(f, "'{pattern}'"),
+        }
+    }
+}
+
+/// the first entry of `protected` that covers `proc`.
+pub fn protecting<'a>(
+    protected: &'a [ProtectedTarget],
+    proc: &ProcessInfo,
+) -> Option<&'a ProtectedTarget> {
+    protected.iter().find(|target| target.matches(proc))
+}
+
+/// the `[safety]` table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Safety {
+    pub protected: Vec<ProtectedTarget>,
+    /// `safe_mode = true` does what `--safe` does.
+    pub safe_mode: bool,
+}
+
+/// reads the `[safety]` table; a missing file protects nothing.
+pub fn load(path: &Path) -> Result<Safety, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Safety::default()),
+        Err(err) => return Err(format!("failed to read {}: {}", path.display(), err)),
+    };
+
+    let mut safety = Safety::default();
+    for (idx, line) in section_lines(&contents, SAFETY_SECTION) {
+        let location = format!("{}:{}", path.display(), idx + 1);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("{location}: expected key = value"))?;
+        match key.trim() {
+            "protected" => {
+                safety.protected = parse_list(value).map_err(|err| format!("{location}: {err}"))?;
+            }
+            "safe_mode" => {
+                safety.safe_mode = match value.trim() {
+                    "true" => true,
+                    "false" => false,
+                    other => {
+                        return Err(format!("{location}: expected true or false, got '{other}'"));
+                    }
+                };
+            }
+            other => return Err(format!("{location}: unknown safety setting '{other}'")),
+        }
+    }
+    Ok(safety)
+}
+
+/// `[1234, "postgres*"]`: numbers are pids, strings are name globs.
+fn parse_list(value: &str) -> Result<Vec<ProtectedTarget>, String> {
+    let inner = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| "expected a [list]".to_string())?;
+
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in inner.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                items.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    items.push(current);
+
+    items
+        .iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            if item.starts_with('"') {
+                let name = unquote(item).ok_or_else(|| format!("invalid string {item}"))?;
+                Ok(ProtectedTarget::Name(name))
+            } else {
+                item.parse()
+                    .map(ProtectedTarget::Pid)
+                    .map_err(|_| format!("expected a pid or a quoted name, got {item}"))
+            }
+        })
+        .collect()
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
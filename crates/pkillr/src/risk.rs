@@ -0,0 +1,78 @@
+use crate::process::ProcessInfo;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RiskLevel {
+    Elevated,
+    Critical,
+}
+
+#[derive(Debug, Clone)]
+pub struct RiskInfo {
+    pub level: RiskLevel,
+    pub reason: String,
+}
+
+/// rates how disruptive killing `info` would be. `shell_pid` is the shell
+/// that launched the caller, which is always treated as critical.
+pub fn assess_risk(info: &ProcessInfo, shell_pid: u32) -> Option<RiskInfo> {
+    if info.pid == 1 {
+        return Some(RiskInfo {
+            level: RiskLevel::Critical,
+            reason: "init process".to_string(),
+        });
+    }
+    if info.pid == shell_pid {
+        return Some(RiskInfo {
+            level: RiskLevel::Critical,
+            reason: "current shell".to_string(),
+        });
+    }
+
+    let name = info.name.to_ascii_lowercase();
+    let mut result: Option<RiskInfo> = None;
+
+    for (pattern, level, reason) in CRITICAL_NAME_PATTERNS.iter() {
+        if name.contains(pattern) {
+            result = combine_risk(result, *level, reason);
+        }
+    }
+
+    if info.user == "root" {
+        result = combine_risk(result, RiskLevel::Elevated, "root-owned process");
+    }
+
+    result
+}
+
+const CRITICAL_NAME_PATTERNS: &[(&str, RiskLevel, &str)] = &[
+    ("systemd", RiskLevel::Critical, "system init"),
+    ("dbus-daemon", RiskLevel::Elevated, "dbus session"),
+    ("dbus-broker", RiskLevel::Elevated, "dbus broker"),
+    ("gnome-shell", RiskLevel::Critical, "desktop shell"),
+    ("plasmashell", RiskLevel::Critical, "desktop shell"),
+    ("kwin", RiskLevel::Critical, "window manager"),
+    ("mutter", RiskLevel::Critical, "window manager"),
+    ("sway", RiskLevel::Critical, "window manager"),
+    ("hyprland", RiskLevel::Critical, "window manager"),
+    ("wayfire", RiskLevel::Critical, "window manager"),
+    ("i3", RiskLevel::Critical, "window manager"),
+    ("xfce4-session", RiskLevel::Elevated, "desktop session"),
+    ("xorg", RiskLevel::Critical, "display server"),
+    ("xwayland", RiskLevel::Elevated, "display bridge"),
+    ("pipewire", RiskLevel::Elevated, "media service"),
+    ("pulseaudio", RiskLevel::Elevated, "audio server"),
+    ("tmux", RiskLevel::Elevated, "terminal multiplexer"),
+    ("wezterm", RiskLevel::Elevated, "terminal host"),
+    ("alacritty", RiskLevel::Elevated, "terminal host"),
+    ("kitty", RiskLevel::Elevated, "terminal host"),
+];
+
+fn combine_risk(current: Option<RiskInfo>, level: RiskLevel, reason: &str) -> Option<RiskInfo> {
+    match current {
+        Some(existing) if existing.level >= level => Some(existing),
+        _ => Some(RiskInfo {
+            level,
+            reason: reason.to_string(),
+        }),
+    }
+}
@@ -0,0 +1,221 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use crate::config::{
+    Column, Config, EnterAction, MemoryScale, RuntimeThresholds, SelectionStyle, SignalOrder,
+    Theme, TreeStyle, mib_to_bytes,
+};
+use crate::views::{section_lines, unquote};
+
+const DISPLAY_SECTION: &str = "[display]";
+const INFO_SECTION: &str = "[info]";
+
+/// display options read from the `[display]` table; unset keys keep their current value.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DisplaySettings {
+    pub theme: Option<Theme>,
+    pub selection_style: Option<SelectionStyle>,
+    pub signal_order: Option<SignalOrder>,
+    pub tree_style: Option<TreeStyle>,
+    pub enter_action: Option<EnterAction>,
+    pub mem_scale: Option<MemoryScale>,
+    pub mem_warn_pct: Option<f32>,
+    pub mem_crit_pct: Option<f32>,
+    pub mem_warn_mib: Option<u64>,
+    pub mem_crit_mib: Option<u64>,
+    pub swap_warn_mib: Option<u64>,
+    pub runtime_colors: Option<bool>,
+    /// seconds; only take effect while runtime colors are on.
+    pub runtime_fresh: Option<u64>,
+    pub runtime_old: Option<u64>,
+    pub mouse: Option<bool>,
+    /// table columns in display order, e.g. `["pid", "name", "cpu", "time"]`.
+    pub columns: Option<Vec<Column>>,
+}
+
+impl DisplaySettings {
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(theme) = self.theme {
+            config.theme = theme;
+        }
+        if let Some(style) = self.selection_style {
+            config.selection_style = style;
+        }
+        if let Some(order) = self.signal_order {
+            config.signal_order = order;
+        }
+        if let Some(style) = self.tree_style {
+            config.tree_style = style;
+        }
+        if let Some(action) = self.enter_action {
+            config.enter_action = action;
+        }
+        let thresholds = &mut config.memory_thresholds;
+        if let Some(scale) = self.mem_scale {
+            thresholds.scale = scale;
+        }
+        if let Some(percent) = self.mem_warn_pct {
+            thresholds.warn_percent = percent;
+        }
+        if let Some(percent) = self.mem_crit_pct {
+            thresholds.critical_percent = percent;
+        }
+        if let Some(mib) = self.mem_warn_mib {
+            thresholds.warn_bytes = mib_to_bytes(mib);
+        }
+        if let Some(mib) = self.mem_crit_mib {
+            thresholds.critical_bytes = mib_to_bytes(mib);
+        }
+        if let Some(mib) = self.swap_warn_mib {
+            config.swap_warn_bytes = mib_to_bytes(mib);
+        }
+        match self.runtime_colors {
+            Some(true) => {
+                config
+                    .runtime_colors
+                    .get_or_insert_with(RuntimeThresholds::default);
+            }
+            Some(false) => config.runtime_colors = None,
+            None => {}
+        }
+        if let Some(thresholds) = &mut config.runtime_colors {
+            if let Some(secs) = self.runtime_fresh {
+                thresholds.fresh = Duration::from_secs(secs);
+            }
+            if let Some(secs) = self.runtime_old {
+                thresholds.old = Duration::from_secs(secs);
+            }
+        }
+        if let Some(mouse) = self.mouse {
+            config.mouse = mouse;
+        }
+        if let Some(columns) = &self.columns {
+            config.columns = columns.clone();
+        }
+    }
+}
+
+/// info pane list limits read from the `[info]` table; 0 shows a whole list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InfoSettings {
+    pub max_maps: Option<usize>,
+    pub max_open_files: Option<usize>,
+    pub max_env: Option<usize>,
+}
+
+impl InfoSettings {
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(count) = self.max_maps {
+            config.info_max_maps = count;
+        }
+        if let Some(count) = self.max_open_files {
+            config.info_max_open_files = count;
+        }
+        if let Some(count) = self.max_env {
+            config.info_max_environment = count;
+        }
+    }
+}
+
+/// reads the `[display]` table; a missing file changes nothing.
+pub fn load(path: &Path) -> Result<DisplaySettings, String> {
+    let mut settings = DisplaySettings::default();
+    for (location, key, value) in table_entries(path, DISPLAY_SECTION)? {
+        let value = value.as_str();
+        match key.as_str() {
+            "theme" => settings.theme = Some(parse_enum(value, &location)?),
+            "selection_style" => settings.selection_style = Some(parse_enum(value, &location)?),
+            "signal_order" => settings.signal_order = Some(parse_enum(value, &location)?),
+            "tree_style" => settings.tree_style = Some(parse_enum(value, &location)?),
+            "enter_action" => settings.enter_action = Some(parse_enum(value, &location)?),
+            "mem_scale" => settings.mem_scale = Some(parse_enum(value, &location)?),
+            "mem_warn_pct" => settings.mem_warn_pct = Some(parse_number(value, &location)?),
+            "mem_crit_pct" => settings.mem_crit_pct = Some(parse_number(value, &location)?),
+            "mem_warn_mib" => settings.mem_warn_mib = Some(parse_number(value, &location)?),
+            "mem_crit_mib" => settings.mem_crit_mib = Some(parse_number(value, &location)?),
+            "swap_warn_mib" => settings.swap_warn_mib = Some(parse_number(value, &location)?),
+            "runtime_colors" => settings.runtime_colors = Some(parse_bool(value, &location)?),
+            "runtime_fresh" => settings.runtime_fresh = Some(parse_number(value, &location)?),
+            "runtime_old" => settings.runtime_old = Some(parse_number(value, &location)?),
+            "mouse" => settings.mouse = Some(parse_bool(value, &location)?),
+            "columns" => settings.columns = Some(parse_columns(value, &location)?),
+            other => return Err(format!("{location}: unknown display setting '{other}'")),
+        }
+    }
+    Ok(settings)
+}
+
+/// reads the `[info]` table; a missing file changes nothing.
+pub fn load_info(path: &Path) -> Result<InfoSettings, String> {
+    let mut settings = InfoSettings::default();
+    for (location, key, value) in table_entries(path, INFO_SECTION)? {
+        let value = value.as_str();
+        match key.as_str() {
+            "max_maps" => settings.max_maps = Some(parse_number(value, &location)?),
+            "max_open_files" => settings.max_open_files = Some(parse_number(value, &location)?),
+            "max_env" => settings.max_env = Some(parse_number(value, &location)?),
+            other => return Err(format!("{location}: unknown info setting '{other}'")),
+        }
+    }
+    Ok(settings)
+}
+
+/// `(file:line, key, value)` for each line of `section`, trimmed.
+fn table_entries(path: &Path, section: &str) -> Result<Vec<(String, String, String)>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("failed to read {}: {}", path.display(), err)),
+    };
+    section_lines(&contents, section)
+        .into_iter()
+        .map(|(idx, line)| {
+            let location = format!("{}:{}", path.display(), idx + 1);
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{location}: expected key = value"))?;
+            Ok((location, key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+fn parse_enum<T: ValueEnum>(value: &str, location: &str) -> Result<T, String> {
+    let text = unquote(value).ok_or_else(|| format!("{location}: expected a quoted string"))?;
+    T::from_str(&text, true).map_err(|_| format!("{location}: unknown value '{text}'"))
+}
+
+/// `["pid", "name", "cpu"]`; an empty list is refused, as it would leave no table.
+fn parse_columns(value: &str, location: &str) -> Result<Vec<Column>, String> {
+    let inner = value
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| format!("{location}: expected a [list] of column names"))?;
+    let columns = inner
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_enum(item, location))
+        .collect::<Result<Vec<Column>, String>>()?;
+    if columns.is_empty() {
+        return Err(format!("{location}: columns can't be empty"));
+    }
+    Ok(columns)
+}
+
+fn parse_bool(value: &str, location: &str) -> Result<bool, String> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(format!("{location}: expected true or false, got '{value}'")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, location: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{location}: invalid number '{value}'"))
+}
@@ -3,11 +3,12 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use chrono::{DateTime, Utc};
 use nix::errno::Errno;
 use nix::sys::signal::{Signal as NixSignal, kill};
-use nix::unistd::{Pid as NixPid, Uid, User};
+use nix::sys::wait::{WaitPidFlag, WaitStatus, waitpid};
+use nix::unistd::{Pid as NixPid, Uid, User, getppid};
 
-use crate::process::{ProcessInfo, ProcessManager};
+use crate::process::{self, ProcessInfo, ProcessManager};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Signal {
     Sighup,
     Sigint,
@@ -23,6 +24,7 @@ pub enum Signal {
     Sigusr2,
     Sigpipe,
     Sigalrm,
+    #[default]
     Sigterm,
     Sigstkflt,
     Sigchld,
@@ -81,6 +83,27 @@ impl Signal {
         &ALL_SIGNALS
     }
 
+    pub fn from_number(number: i32) -> Option<Signal> {
+        ALL_SIGNALS
+            .iter()
+            .copied()
+            .find(|signal| signal.number() == number)
+    }
+
+    /// parses `SIGTERM`, `term` or `15`.
+    pub fn parse(text: &str) -> Option<Signal> {
+        let text = text.trim();
+        if let Ok(number) = text.parse::<i32>() {
+            return Signal::from_number(number);
+        }
+        let upper = text.to_ascii_uppercase();
+        let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+        ALL_SIGNALS
+            .iter()
+            .copied()
+            .find(|signal| &signal.name()[3..] == name)
+    }
+
     pub fn number(self) -> i32 {
         match self {
             Signal::Sighup => 1,
@@ -189,15 +212,51 @@ impl Signal {
         }
     }
 
+    /// a short plain-language guess at what the signal will do to `target`.
+    pub fn predicted_effect(self, target: TargetKind) -> String {
+        let effect = match (self, target) {
+            (Signal::Sighup, TargetKind::Daemon) => "reloads (daemons re-read their config)",
+            (Signal::Sighup, _) => "hangs up; usually terminates",
+            (Signal::Sigint, TargetKind::Interactive) => "interrupts, like Ctrl-C",
+            (Signal::Sigint, _) => "interrupts; usually terminates",
+            (Signal::Sigterm, _) => "asks to exit (cleanup runs)",
+            (Signal::Sigkill, _) => "force-kills (no cleanup)",
+            (Signal::Sigquit, _) => "quits and may dump core",
+            (Signal::Sigstop, _) => "suspends (resumable with SIGCONT)",
+            (Signal::Sigtstp, TargetKind::Interactive) => "suspends, like Ctrl-Z",
+            (Signal::Sigtstp | Signal::Sigttin | Signal::Sigttou, _) => "suspends unless handled",
+            (Signal::Sigcont, _) => "resumes if stopped; otherwise no effect",
+            (Signal::Sigchld | Signal::Sigurg | Signal::Sigwinch, _) => "ignored unless handled",
+            (Signal::Sigusr1 | Signal::Sigusr2, _) => "app-defined; terminates if unhandled",
+            _ => return format!("{}; usually terminates", self.description()),
+        };
+        effect.to_string()
+    }
+
     fn to_nix(self) -> Result<NixSignal, String> {
         NixSignal::try_from(self.number())
             .map_err(|_| format!("signal {} not available on this platform", self.name()))
     }
 }
 
-impl Default for Signal {
-    fn default() -> Self {
-        Signal::Sigterm
+/// what a signal is aimed at, as far as predicting its effect goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetKind {
+    /// no controlling terminal.
+    Daemon,
+    /// attached to a terminal.
+    Interactive,
+    #[default]
+    Unknown,
+}
+
+impl TargetKind {
+    pub fn of(pid: u32) -> Self {
+        match process::has_controlling_tty(pid) {
+            Some(true) => TargetKind::Interactive,
+            Some(false) => TargetKind::Daemon,
+            None => TargetKind::Unknown,
+        }
     }
 }
 
@@ -207,18 +266,66 @@ pub struct SignalEvent {
     pub pid: u32,
     pub process_name: String,
     pub signal: Signal,
+    /// `si_value` payload when the signal was queued with sigqueue.
+    pub value: Option<i32>,
     pub result: Result<(), String>,
 }
 
+/// where signals are delivered. `ProcessManager` signals the live system; tests can
+/// record what would have been sent to a fixed list instead.
+pub trait SignalTarget {
+    /// the processes a signal can reach, for finding targets and their subtrees.
+    fn processes(&mut self) -> Vec<ProcessInfo>;
+
+    /// sends `signal` to `target`, queued with `value` when given, once it has passed
+    /// the pid 1 and self guards.
+    fn send(
+        &mut self,
+        target: &ProcessInfo,
+        signal: Signal,
+        value: Option<i32>,
+    ) -> Result<(), String>;
+}
+
+impl SignalTarget for ProcessManager {
+    fn processes(&mut self) -> Vec<ProcessInfo> {
+        self.get_processes(true)
+    }
+
+    fn send(
+        &mut self,
+        target: &ProcessInfo,
+        signal: Signal,
+        value: Option<i32>,
+    ) -> Result<(), String> {
+        ensure_permissions(target)?;
+        match value {
+            Some(value) => queue_to_pid(target.pid, signal, value),
+            None => send_to_pid(target.pid, signal),
+        }
+    }
+}
+
 pub struct SignalSender {
-    manager: ProcessManager,
+    target: Box<dyn SignalTarget>,
     history: VecDeque<SignalEvent>,
 }
 
+impl Default for SignalSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SignalSender {
     pub fn new() -> Self {
+        Self::with_target(Box::new(ProcessManager::new()))
+    }
+
+    /// sends through `target` instead of signalling the live system.
+    pub fn with_target(target: Box<dyn SignalTarget>) -> Self {
         Self {
-            manager: ProcessManager::new(),
+            target,
             history: VecDeque::with_capacity(10),
         }
     }
@@ -228,13 +335,24 @@ impl SignalSender {
     }
 
     pub fn send_signal(&mut self, pid: u32, signal: Signal) -> Result<(), String> {
-        match send_signal_with_manager(&mut self.manager, pid, signal) {
+        self.send_signal_with_value(pid, signal, None)
+    }
+
+    /// like `send_signal`, but queues the signal with `value` as its `si_value` when given.
+    pub fn send_signal_with_value(
+        &mut self,
+        pid: u32,
+        signal: Signal,
+        value: Option<i32>,
+    ) -> Result<(), String> {
+        match send_signal_to(self.target.as_mut(), pid, signal, value) {
             Ok(info) => {
                 self.push_event(SignalEvent {
                     timestamp: Utc::now(),
                     pid,
                     process_name: info.name.clone(),
                     signal,
+                    value,
                     result: Ok(()),
                 });
                 Ok(())
@@ -249,6 +367,7 @@ impl SignalSender {
                     pid,
                     process_name: name,
                     signal,
+                    value,
                     result: Err(err.clone()),
                 });
                 Err(err)
@@ -258,8 +377,7 @@ impl SignalSender {
 
     pub fn kill_process_tree(&mut self, root_pid: u32, signal: Signal) -> Result<Vec<u32>, String> {
         let mut events = Vec::new();
-        let outcome =
-            kill_process_tree_with_manager(&mut self.manager, root_pid, signal, &mut events);
+        let outcome = kill_process_tree_in(self.target.as_mut(), root_pid, signal, &mut events);
         for event in events {
             self.push_event(event);
         }
@@ -274,40 +392,116 @@ impl SignalSender {
     }
 
     fn lookup_process(&mut self, pid: u32) -> Option<ProcessInfo> {
-        self.manager
-            .get_processes(true)
-            .into_iter()
-            .find(|proc| proc.pid == pid)
+        lookup(self.target.as_mut(), pid).ok()
     }
 }
 
-#[allow(dead_code)]
+/// outcome of signalling a set of processes outside the tui.
+#[derive(Debug, Clone, Default)]
+pub struct KillReport {
+    /// pids that received the signal.
+    pub signalled: Vec<u32>,
+    /// pids that were skipped or failed, with the reason.
+    pub failed: Vec<(u32, String)>,
+}
+
+impl KillReport {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && !self.signalled.is_empty()
+    }
+}
+
+/// sends `signal` to `pid` after the same guards the tui applies: pid 1,
+/// pkillr itself and the invoking shell are refused, and the caller must own
+/// the process unless running as root.
+pub fn safe_kill(pid: u32, signal: Signal) -> Result<(), String> {
+    ensure_not_parent_shell(pid)?;
+    send_signal(pid, signal)
+}
+
+/// sends `signal` to every process whose name is exactly `name`, collecting
+/// a per-pid report instead of stopping at the first failure.
+pub fn kill_by_name(name: &str, signal: Signal) -> KillReport {
+    let mut manager = ProcessManager::new();
+    let mut report = KillReport::default();
+    let mut targets: Vec<u32> = manager
+        .get_processes(true)
+        .into_iter()
+        .filter(|proc| proc.name == name)
+        .map(|proc| proc.pid)
+        .collect();
+    targets.sort_unstable();
+
+    for pid in targets {
+        let result = ensure_not_parent_shell(pid)
+            .and_then(|_| send_signal_to(&mut manager, pid, signal, None).map(|_| ()));
+        match result {
+            Ok(()) => report.signalled.push(pid),
+            Err(err) => report.failed.push((pid, err)),
+        }
+    }
+    report
+}
+
 pub fn send_signal(pid: u32, signal: Signal) -> Result<(), String> {
     let mut manager = ProcessManager::new();
-    send_signal_with_manager(&mut manager, pid, signal).map(|_| ())
+    send_signal_to(&mut manager, pid, signal, None).map(|_| ())
+}
+
+/// how one of pkillr's own children stands after `reap_child`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reaped {
+    /// not finished yet, or only stopped.
+    Running,
+    Exited(i32),
+    Killed(Signal),
+}
+
+/// collects a child of this process that has ended, so it doesn't linger as a zombie.
+/// never blocks; only ever call it for our own children, reaping is the parent's job.
+pub fn reap_child(pid: u32) -> Result<Reaped, String> {
+    let status = waitpid(NixPid::from_raw(pid as i32), Some(WaitPidFlag::WNOHANG))
+        .map_err(|err| format!("waitpid {pid}: {err}"))?;
+    Ok(match status {
+        WaitStatus::Exited(_, code) => Reaped::Exited(code),
+        WaitStatus::Signaled(_, signal, _) => match Signal::from_number(signal as i32) {
+            Some(signal) => Reaped::Killed(signal),
+            None => Reaped::Exited(128 + signal as i32),
+        },
+        _ => Reaped::Running,
+    })
+}
+
+/// parses a sigqueue payload; `si_value` carries a C int.
+pub fn parse_signal_value(text: &str) -> Result<i32, String> {
+    let trimmed = text.trim();
+    let value: i64 = trimmed
+        .parse()
+        .map_err(|_| format!("invalid signal value '{}'", trimmed))?;
+    i32::try_from(value)
+        .map_err(|_| format!("signal value must be between {} and {}", i32::MIN, i32::MAX))
 }
 
-#[allow(dead_code)]
 pub fn kill_process_tree(root_pid: u32, signal: Signal) -> Result<Vec<u32>, String> {
     let mut manager = ProcessManager::new();
     let mut events = Vec::new();
-    kill_process_tree_with_manager(&mut manager, root_pid, signal, &mut events)
+    kill_process_tree_in(&mut manager, root_pid, signal, &mut events)
 }
 
-fn send_signal_with_manager(
-    manager: &mut ProcessManager,
+fn send_signal_to(
+    target: &mut dyn SignalTarget,
     pid: u32,
     signal: Signal,
+    value: Option<i32>,
 ) -> Result<ProcessInfo, String> {
-    let info = lookup(manager, pid)?;
+    let info = lookup(target, pid)?;
     validate_target(&info)?;
-    ensure_permissions(&info)?;
-    send_to_pid(pid, signal)?;
+    target.send(&info, signal, value)?;
     Ok(info)
 }
 
-fn kill_process_tree_with_manager(
-    manager: &mut ProcessManager,
+fn kill_process_tree_in(
+    target: &mut dyn SignalTarget,
     root_pid: u32,
     signal: Signal,
     events: &mut Vec<SignalEvent>,
@@ -319,11 +513,11 @@ fn kill_process_tree_with_manager(
         return Err("refusing to signal pkillr".to_string());
     }
 
-    let tree = collect_tree(manager, root_pid);
+    let tree = collect_tree(target, root_pid);
     let mut killed = Vec::new();
 
     for pid in tree {
-        let info = match lookup(manager, pid) {
+        let info = match lookup(target, pid) {
             Ok(info) => info,
             Err(err) => {
                 events.push(SignalEvent {
@@ -331,21 +525,21 @@ fn kill_process_tree_with_manager(
                     pid,
                     process_name: "unknown".to_string(),
                     signal,
+                    value: None,
                     result: Err(err.clone()),
                 });
                 return Err(format!("failed after killing {:?}: {}", killed, err));
             }
         };
 
-        let result = validate_target(&info)
-            .and_then(|_| ensure_permissions(&info))
-            .and_then(|_| send_to_pid(pid, signal));
+        let result = validate_target(&info).and_then(|_| target.send(&info, signal, None));
 
         events.push(SignalEvent {
             timestamp: Utc::now(),
             pid,
             process_name: info.name.clone(),
             signal,
+            value: None,
             result: result.clone(),
         });
 
@@ -360,9 +554,9 @@ fn kill_process_tree_with_manager(
     Ok(killed)
 }
 
-fn lookup(manager: &mut ProcessManager, pid: u32) -> Result<ProcessInfo, String> {
-    manager
-        .get_processes(true)
+fn lookup(target: &mut dyn SignalTarget, pid: u32) -> Result<ProcessInfo, String> {
+    target
+        .processes()
         .into_iter()
         .find(|proc| proc.pid == pid)
         .ok_or_else(|| "process not found".to_string())
@@ -378,6 +572,13 @@ fn validate_target(info: &ProcessInfo) -> Result<(), String> {
     Ok(())
 }
 
+fn ensure_not_parent_shell(pid: u32) -> Result<(), String> {
+    if pid == getppid().as_raw() as u32 && !Uid::current().is_root() {
+        return Err("refusing to signal the parent shell".to_string());
+    }
+    Ok(())
+}
+
 fn ensure_permissions(info: &ProcessInfo) -> Result<(), String> {
     let current_uid = Uid::current();
     if current_uid.as_raw() == 0 {
@@ -411,8 +612,28 @@ fn send_to_pid(pid: u32, signal: Signal) -> Result<(), String> {
     }
 }
 
-fn collect_tree(manager: &mut ProcessManager, root_pid: u32) -> Vec<u32> {
-    let processes = manager.get_processes(true);
+fn queue_to_pid(pid: u32, signal: Signal, value: i32) -> Result<(), String> {
+    // sival_int overlays the leading bytes of the union, whatever the endianness.
+    let mut bytes = [0u8; std::mem::size_of::<usize>()];
+    bytes[..4].copy_from_slice(&value.to_ne_bytes());
+    let sigval = libc::sigval {
+        sival_ptr: usize::from_ne_bytes(bytes) as *mut libc::c_void,
+    };
+    // SAFETY: sigqueue only reads its arguments; the pointer is never dereferenced.
+    let rc = unsafe { libc::sigqueue(pid as libc::pid_t, signal.number(), sigval) };
+    if rc == 0 {
+        return Ok(());
+    }
+    match Errno::last() {
+        Errno::EPERM => Err("permission denied (needs sudo)".to_string()),
+        Errno::ESRCH => Err("process not found".to_string()),
+        Errno::EAGAIN => Err("signal queue limit reached".to_string()),
+        err => Err(format!("failed to queue {}: {}", signal.name(), err)),
+    }
+}
+
+fn collect_tree(target: &mut dyn SignalTarget, root_pid: u32) -> Vec<u32> {
+    let processes = target.processes();
     let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
 
     for process in &processes {
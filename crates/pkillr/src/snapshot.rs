@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::thread;
+
+use chrono::Utc;
+use sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
+
+use crate::process::ProcessManager;
+use crate::ui::tree_view::format_bytes;
+use pkillr::ProcessDto;
+use pkillr::{Json, parse_json, quote_json};
+
+/// the snapshots match.
+const EXIT_SAME: i32 = 0;
+/// processes appeared, disappeared or changed.
+const EXIT_DIFFERENT: i32 = 1;
+/// a snapshot could not be read or written.
+const EXIT_TROUBLE: i32 = 2;
+
+/// cpu percentage points a process must move by to count as changed.
+const CPU_CHANGE_POINTS: f32 = 5.0;
+/// relative memory growth or shrinkage that counts as changed...
+const MEMORY_CHANGE_RATIO: f64 = 0.10;
+/// ...as long as it is also at least this many bytes.
+const MEMORY_CHANGE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// `--snapshot`: samples every process twice (for cpu) and writes them as json,
+/// one `ProcessDto` per process so snapshots share the export schema.
+pub fn write(path: &Path) -> i32 {
+    let mut manager = ProcessManager::new();
+    thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    let mut entries: Vec<ProcessDto> = manager
+        .get_processes(true)
+        .iter()
+        .map(ProcessDto::from)
+        .collect();
+    entries.sort_by_key(|entry| entry.pid);
+    match fs::write(path, to_json(&entries)) {
+        Ok(()) => {
+            eprintln!("wrote {} processes to {}", entries.len(), path.display());
+            EXIT_SAME
+        }
+        Err(err) => {
+            eprintln!("failed to write {}: {}", path.display(), err);
+            EXIT_TROUBLE
+        }
+    }
+}
+
+/// `--diff`: prints what changed between two snapshots. exits 0 when nothing
+/// did, 1 when something did and 2 when a file can't be read.
+pub fn diff_files(before: &Path, after: &Path) -> i32 {
+    let load = |path: &Path| {
+        fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))
+            .and_then(|text| from_json(&text).map_err(|err| format!("{}: {}", path.display(), err)))
+    };
+    let (before, after) = match (load(before), load(after)) {
+        (Ok(before), Ok(after)) => (before, after),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("{err}");
+            return EXIT_TROUBLE;
+        }
+    };
+    let lines = diff(&before, &after);
+    for line in &lines {
+        println!("{line}");
+    }
+    if lines.is_empty() {
+        EXIT_SAME
+    } else {
+        EXIT_DIFFERENT
+    }
+}
+
+/// `+` appeared, `-` disappeared, `~` changed noticeably; sorted by pid. a
+/// reused pid under a new name counts as one process leaving and another arriving.
+pub fn diff(before: &[ProcessDto], after: &[ProcessDto]) -> Vec<String> {
+    let key = |entry: &ProcessDto| (entry.pid, entry.name.clone());
+    let old: HashMap<_, _> = before.iter().map(|entry| (key(entry), entry)).collect();
+    let new: HashMap<_, _> = after.iter().map(|entry| (key(entry), entry)).collect();
+
+    let mut lines: BTreeMap<(u32, u8, String), String> = BTreeMap::new();
+    for (id, entry) in &old {
+        if !new.contains_key(id) {
+            lines.insert(
+                (entry.pid, 0, entry.name.clone()),
+                format!("- {}", describe(entry)),
+            );
+        }
+    }
+    for (id, entry) in &new {
+        match old.get(id) {
+            None => {
+                lines.insert(
+                    (entry.pid, 1, entry.name.clone()),
+                    format!("+ {}", describe(entry)),
+                );
+            }
+            Some(previous) => {
+                if let Some(change) = describe_change(previous, entry) {
+                    lines.insert((entry.pid, 2, entry.name.clone()), change);
+                }
+            }
+        }
+    }
+    lines.into_values().collect()
+}
+
+fn describe(entry: &ProcessDto) -> String {
+    format!(
+        "{} {} ({}) cpu {:.1}% mem {}",
+        entry.pid,
+        entry.name,
+        entry.user,
+        entry.cpu_percent,
+        format_bytes(entry.memory_bytes)
+    )
+}
+
+fn describe_change(before: &ProcessDto, after: &ProcessDto) -> Option<String> {
+    let mut parts = Vec::new();
+    if (after.cpu_percent - before.cpu_percent).abs() >= CPU_CHANGE_POINTS {
+        parts.push(format!(
+            "cpu {:.1}% -> {:.1}%",
+            before.cpu_percent, after.cpu_percent
+        ));
+    }
+    let delta = after.memory_bytes.abs_diff(before.memory_bytes);
+    if delta >= MEMORY_CHANGE_BYTES
+        && delta as f64 >= before.memory_bytes as f64 * MEMORY_CHANGE_RATIO
+    {
+        parts.push(format!(
+            "mem {} -> {}",
+            format_bytes(before.memory_bytes),
+            format_bytes(after.memory_bytes)
+        ));
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "~ {} {} {}",
+        after.pid,
+        after.name,
+        parts.join(", ")
+    ))
+}
+
+fn to_json(entries: &[ProcessDto]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(
+        out,
+        "  \"taken_at\": {},",
+        quote_json(&Utc::now().to_rfc3339())
+    );
+    let _ = writeln!(out, "  \"processes\": [");
+    for (idx, entry) in entries.iter().enumerate() {
+        let _ = write!(out, "    {}", entry.to_json());
+        out.push_str(if idx + 1 < entries.len() { ",\n" } else { "\n" });
+    }
+    let _ = writeln!(out, "  ]");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+fn from_json(text: &str) -> Result<Vec<ProcessDto>, String> {
+    let root = parse_json(text)?;
+    let processes = root
+        .field("processes")
+        .and_then(Json::as_array)
+        .ok_or("missing \"processes\" array")?;
+    processes
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            ProcessDto::from_value(value).map_err(|err| format!("process {}: {}", idx + 1, err))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, from_json, to_json};
+    use pkillr::{ProcessDto, SCHEMA_VERSION};
+
+    fn entry(pid: u32, name: &str, cpu_percent: f32, memory_mib: u64) -> ProcessDto {
+        ProcessDto {
+            schema_version: SCHEMA_VERSION,
+            pid,
+            parent_pid: Some(1),
+            name: name.to_string(),
+            user: "tester".to_string(),
+            state: "Sleeping".to_string(),
+            cpu_percent,
+            memory_bytes: memory_mib * 1024 * 1024,
+            runtime_secs: 60,
+            cpu_time_ms: 1_500,
+            cmdline: vec![format!("/usr/bin/{name}"), "--title=\"a\\b\"\n".to_string()],
+            cwd: None,
+        }
+    }
+
+    #[test]
+    fn snapshots_round_trip_through_json() {
+        let entries = vec![
+            entry(1, "init", 0.0, 4),
+            entry(42, "naïve worker", 12.5, 300),
+        ];
+        assert_eq!(from_json(&to_json(&entries)).unwrap(), entries);
+        assert!(from_json("{\"processes\": [{\"pid\": 1}]}").is_err());
+    }
+
+    #[test]
+    fn diff_marks_arrivals_departures_and_big_changes_by_pid() {
+        let before = vec![
+            entry(10, "api", 2.0, 100),
+            entry(20, "cache", 1.0, 500),
+            entry(30, "cron", 0.0, 10),
+        ];
+        let after = vec![
+            entry(10, "api", 40.0, 102),
+            entry(20, "cache", 1.5, 800),
+            entry(30, "backup", 3.0, 50),
+        ];
+        let lines = diff(&before, &after);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "~ 10 api cpu 2.0% -> 40.0%");
+        assert!(lines[1].starts_with("~ 20 cache mem "));
+        assert!(lines[2].starts_with("- 30 cron "));
+        assert!(lines[3].starts_with("+ 30 backup "));
+        assert!(diff(&after, &after).is_empty());
+    }
+}
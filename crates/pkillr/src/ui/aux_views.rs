@@ -1,11 +1,15 @@
 use chrono::Local;
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::Constraint;
+use ratatui::layout::{Margin, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap};
 
-use crate::app::App;
+use crate::risk::RiskLevel;
+use crate::signals::Signal;
+use crate::ui::{popup_rect, scroll_to_show, tree_view};
+use pkillr::{App, process_count};
 
 pub fn render_signal_history(frame: &mut Frame, area: Rect, app: &App) {
     let popup = centered_rect(60, 70, area);
@@ -41,7 +45,7 @@ pub fn render_signal_history(frame: &mut Frame, area: Rect, app: &App) {
                 Err(err) => app.friendly_error_message(err),
             };
 
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::raw("           "),
                 Span::styled(
                     entry.signal.name(),
@@ -49,9 +53,16 @@ pub fn render_signal_history(frame: &mut Frame, area: Rect, app: &App) {
                         .fg(palette.text_normal)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::raw(" \u{2192} "),
-                Span::styled(status_text, Style::default().fg(status_color)),
-            ]));
+            ];
+            if let Some(value) = entry.value {
+                spans.push(Span::styled(
+                    format!(" (value {})", value),
+                    Style::default().fg(palette.text_dim),
+                ));
+            }
+            spans.push(Span::raw(" \u{2192} "));
+            spans.push(Span::styled(status_text, Style::default().fg(status_color)));
+            lines.push(Line::from(spans));
 
             if idx + 1 < history.len() {
                 lines.push(Line::default());
@@ -77,7 +88,135 @@ pub fn render_signal_history(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(paragraph, popup);
 }
 
-pub fn render_help_popup(frame: &mut Frame, area: Rect, app: &App) {
+pub fn render_views_popup(frame: &mut Frame, area: Rect, app: &App) {
+    let popup = centered_rect(60, 50, area);
+    let palette = app.theme().palette();
+
+    let mut lines: Vec<Line> = app
+        .saved_views()
+        .iter()
+        .enumerate()
+        .map(|(idx, view)| {
+            let selected = idx == app.views_selected();
+            let name_style = if selected {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(palette.highlight_selected)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+                    .fg(palette.text_normal)
+                    .add_modifier(Modifier::BOLD)
+            };
+            Line::from(vec![
+                Span::styled(format!(" {:<16}", view.name), name_style),
+                Span::raw(" "),
+                Span::styled(view.query.clone(), Style::default().fg(palette.kill_accent)),
+            ])
+        })
+        .collect();
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "Enter apply | d delete | Esc close",
+        Style::default().fg(palette.text_dim),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.table_border))
+        .title(Line::from(Span::styled(
+            " Saved Views ",
+            Style::default()
+                .fg(palette.table_header)
+                .add_modifier(Modifier::BOLD),
+        )));
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(paragraph, popup);
+}
+
+pub fn render_users_popup(frame: &mut Frame, area: Rect, app: &mut App) {
+    let popup = centered_rect(60, 60, area);
+    let palette = app.theme().palette();
+    let summaries = app.user_summaries();
+
+    // borders and the header row
+    let visible = popup.height.saturating_sub(3) as usize;
+    let offset = scroll_to_show(
+        app.users_scroll_offset(),
+        app.users_selected(),
+        summaries.len(),
+        visible,
+    );
+    app.set_users_scroll_offset(offset);
+
+    let header = Row::new(["User", "Procs", "CPU%", "Memory"].map(|title| {
+        Cell::from(title).style(
+            Style::default()
+                .fg(palette.table_header)
+                .add_modifier(Modifier::BOLD),
+        )
+    }));
+    let rows: Vec<Row> = summaries
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(idx, summary)| {
+            let style = if idx == app.users_selected() {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(palette.highlight_selected)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(palette.text_normal)
+            };
+            Row::new(vec![
+                Cell::from(summary.user.clone()),
+                Cell::from(format!("{:>5}", summary.processes)),
+                Cell::from(format!("{:>6.1}", summary.cpu_percent)),
+                Cell::from(format!(
+                    "{:>9}",
+                    tree_view::format_bytes(summary.memory_bytes)
+                )),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.table_border))
+        .title(Line::from(Span::styled(
+            format!(" Processes by User (by {}) ", app.users_sort().label()),
+            Style::default()
+                .fg(palette.table_header)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .title_bottom(Line::from(Span::styled(
+            " Enter filter to user | s sort | Esc close ",
+            Style::default().fg(palette.text_dim),
+        )));
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(12),
+            Constraint::Length(6),
+            Constraint::Length(7),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(table, popup);
+}
+
+pub fn render_help_popup(frame: &mut Frame, area: Rect, app: &mut App) {
     let popup = centered_rect(70, 80, area);
     let palette = app.theme().palette();
 
@@ -87,72 +226,52 @@ pub fn render_help_popup(frame: &mut Frame, area: Rect, app: &App) {
     let body = Style::default().fg(palette.text_normal);
     let dim = Style::default().fg(palette.text_dim);
 
+    let filter_line = if app.help_filter_editing() {
+        Line::from(vec![
+            Span::styled("filter: ", heading),
+            Span::styled(format!("{}_", app.help_filter()), body),
+        ])
+    } else if app.help_filter().is_empty() {
+        Line::from(Span::styled("/ filter  j/k scroll", dim))
+    } else {
+        Line::from(vec![
+            Span::styled("filter: ", heading),
+            Span::styled(app.help_filter().to_string(), body),
+        ])
+    };
+
+    let entries = app.help_entries();
+    let keys_width = entries
+        .iter()
+        .map(|entry| entry.keys.chars().count() + 2)
+        .max()
+        .unwrap_or(0)
+        .max(10);
     let mut lines = Vec::new();
-    lines.push(Line::default());
-    lines.push(Line::from(Span::styled("NAVIGATION", heading)));
-    lines.push(Line::from(Span::styled("  ↑↓ / j k  move selection", body)));
-    lines.push(Line::from(Span::styled(
-        "  g G       jump top/bottom",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  < >       cycle sort column",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  Esc       close info/tree",
-        body,
-    )));
-    lines.push(Line::default());
-    lines.push(Line::from(Span::styled("ACTIONS", heading)));
-    lines.push(Line::from(Span::styled("  /         fuzzy search", body)));
-    lines.push(Line::from(Span::styled("  /^...$/  regex filter", body)));
-    lines.push(Line::from(Span::styled("  /killed  history filter", body)));
-    lines.push(Line::from(Span::styled(
-        "  Space     select / toggle",
-        body,
-    )));
-    lines.push(Line::from(Span::styled("  Enter/k   kill (SIGTERM)", body)));
-    lines.push(Line::from(Span::styled(
-        "  K         force kill (SIGKILL)",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  x         kill tree (preview)",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  s         open signal menu",
-        body,
-    )));
-    lines.push(Line::default());
-    lines.push(Line::from(Span::styled("VIEWS", heading)));
-    lines.push(Line::from(Span::styled(
-        "  i         toggle info pane",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  Tab       switch info focus",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  e/f/m/n/c toggle info sections",
-        body,
-    )));
-    lines.push(Line::from(Span::styled(
-        "  t         toggle process tree",
-        body,
-    )));
-    lines.push(Line::from(Span::styled("  h         signal history", body)));
-    lines.push(Line::default());
-    lines.push(Line::from(Span::styled("  ?         this help", body)));
-    lines.push(Line::from(Span::styled("  q         quit", body)));
-    lines.push(Line::from(Span::styled("  Ctrl+C    quit instantly", body)));
-    lines.push(Line::default());
-    lines.push(Line::from(Span::styled(
-        "Press any key to close",
-        dim.add_modifier(Modifier::ITALIC),
-    )));
+    let mut section = "";
+    for entry in entries {
+        if entry.section != section {
+            if !section.is_empty() {
+                lines.push(Line::default());
+            }
+            section = entry.section;
+            lines.push(Line::from(Span::styled(section, heading)));
+        }
+        lines.push(Line::from(Span::styled(
+            format!("  {:<keys_width$}{}", entry.keys, entry.description),
+            body,
+        )));
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled("no matching bindings", dim)));
+    }
+
+    // filter line on top, close hint at the bottom, both outside the scrolled list
+    let inner = popup.inner(&Margin::new(1, 1));
+    let list_height = inner.height.saturating_sub(2) as usize;
+    app.set_help_max_scroll(lines.len().saturating_sub(list_height));
+    let offset = app.help_scroll();
+    let visible: Vec<Line> = lines.into_iter().skip(offset).take(list_height).collect();
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -164,21 +283,172 @@ pub fn render_help_popup(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         )));
 
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
-
     frame.render_widget(Clear, popup);
-    frame.render_widget(paragraph, popup);
+    frame.render_widget(block, popup);
+    if inner.height == 0 {
+        return;
+    }
+    frame.render_widget(Paragraph::new(filter_line), Rect { height: 1, ..inner });
+    if list_height > 0 {
+        frame.render_widget(
+            Paragraph::new(visible),
+            Rect {
+                y: inner.y + 1,
+                height: list_height as u16,
+                ..inner
+            },
+        );
+    }
+    if inner.height >= 2 {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "Press Esc to close",
+                dim.add_modifier(Modifier::ITALIC),
+            ))),
+            Rect {
+                y: inner.y + inner.height - 1,
+                height: 1,
+                ..inner
+            },
+        );
+    }
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
-    let popup_width = (area.width * percent_x) / 100;
-    let popup_height = (area.height * percent_y) / 100;
-    Rect {
-        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
-        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
-        width: popup_width.max(1),
-        height: popup_height.max(1),
+    let popup_width = (area.width as u32 * percent_x as u32 / 100) as u16;
+    let popup_height = (area.height as u32 * percent_y as u32 / 100) as u16;
+    popup_rect(area, popup_width.max(1), popup_height.max(1))
+}
+
+pub fn render_batch_kill_prompt(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(prompt) = app.batch_kill_prompt() else {
+        return;
+    };
+    let palette = app.theme().palette();
+    let risk_style = |level: RiskLevel| match level {
+        RiskLevel::Critical => ("CRITICAL", palette.status_error),
+        RiskLevel::Elevated => ("warning", palette.status_warning),
+    };
+
+    let mut header = vec![
+        Line::from(vec![
+            Span::raw("Send "),
+            Span::styled(
+                prompt.signal.name(),
+                Style::default()
+                    .fg(palette.kill_accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " to {} matching '{}':",
+                process_count(prompt.targets.len()),
+                prompt.query
+            )),
+        ]),
+        Line::default(),
+    ];
+
+    let mut footer = vec![Line::default()];
+    let critical = prompt
+        .targets
+        .iter()
+        .filter(|target| matches!(&target.risk, Some(risk) if risk.level == RiskLevel::Critical))
+        .count();
+    let elevated = prompt
+        .targets
+        .iter()
+        .filter(|target| matches!(&target.risk, Some(risk) if risk.level == RiskLevel::Elevated))
+        .count();
+    if critical + elevated > 0 {
+        let color = if critical > 0 {
+            palette.status_error
+        } else {
+            palette.status_warning
+        };
+        footer.push(Line::from(Span::styled(
+            format!("Risk: {} critical, {} elevated", critical, elevated),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+    footer.push(Line::from(Span::styled(
+        "y send | n/Esc cancel | Tab change signal",
+        Style::default().fg(palette.text_dim),
+    )));
+
+    // the target list gets whatever height the frame leaves after header and footer
+    let fixed = header.len() + footer.len() + 2;
+    let room = (area.height as usize).saturating_sub(fixed + 2).max(1);
+    let shown = if prompt.targets.len() > room {
+        room.saturating_sub(1)
+    } else {
+        prompt.targets.len()
+    };
+    let mut rows: Vec<Line> = prompt
+        .targets
+        .iter()
+        .take(shown)
+        .map(|target| {
+            let mut spans = vec![Span::styled(
+                format!("  {:>7}  {}", target.pid, target.name),
+                Style::default().fg(palette.text_normal),
+            )];
+            if let Some(risk) = &target.risk {
+                let (label, color) = risk_style(risk.level);
+                spans.push(Span::styled(
+                    format!("  [{}: {}]", label, risk.reason),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+            }
+            if let Some((tracer, name)) = &target.tracer
+                && prompt.signal != Signal::Sigkill
+            {
+                spans.push(Span::styled(
+                    format!("  [traced by PID {tracer} ({name}), may intercept]"),
+                    Style::default().fg(palette.status_warning),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    if shown < prompt.targets.len() {
+        rows.push(Line::from(Span::styled(
+            format!("  … and {} more", prompt.targets.len() - shown),
+            Style::default().fg(palette.text_dim),
+        )));
     }
+
+    let mut content = Vec::new();
+    content.append(&mut header);
+    content.append(&mut rows);
+    content.append(&mut footer);
+
+    let width = content
+        .iter()
+        .map(|line| line.width())
+        .max()
+        .unwrap_or(20)
+        .max(30)
+        + 4;
+    let popup = popup_rect(
+        area,
+        width.min(u16::MAX as usize) as u16,
+        (content.len() + 2).min(u16::MAX as usize) as u16,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.kill_accent))
+        .title(Line::from(Span::styled(
+            " Signal Filtered Processes? ",
+            Style::default()
+                .fg(palette.kill_accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(paragraph, popup);
 }
@@ -1,12 +1,19 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
-use crate::app::App;
-use crate::config::Palette;
-use crate::process::{ChildProcess, ProcessDetails};
+use crate::config::{Palette, PathDisplay, TreeConnectors};
+use crate::process::{
+    ChildProcess, EnvChange, FdSummary, ProcessDetails, argv0_basename, diff_environment,
+    truncate_env_entry,
+};
+use crate::ui::tree_view::format_bytes;
+use pkillr::{App, EnvReference, InfoSection};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let palette = app.theme().palette();
@@ -23,21 +30,69 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let maps_expanded = app.info_maps_expanded();
     let network_expanded = app.info_network_expanded();
     let cgroups_expanded = app.info_cgroups_expanded();
-
-    let has_selection = app.current_pid().is_some();
-
+    let env_limit = (app.info_environment_limit(), app.info_env_entry_max());
+    let files_limit = app.info_open_files_limit();
+    let maps_limit = app.info_maps_limit();
+
+    let args_joined = app.info_args_joined();
+    let path_display = app.info_path_display();
+    let search_query = app.search_query().trim().to_string();
+    let has_selection = app.info_pid().is_some();
+    let env_reference = app.env_reference().cloned();
+    let reference_alive = app.env_reference_alive();
+    let connectors = app.tree_connectors();
+    let exited = app
+        .info_exited()
+        .map(|(pid, name)| format!("{name} (PID {pid}) exited; its details are gone."));
+
+    let mut starts: Vec<(InfoSection, usize)> = Vec::new();
     if let Some(details) = app.process_details() {
-        build_basic_section(&mut lines, &palette, details);
-        build_command_section(&mut lines, &palette, details);
-        build_children_section(&mut lines, &palette, details.children.as_slice());
+        starts.push((InfoSection::Basic, lines.len()));
+        build_basic_section(&mut lines, &palette, details, path_display);
+        starts.push((InfoSection::Command, lines.len()));
+        build_command_section(&mut lines, &palette, details, args_joined, &search_query);
+        starts.push((InfoSection::Children, lines.len()));
+        build_children_section(
+            &mut lines,
+            &palette,
+            details.children.as_slice(),
+            connectors,
+        );
+        starts.push((InfoSection::Capabilities, lines.len()));
         build_capabilities_section(&mut lines, &palette, details);
-        build_environment_section(&mut lines, &palette, env_expanded, details);
-        build_open_files_section(&mut lines, &palette, files_expanded, details);
-        build_memory_map_section(&mut lines, &palette, maps_expanded, details);
+        starts.push((InfoSection::Environment, lines.len()));
+        match env_reference
+            .as_ref()
+            .filter(|reference| reference.pid != details.pid)
+        {
+            Some(reference) => build_environment_diff_section(
+                &mut lines,
+                &palette,
+                env_expanded,
+                env_limit,
+                details,
+                (reference, reference_alive),
+            ),
+            None => {
+                build_environment_section(&mut lines, &palette, env_expanded, env_limit, details)
+            }
+        }
+        starts.push((InfoSection::OpenFiles, lines.len()));
+        build_open_files_section(&mut lines, &palette, files_expanded, files_limit, details);
+        starts.push((InfoSection::MemoryMaps, lines.len()));
+        build_memory_map_section(&mut lines, &palette, maps_expanded, maps_limit, details);
+        starts.push((InfoSection::Network, lines.len()));
         build_network_section(&mut lines, &palette, network_expanded, details);
+        starts.push((InfoSection::Cgroups, lines.len()));
         build_cgroup_section(&mut lines, &palette, cgroups_expanded, details);
     } else {
-        if has_selection {
+        if let Some(exited) = exited {
+            lines.push(Line::from(Span::styled(
+                exited,
+                Style::default().fg(palette.status_warning),
+            )));
+            lines.push(Line::from("It leaves the list on the next refresh."));
+        } else if has_selection {
             lines.push(Line::from("Process terminated or inaccessible."));
         } else {
             lines.push(Line::from("No process selected."));
@@ -48,6 +103,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     if lines.is_empty() {
         lines.push(Line::default());
     }
+    let offsets = wrapped_offsets(&lines, area.width.saturating_sub(2));
+    let section_starts = starts
+        .into_iter()
+        .map(|(section, start)| (section, wrapped_offset(&lines, &offsets, start)))
+        .collect();
+    app.set_info_section_starts(section_starts);
 
     let mut title_spans = vec![Span::styled(
         " Process Details ",
@@ -72,7 +133,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     frame.render_widget(paragraph, area);
 }
 
-fn build_basic_section(lines: &mut Vec<Line>, palette: &Palette, details: &ProcessDetails) {
+fn build_basic_section(
+    lines: &mut Vec<Line>,
+    palette: &Palette,
+    details: &ProcessDetails,
+    path_display: PathDisplay,
+) {
     let label = label_style(palette);
     let value = value_style(palette);
 
@@ -84,6 +150,17 @@ fn build_basic_section(lines: &mut Vec<Line>, palette: &Palette, details: &Proce
         ]),
     );
 
+    push_line(
+        lines,
+        Line::from(vec![
+            Span::styled("argv[0]: ", label),
+            match argv0_basename(&details.cmdline) {
+                Some(argv0) => Span::styled(argv0.to_string(), value),
+                None => Span::styled("(no argv)", Style::default().fg(palette.text_dim)),
+            },
+        ]),
+    );
+
     push_line(
         lines,
         Line::from(vec![
@@ -114,39 +191,196 @@ fn build_basic_section(lines: &mut Vec<Line>, palette: &Palette, details: &Proce
         ]),
     );
 
-    push_blank_line(lines);
+    push_line(
+        lines,
+        Line::from(vec![
+            Span::styled("IO Priority: ", label),
+            Span::styled(
+                details
+                    .io_priority
+                    .clone()
+                    .unwrap_or_else(|| "n/a".to_string()),
+                value,
+            ),
+        ]),
+    );
+
+    if let Some(tracer) = details.tracer_pid {
+        let name = details.tracer_name.as_deref().unwrap_or("unknown");
+        push_line(
+            lines,
+            Line::from(vec![
+                Span::styled("Traced by: ", label),
+                Span::styled(
+                    format!("PID {tracer} ({name}); signals may be intercepted"),
+                    Style::default().fg(palette.status_warning),
+                ),
+            ]),
+        );
+    }
+
+    push_line(
+        lines,
+        Line::from(vec![
+            Span::styled("CPUs: ", label),
+            Span::styled(
+                details
+                    .cpu_affinity
+                    .clone()
+                    .unwrap_or_else(|| "n/a".to_string()),
+                value,
+            ),
+        ]),
+    );
+
+    if let (Some(minor), Some(major)) = (details.minor_faults, details.major_faults) {
+        // major faults wait on disk, so they're the ones worth drawing the eye to.
+        let major_style = if major > 0 {
+            Style::default().fg(palette.status_warning)
+        } else {
+            value
+        };
+        push_line(
+            lines,
+            Line::from(vec![
+                Span::styled("Page Faults: ", label),
+                Span::styled(format!("{major} major"), major_style),
+                Span::styled(format!(", {minor} minor"), value),
+            ]),
+        );
+    }
+
+    let sched = match (details.sched_run, details.sched_wait) {
+        (Some(run), Some(wait)) => {
+            let wanted = (run + wait).as_secs_f64();
+            let share = if wanted > 0.0 {
+                wait.as_secs_f64() / wanted * 100.0
+            } else {
+                0.0
+            };
+            // a quarter of its runnable time spent queued means the cpus are contended.
+            let style = if share >= 25.0 {
+                Style::default().fg(palette.status_warning)
+            } else {
+                value
+            };
+            Span::styled(
+                format!(
+                    "{} on cpu, {} waiting to run ({share:.0}%)",
+                    format_sched_time(run),
+                    format_sched_time(wait)
+                ),
+                style,
+            )
+        }
+        _ => Span::styled("n/a (kernel without schedstats)", value),
+    };
+    push_line(
+        lines,
+        Line::from(vec![Span::styled("Scheduler: ", label), sched]),
+    );
 
-    let cwd = details
-        .cwd
-        .as_ref()
-        .map(|path| path.to_string_lossy().into_owned())
-        .unwrap_or_else(|| "<unknown>".to_string());
     push_line(
         lines,
         Line::from(vec![
-            Span::styled("Working Dir: ", label),
-            Span::styled(cwd, value),
+            Span::styled("Swap: ", label),
+            Span::styled(
+                details
+                    .swap_bytes
+                    .map(format_bytes)
+                    .unwrap_or_else(|| "n/a".to_string()),
+                value,
+            ),
         ]),
     );
+
+    push_blank_line(lines);
+
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    for (title, path) in [
+        ("Working Dir: ", &details.cwd),
+        ("Executable: ", &details.exe),
+    ] {
+        let text = path
+            .as_deref()
+            .map(|path| path_display.render(path, home.as_deref()))
+            .unwrap_or_else(|| "<unknown>".to_string());
+        push_line(
+            lines,
+            Line::from(vec![Span::styled(title, label), Span::styled(text, value)]),
+        );
+    }
 }
 
-fn build_command_section(lines: &mut Vec<Line>, palette: &Palette, details: &ProcessDetails) {
+fn build_command_section(
+    lines: &mut Vec<Line>,
+    palette: &Palette,
+    details: &ProcessDetails,
+    joined: bool,
+    query: &str,
+) {
     push_blank_line(lines);
     let label = label_style(palette);
+    let mode = if joined { "joined" } else { "per argument" };
     push_line(
         lines,
-        Line::from(Span::styled("Command:", label.add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::styled("Command:", label.add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!(" ({mode}, A to switch)"),
+                Style::default().fg(palette.text_dim),
+            ),
+        ]),
     );
+    lines.extend(command_lines(palette, &details.cmdline, joined, query));
+}
 
-    let command = if details.cmdline.is_empty() {
-        "<unknown>".to_string()
-    } else {
-        details.cmdline.join(" ")
-    };
-    push_line(lines, Line::from(format!("  {}", command)));
+/// the command line either joined or one argument per line, argv[0] in bold and
+/// arguments containing the search query highlighted.
+fn command_lines(
+    palette: &Palette,
+    cmdline: &[String],
+    joined: bool,
+    query: &str,
+) -> Vec<Line<'static>> {
+    if cmdline.is_empty() {
+        return vec![Line::from(Span::styled(
+            "  <none>",
+            Style::default().fg(palette.text_dim),
+        ))];
+    }
+    if joined {
+        return vec![Line::from(format!("  {}", cmdline.join(" ")))];
+    }
+
+    let query = query.to_lowercase();
+    let value = value_style(palette);
+    cmdline
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| {
+            let mut style = if idx == 0 {
+                value.add_modifier(Modifier::BOLD)
+            } else {
+                value
+            };
+            if !query.is_empty() && arg.to_lowercase().contains(&query) {
+                style = style
+                    .fg(palette.kill_accent)
+                    .add_modifier(Modifier::UNDERLINED);
+            }
+            let indent = if idx == 0 { "  " } else { "    " };
+            Line::from(vec![Span::raw(indent), Span::styled(arg.clone(), style)])
+        })
+        .collect()
 }
 
-fn build_children_section(lines: &mut Vec<Line>, palette: &Palette, children: &[ChildProcess]) {
+fn build_children_section(
+    lines: &mut Vec<Line>,
+    palette: &Palette,
+    children: &[ChildProcess],
+    connectors: TreeConnectors,
+) {
     push_blank_line(lines);
     let label = label_style(palette);
     push_line(
@@ -164,9 +398,9 @@ fn build_children_section(lines: &mut Vec<Line>, palette: &Palette, children: &[
 
     for (index, child) in children.iter().enumerate() {
         let branch = if index + 1 == children.len() {
-            "└─"
+            connectors.last
         } else {
-            "├─"
+            connectors.branch
         };
         let entry = format!(
             "  {} {} {} ({})",
@@ -203,6 +437,7 @@ fn build_environment_section(
     lines: &mut Vec<Line>,
     palette: &Palette,
     expanded: bool,
+    (limit, max_len): (usize, usize),
     details: &ProcessDetails,
 ) {
     push_blank_line(lines);
@@ -211,16 +446,23 @@ fn build_environment_section(
         push_line(
             lines,
             Line::from(Span::styled(
-                "Environment (press e to collapse):",
+                format!(
+                    "Environment{} (press e to collapse):",
+                    count_suffix(details.environment.len(), limit)
+                ),
                 label.add_modifier(Modifier::BOLD),
             )),
         );
         if details.environment.is_empty() {
             push_line(lines, Line::from("  <unavailable>"));
         } else {
-            for entry in &details.environment {
-                push_line(lines, Line::from(format!("  {}", entry)));
+            for entry in details.environment.iter().take(limit) {
+                push_line(
+                    lines,
+                    Line::from(format!("  {}", truncate_env_entry(entry, max_len))),
+                );
             }
+            push_more_hint(lines, palette, details.environment.len(), limit);
         }
     } else {
         push_line(
@@ -230,33 +472,144 @@ fn build_environment_section(
     }
 }
 
+fn build_environment_diff_section(
+    lines: &mut Vec<Line>,
+    palette: &Palette,
+    expanded: bool,
+    (limit, max_len): (usize, usize),
+    details: &ProcessDetails,
+    (reference, alive): (&EnvReference, bool),
+) {
+    push_blank_line(lines);
+    let label = label_style(palette);
+    let mut changes = diff_environment(&reference.environment, &details.environment);
+    // differences first; identical variables trail in dim text
+    changes.sort_by_key(|change| matches!(change, EnvChange::Same(_)));
+    let differing = changes
+        .iter()
+        .filter(|change| !matches!(change, EnvChange::Same(_)))
+        .count();
+    let against = format!(
+        "PID {} ({}{})",
+        reference.pid,
+        reference.name,
+        if alive { "" } else { ", exited" }
+    );
+
+    if !expanded {
+        push_line(
+            lines,
+            Line::from(Span::styled(
+                format!(
+                    "Environment: {} differ from {} (press e to expand)",
+                    differing, against
+                ),
+                label,
+            )),
+        );
+        return;
+    }
+
+    push_line(
+        lines,
+        Line::from(Span::styled(
+            format!(
+                "Environment vs {}{} (press e to collapse):",
+                against,
+                count_suffix(changes.len(), limit)
+            ),
+            label.add_modifier(Modifier::BOLD),
+        )),
+    );
+    if details.environment.is_empty() {
+        push_line(lines, Line::from("  <unavailable>"));
+        return;
+    }
+    // the diff runs on full values; only what is drawn gets cut short
+    let shown = |text: &str| truncate_env_entry(text, max_len).into_owned();
+    for change in changes.iter().take(limit) {
+        let line = match change {
+            EnvChange::Same(entry) => Line::from(Span::styled(
+                format!("  {}", shown(entry)),
+                Style::default().fg(palette.text_dim),
+            )),
+            EnvChange::Added(entry) => Line::from(Span::styled(
+                format!("+ {}", shown(entry)),
+                Style::default().fg(Color::Green),
+            )),
+            EnvChange::Removed(entry) => Line::from(Span::styled(
+                format!("- {}", shown(entry)),
+                Style::default().fg(palette.status_error),
+            )),
+            EnvChange::Changed {
+                name,
+                reference,
+                current,
+            } => Line::from(vec![
+                Span::styled(
+                    format!("~ {}={}", name, shown(current)),
+                    Style::default().fg(palette.status_warning),
+                ),
+                Span::styled(
+                    format!(" (was {})", shown(reference)),
+                    Style::default().fg(palette.text_dim),
+                ),
+            ]),
+        };
+        push_line(lines, line);
+    }
+    push_more_hint(lines, palette, changes.len(), limit);
+}
+
 fn build_open_files_section(
     lines: &mut Vec<Line>,
     palette: &Palette,
     expanded: bool,
+    limit: usize,
     details: &ProcessDetails,
 ) {
     push_blank_line(lines);
     let label = label_style(palette);
+    let summary = if details.open_files.is_empty() {
+        None
+    } else {
+        Some(FdSummary::from_entries(&details.open_files).describe())
+    };
     if expanded {
         push_line(
             lines,
             Line::from(Span::styled(
-                "Open File Descriptors (press f to collapse):",
+                format!(
+                    "Open File Descriptors{} (press f to collapse):",
+                    count_suffix(details.open_files.len(), limit)
+                ),
                 label.add_modifier(Modifier::BOLD),
             )),
         );
+        if let Some(summary) = summary {
+            push_line(
+                lines,
+                Line::from(Span::styled(format!("  {}", summary), value_style(palette))),
+            );
+        }
         if details.open_files.is_empty() {
             push_line(lines, Line::from("  <unavailable>"));
         } else {
-            for file in &details.open_files {
+            for file in details.open_files.iter().take(limit) {
                 push_line(lines, Line::from(format!("  {}", file)));
             }
+            push_more_hint(lines, palette, details.open_files.len(), limit);
         }
     } else {
         push_line(
             lines,
-            Line::from(Span::styled("Open Files: (press f to expand)", label)),
+            Line::from(Span::styled(
+                match summary {
+                    Some(summary) => format!("Open Files: {} (press f to expand)", summary),
+                    None => "Open Files: (press f to expand)".to_string(),
+                },
+                label,
+            )),
         );
     }
 }
@@ -265,6 +618,7 @@ fn build_memory_map_section(
     lines: &mut Vec<Line>,
     palette: &Palette,
     expanded: bool,
+    limit: usize,
     details: &ProcessDetails,
 ) {
     push_blank_line(lines);
@@ -273,16 +627,20 @@ fn build_memory_map_section(
         push_line(
             lines,
             Line::from(Span::styled(
-                "Memory Map Segments (press m to collapse):",
+                format!(
+                    "Memory Map Segments{} (press m to collapse):",
+                    count_suffix(details.memory_maps.len(), limit)
+                ),
                 label.add_modifier(Modifier::BOLD),
             )),
         );
         if details.memory_maps.is_empty() {
             push_line(lines, Line::from("  <unavailable>"));
         } else {
-            for entry in &details.memory_maps {
+            for entry in details.memory_maps.iter().take(limit) {
                 push_line(lines, Line::from(format!("  {}", entry)));
             }
+            push_more_hint(lines, palette, details.memory_maps.len(), limit);
         }
     } else {
         push_line(
@@ -366,12 +724,68 @@ fn build_cgroup_section(
     }
 }
 
+fn format_sched_time(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+fn count_suffix(total: usize, limit: usize) -> String {
+    if total > limit {
+        format!(" [{} of {}]", limit, total)
+    } else {
+        format!(" [{}]", total)
+    }
+}
+
+fn push_more_hint(lines: &mut Vec<Line>, palette: &Palette, total: usize, limit: usize) {
+    if total > limit {
+        push_line(
+            lines,
+            Line::from(Span::styled(
+                format!("  … {} more (press + to load more)", total - limit),
+                Style::default().fg(palette.text_dim),
+            )),
+        );
+    }
+}
+
+/// the scroll offset of each line once everything above it has wrapped, one entry per line
+/// plus a trailing total.
+fn wrapped_offsets(lines: &[Line], width: u16) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(lines.len() + 1);
+    let mut total = 0;
+    offsets.push(total);
+    for line in lines {
+        total += Paragraph::new(line.clone())
+            .wrap(Wrap { trim: false })
+            .line_count(width.max(1));
+        offsets.push(total);
+    }
+    offsets
+}
+
+/// the scroll offset of `lines[start]`; sections open with a blank spacer line, which is
+/// skipped so the heading lands on top.
+fn wrapped_offset(lines: &[Line], offsets: &[usize], start: usize) -> u16 {
+    let start = match lines.get(start) {
+        Some(line) if line.width() == 0 => start + 1,
+        _ => start,
+    };
+    let above = offsets[start.min(lines.len())];
+    above.min(u16::MAX as usize) as u16
+}
+
 fn push_line<'a>(lines: &mut Vec<Line<'a>>, line: Line<'a>) {
     lines.push(line);
 }
 
 fn push_blank_line(lines: &mut Vec<Line>) {
-    if lines.last().map_or(false, |line| line.spans.is_empty()) {
+    if lines.last().is_some_and(|line| line.spans.is_empty()) {
         return;
     }
     lines.push(Line::default());
@@ -386,3 +800,39 @@ fn label_style(palette: &Palette) -> Style {
 fn value_style(palette: &Palette) -> Style {
     Style::default().fg(palette.text_normal)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::command_lines;
+    use crate::config::Theme;
+
+    fn text(lines: &[ratatui::text::Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn command_lines_put_each_argument_on_its_own_line() {
+        let palette = Theme::Serious.palette();
+        let cmdline = ["/usr/bin/postgres", "-D", "/var/lib/pg"].map(String::from);
+
+        let split = command_lines(&palette, &cmdline, false, "var");
+        assert_eq!(
+            text(&split),
+            ["  /usr/bin/postgres", "    -D", "    /var/lib/pg"]
+        );
+        assert_eq!(split[2].spans[1].style.fg, Some(palette.kill_accent));
+        assert_ne!(split[1].spans[1].style.fg, Some(palette.kill_accent));
+
+        let joined = command_lines(&palette, &cmdline, true, "");
+        assert_eq!(text(&joined), ["  /usr/bin/postgres -D /var/lib/pg"]);
+        assert_eq!(text(&command_lines(&palette, &[], false, "")), ["  <none>"]);
+    }
+}
@@ -1,6 +1,7 @@
 use ratatui::Frame;
+use ratatui::layout::Rect;
 
-use crate::app::App;
+use pkillr::App;
 
 pub mod aux_views;
 pub mod info_pane;
@@ -12,3 +13,75 @@ pub fn render(frame: &mut Frame<'_>, app: &mut App) {
     let area = frame.size();
     table::render(frame, area, app);
 }
+
+/// cells left free on every side of a popup when the frame is tight.
+const POPUP_MARGIN: u16 = 1;
+
+/// centres a `width` x `height` popup in `area`, shrinking it to fit with a margin.
+pub(crate) fn popup_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let max_width = area
+        .width
+        .saturating_sub(2 * POPUP_MARGIN)
+        .max(area.width.min(1));
+    let max_height = area
+        .height
+        .saturating_sub(2 * POPUP_MARGIN)
+        .max(area.height.min(1));
+    let width = width.min(max_width);
+    let height = height.min(max_height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// the smallest change to `offset` that keeps row `selected` of `len` inside a list
+/// `visible` rows tall.
+pub(crate) fn scroll_to_show(offset: usize, selected: usize, len: usize, visible: usize) -> usize {
+    if visible == 0 {
+        return 0;
+    }
+    let offset = if selected >= offset + visible {
+        selected + 1 - visible
+    } else if selected < offset {
+        selected
+    } else {
+        offset
+    };
+    offset.min(len.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use super::{popup_rect, scroll_to_show};
+
+    #[test]
+    fn popup_rect_keeps_a_margin_inside_the_frame() {
+        let area = Rect::new(0, 0, 40, 10);
+        let popup = popup_rect(area, 200, 200);
+        assert_eq!(popup, Rect::new(1, 1, 38, 8));
+        assert_eq!(popup_rect(area, 10, 4), Rect::new(15, 3, 10, 4));
+    }
+
+    #[test]
+    fn popup_rect_never_exceeds_tiny_frames() {
+        for (width, height) in [(0, 0), (1, 1), (2, 2), (3, 1)] {
+            let area = Rect::new(5, 5, width, height);
+            let popup = popup_rect(area, 50, 50);
+            assert!(area.union(popup) == area || popup.area() == 0, "{popup:?}");
+        }
+    }
+
+    #[test]
+    fn scroll_to_show_moves_only_as_far_as_needed() {
+        assert_eq!(scroll_to_show(0, 3, 20, 5), 0);
+        assert_eq!(scroll_to_show(0, 7, 20, 5), 3);
+        assert_eq!(scroll_to_show(3, 8, 20, 5), 4);
+        assert_eq!(scroll_to_show(10, 2, 20, 5), 2);
+        assert_eq!(scroll_to_show(10, 0, 20, 0), 0);
+    }
+}
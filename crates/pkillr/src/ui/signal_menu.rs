@@ -6,15 +6,12 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 
-use crate::app::App;
 use crate::signals::Signal;
+use crate::ui::{popup_rect, scroll_to_show};
+use pkillr::App;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
-    let signals = Signal::all();
-    if signals.is_empty() {
-        return;
-    }
-
+    let signals = app.signal_menu_signals();
     let palette = app.theme().palette();
 
     let dim = Block::default().style(Style::default().bg(Color::Rgb(30, 30, 30)));
@@ -22,31 +19,18 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
 
     let popup_width = (area.width as f32 * 0.5).max(30.0) as u16;
     let popup_height = (area.height as f32 * 0.7).max(10.0) as u16;
-    let popup_x = area.x + (area.width.saturating_sub(popup_width)) / 2;
-    let popup_y = area.y + (area.height.saturating_sub(popup_height)) / 2;
-    let popup = Rect {
-        x: popup_x,
-        y: popup_y,
-        width: popup_width.min(area.width),
-        height: popup_height.min(area.height),
-    };
+    let popup = popup_rect(area, popup_width, popup_height);
 
-    let inner_height = popup.height.saturating_sub(4) as usize; // borders + title + hint
-    let mut offset = app.signal_menu_scroll_offset();
+    let inner_height = popup.height.saturating_sub(5) as usize; // borders + title + prediction + hint
     let selected = app
         .signal_menu_selected()
         .min(signals.len().saturating_sub(1));
-
-    if inner_height > 0 {
-        if selected >= offset + inner_height {
-            offset = selected + 1 - inner_height;
-        } else if selected < offset {
-            offset = selected;
-        }
-    } else {
-        offset = 0;
-    }
-    offset = offset.min(signals.len().saturating_sub(1));
+    let offset = scroll_to_show(
+        app.signal_menu_scroll_offset(),
+        selected,
+        signals.len(),
+        inner_height,
+    );
     app.set_signal_menu_scroll_offset(offset);
 
     let end = min(offset.saturating_add(inner_height), signals.len());
@@ -56,7 +40,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
         &signals[offset..end]
     };
 
-    let items: Vec<ListItem> = displayed
+    let mut items: Vec<ListItem> = displayed
         .iter()
         .map(|signal| {
             let number = format!("{:>2}", signal.number());
@@ -98,6 +82,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
             ListItem::new(line)
         })
         .collect();
+    if signals.is_empty() {
+        items.push(ListItem::new(Line::from(Span::styled(
+            "no matching signals",
+            Style::default().fg(palette.text_dim),
+        ))));
+    }
 
     let highlight = Style::default()
         .bg(palette.highlight_selected)
@@ -129,20 +119,45 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let inner = popup.inner(&margin);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
         .split(inner);
 
     let mut state = ListState::default();
-    if selected < signals.len() {
+    if !signals.is_empty() && selected < signals.len() {
         state.select(Some(selected - offset));
     }
     frame.render_stateful_widget(list, chunks[0], &mut state);
 
-    let hints =
-        Paragraph::new("↑↓/jk navigate | Enter send | 1-9 select | Esc cancel | ⚠ dangerous")
-            .style(Style::default().fg(palette.text_dim))
-            .wrap(Wrap { trim: true });
-    frame.render_widget(hints, chunks[1]);
+    if let Some(prediction) = app.signal_menu_prediction() {
+        let style = if signals.get(selected).copied().is_some_and(is_dangerous)
+            || app.signal_menu_tracer().is_some()
+        {
+            Style::default().fg(palette.status_warning)
+        } else {
+            Style::default().fg(palette.text_normal)
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(format!("→ {prediction}"), style))),
+            chunks[1],
+        );
+    }
+
+    let hint_text = if let Some(entry) = app.signal_value_entry() {
+        format!("sigqueue value: {}_ | Enter send | Esc back", entry)
+    } else if app.advanced_signals() {
+        "↑↓/jk navigate | Enter send | v send with value | o order | Esc cancel | ⚠ dangerous"
+            .to_string()
+    } else {
+        "↑↓/jk navigate | Enter send | 1-31 select | o order | Esc cancel | ⚠ dangerous".to_string()
+    };
+    let hints = Paragraph::new(hint_text)
+        .style(Style::default().fg(palette.text_dim))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(hints, chunks[2]);
 }
 
 fn is_dangerous(signal: Signal) -> bool {
@@ -1,5 +1,6 @@
 use std::cmp::{max, min};
 use std::collections::HashSet;
+use std::time::Instant;
 
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
@@ -8,9 +9,10 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 
-use crate::app::{App, AppMode, StatusLevel};
-use crate::process::{self, ProcessInfo};
+use crate::config::{Column, EnterAction, SelectionStyle, percent_width};
+use crate::process::{self, ProcessInfo, ProcessState};
 use crate::ui::{aux_views, info_pane, signal_menu, tree_view};
+use pkillr::{App, AppMode, SortColumn, StatusLevel, TableGeometry, process_count};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let layout = Layout::default()
@@ -23,7 +25,14 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
         .split(area);
 
     render_header(frame, layout[0], app);
-    if app.tree_view_open() {
+    if app.tree_view_open() && app.is_info_pane_open() {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(layout[1]);
+        tree_view::render(frame, chunks[0], app);
+        info_pane::render(frame, chunks[1], app);
+    } else if app.tree_view_open() {
         tree_view::render(frame, layout[1], app);
     } else {
         render_table(frame, layout[1], app);
@@ -36,6 +45,15 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     if app.history_popup_open() {
         aux_views::render_signal_history(frame, area, app);
     }
+    if app.views_popup_open() {
+        aux_views::render_views_popup(frame, area, app);
+    }
+    if app.users_popup_open() {
+        aux_views::render_users_popup(frame, area, app);
+    }
+    if app.batch_kill_prompt().is_some() {
+        aux_views::render_batch_kill_prompt(frame, area, app);
+    }
     if app.help_popup_open() {
         aux_views::render_help_popup(frame, area, app);
     }
@@ -48,18 +66,60 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         mode_label(app.mode())
     };
-    let mut spans = vec![
-        Span::styled("pkillr", Style::default().fg(palette.table_header)),
+    let mut spans = vec![Span::styled(
+        "pkillr",
+        Style::default().fg(palette.table_header),
+    )];
+    if app.is_root() {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            "[ROOT]",
+            Style::default()
+                .fg(palette.status_error)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans.extend([
         Span::raw(" | "),
         Span::styled(mode_text, Style::default().fg(palette.text_normal)),
         Span::raw(" | "),
         Span::styled(
-            format!("{} processes", app.filtered_processes().len()),
+            process_count(app.filtered_processes().len()),
             Style::default().fg(palette.text_dim),
         ),
-    ];
+    ]);
 
-    if !app.search_query().is_empty() {
+    let layers: Vec<&str> = app.filter_layers().collect();
+    if !layers.is_empty() {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            layers.join(" › "),
+            Style::default().fg(palette.kill_accent),
+        ));
+        spans.push(Span::styled(" ›", Style::default().fg(palette.text_dim)));
+    }
+
+    if matches!(app.mode(), AppMode::Search) {
+        let count = app.filtered_processes().len();
+        spans.push(Span::raw(" | search: "));
+        spans.push(Span::styled(
+            app.search_query(),
+            Style::default().fg(palette.kill_accent),
+        ));
+        let count_color = if count == 0 {
+            palette.status_error
+        } else {
+            palette.text_dim
+        };
+        spans.push(Span::styled(
+            format!(
+                " ({} {})",
+                count,
+                if count == 1 { "match" } else { "matches" }
+            ),
+            Style::default().fg(count_color),
+        ));
+    } else if !app.search_query().is_empty() {
         spans.push(Span::raw(" | filter: "));
         spans.push(Span::styled(
             app.search_query(),
@@ -67,10 +127,81 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
         ));
     }
 
+    if app.show_refresh_age() {
+        let age = app.refresh_age(Instant::now()).as_secs();
+        let text = if app.is_paused() {
+            format!("#{} paused, {}s ago", app.refresh_generation(), age)
+        } else {
+            format!("#{} {}s ago", app.refresh_generation(), age)
+        };
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(text, Style::default().fg(palette.text_dim)));
+    }
+
+    if app.safe_mode() {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            "SAFE",
+            Style::default()
+                .fg(palette.status_info)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if app.hide_idle() {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!("cpu ≥ {:.1}%", app.idle_cpu_threshold()),
+            Style::default().fg(palette.text_dim),
+        ));
+    }
+
+    let watches = app.respawn_watch_count();
+    if watches > 0 {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!("re-killing respawns: {}", watches),
+            Style::default()
+                .fg(palette.status_warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if let Some(pressure) = app.pressure() {
+        spans.push(Span::raw(" | psi"));
+        for (label, value) in [
+            ("cpu", pressure.cpu),
+            ("mem", pressure.memory),
+            ("io", pressure.io),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+            let color = if value >= 25.0 {
+                palette.status_error
+            } else if value >= 10.0 {
+                palette.status_warning
+            } else {
+                palette.text_dim
+            };
+            spans.push(Span::styled(
+                format!(" {} {:.1}%", label, value),
+                Style::default().fg(color),
+            ));
+        }
+    }
+
     let paragraph = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
     frame.render_widget(paragraph, area);
 }
 
+const MAX_NAME_WIDTH: usize = 40;
+const MAX_USER_WIDTH: usize = 16;
+/// name characters kept before row markers are allowed to be cut off too.
+const MIN_NAME_CHARS: usize = 4;
+/// the column holding the ▶ marker under `SelectionStyle::Gutter`.
+const GUTTER_WIDTH: u16 = 1;
+
 fn render_table(frame: &mut Frame, area: Rect, app: &mut App) {
     let mut table_area = area;
     let mut info_area = None;
@@ -111,6 +242,35 @@ fn render_process_list(frame: &mut Frame, area: Rect, app: &mut App) {
         offset = 0;
     }
     app.set_table_scroll_offset(offset);
+    app.set_table_visible_height(visible_height);
+    if app.widths_need_measuring() {
+        let widths = measure_column_widths(app.columns(), app.filtered_processes());
+        app.lock_column_widths(widths);
+    }
+    let column_widths = app.column_widths();
+    // the gutter marker gets a column of its own, ahead of whatever comes first.
+    let gutter = app.selection_style() == SelectionStyle::Gutter;
+    let mut x = area.x + 1 + if gutter { GUTTER_WIDTH + 1 } else { 0 };
+    let columns = app
+        .columns()
+        .iter()
+        .zip(&column_widths)
+        .map(|(column, width)| {
+            let start = x;
+            x = x.saturating_add(width + 1);
+            (
+                start,
+                start.saturating_add(*width),
+                sort_column_for(*column),
+            )
+        })
+        .collect();
+    app.set_table_geometry(Some(TableGeometry {
+        header_y: area.y + 1,
+        rows_y: area.y + 2,
+        rows_height: visible_height as u16,
+        columns,
+    }));
 
     let processes = app.filtered_processes();
     if row_count == 0 {
@@ -127,7 +287,7 @@ fn render_process_list(frame: &mut Frame, area: Rect, app: &mut App) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(palette.table_border)),
+                .border_style(Style::default().fg(table_border_color(app))),
         );
         frame.render_widget(paragraph, area);
         return;
@@ -140,31 +300,49 @@ fn render_process_list(frame: &mut Frame, area: Rect, app: &mut App) {
         &processes[offset..end]
     };
 
-    let header_cells = ["PID", "Name", "CPU%", "MEM%", "User", "Runtime"]
-        .into_iter()
-        .map(|title| Cell::from(title).style(Style::default().fg(palette.table_header)));
+    let sort_arrow = if app.sort_descending() { "▼" } else { "▲" };
+    let mut header_cells: Vec<Cell> = app
+        .columns()
+        .iter()
+        .map(|column| {
+            let title = if sort_column_for(*column) == app.sort_column() {
+                format!("{}{}", column.title(), sort_arrow)
+            } else {
+                column.title().to_string()
+            };
+            Cell::from(title).style(Style::default().fg(palette.table_header))
+        })
+        .collect();
+    if gutter {
+        header_cells.insert(0, Cell::from(""));
+    }
+    if app.debug_scores() {
+        header_cells.push(Cell::from("Score").style(Style::default().fg(palette.text_dim)));
+    }
 
     let header = Row::new(header_cells).height(1);
 
     let rows = displayed.iter().enumerate().map(|(idx, proc)| {
         let absolute_index = idx + offset;
-        build_row(app, proc, absolute_index == selected_index)
+        build_row(app, proc, absolute_index == selected_index, &column_widths)
     });
 
-    let widths = [
-        Constraint::Length(8),
-        Constraint::Length(20),
-        Constraint::Length(6),
-        Constraint::Length(6),
-        Constraint::Length(12),
-        Constraint::Length(10),
-    ];
+    let mut widths: Vec<Constraint> = column_widths
+        .iter()
+        .map(|width| Constraint::Length(*width))
+        .collect();
+    if gutter {
+        widths.insert(0, Constraint::Length(GUTTER_WIDTH));
+    }
+    if app.debug_scores() {
+        widths.push(Constraint::Length(9));
+    }
 
     let table = Table::new(rows, widths)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(palette.table_border)),
+                .border_style(Style::default().fg(table_border_color(app))),
         )
         .header(header)
         .column_spacing(1);
@@ -183,6 +361,16 @@ fn render_process_list(frame: &mut Frame, area: Rect, app: &mut App) {
     }
 }
 
+/// as root nothing is dimmed as unkillable, so the table frame itself carries the warning.
+fn table_border_color(app: &App) -> Color {
+    let palette = app.theme().palette();
+    if app.is_root() {
+        palette.status_error
+    } else {
+        palette.table_border
+    }
+}
+
 fn render_status(frame: &mut Frame, area: Rect, app: &App) {
     let palette = app.theme().palette();
     let mut lines = vec![Line::from(""), Line::from("")];
@@ -194,6 +382,22 @@ fn render_status(frame: &mut Frame, area: Rect, app: &App) {
             StatusLevel::Error => palette.status_error,
         };
         lines[0] = Line::from(Span::styled(message.clone(), Style::default().fg(color)));
+    } else if let Some(hint) = app.hidden_processes_hint() {
+        lines[0] = Line::from(Span::styled(hint, Style::default().fg(palette.status_info)));
+    }
+
+    if let Some((cpu, memory)) = app.filtered_totals() {
+        let totals = format!(
+            "filtered total: {:.1}% CPU, {}",
+            cpu,
+            tree_view::format_bytes(memory)
+        );
+        if lines[0].width() > 0 {
+            lines[0].spans.push(Span::raw(" | "));
+        }
+        lines[0]
+            .spans
+            .push(Span::styled(totals, Style::default().fg(palette.text_dim)));
     }
 
     lines[1] = Line::from(Span::styled(
@@ -209,9 +413,43 @@ fn render_status(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
-fn build_row(app: &App, proc: &ProcessInfo, is_selected: bool) -> Row<'static> {
+/// widths that fit every row's name, user and runtime (within limits); numeric
+/// columns keep their fixed widths.
+fn measure_column_widths(columns: &[Column], processes: &[ProcessInfo]) -> Vec<u16> {
+    let longest = |text: fn(&ProcessInfo) -> String| {
+        processes
+            .iter()
+            .map(|proc| text(proc).chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    columns
+        .iter()
+        .map(|column| {
+            let (content, limit) = match column {
+                Column::Name => (longest(|proc| proc.name.clone()), MAX_NAME_WIDTH),
+                Column::User => (longest(|proc| proc.user.clone()), MAX_USER_WIDTH),
+                Column::Runtime => (longest(|proc| format_runtime(proc.runtime)), 12),
+                Column::Pid | Column::Cpu | Column::Mem | Column::Time | Column::Faults => {
+                    return column.width();
+                }
+            };
+            let title = column.title().chars().count() + 1;
+            content.max(title).min(limit) as u16
+        })
+        .collect()
+}
+
+fn build_row(app: &App, proc: &ProcessInfo, is_selected: bool, widths: &[u16]) -> Row<'static> {
+    let width_of = |wanted: Column| {
+        app.columns()
+            .iter()
+            .position(|column| *column == wanted)
+            .and_then(|idx| widths.get(idx))
+            .map_or(wanted.width(), |width| *width) as usize
+    };
     let palette = app.theme().palette();
-    let mut style = app.theme().style_for_process(proc);
+    let mut style = app.process_style(proc);
     let needs_sudo = !app.can_kill_without_privileges(proc);
 
     if needs_sudo {
@@ -221,30 +459,62 @@ fn build_row(app: &App, proc: &ProcessInfo, is_selected: bool) -> Row<'static> {
             .add_modifier(Modifier::ITALIC);
     }
 
+    let selection_style = app.selection_style();
+    let disk_sleep = proc.state == ProcessState::DiskSleep;
+    if disk_sleep {
+        style = style.fg(palette.status_warning);
+    }
+
     if is_selected {
-        style = style.bg(palette.highlight_selected);
+        style = match selection_style {
+            SelectionStyle::Background => style.bg(palette.highlight_selected),
+            SelectionStyle::Gutter => style.add_modifier(Modifier::BOLD),
+            SelectionStyle::Reverse => style.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        };
     }
 
     let pid = format!("{:>8}", proc.pid);
-    let highlight_bytes = app.highlight_indices(proc.pid).unwrap_or(&[]);
-    let highlight_chars = highlight_char_positions(&proc.name, highlight_bytes);
+    let name = app.display_name(proc);
+    // match positions index the comm name, so they only apply when it is shown.
+    let highlight_bytes = if name == proc.name {
+        app.highlight_indices(proc.pid).unwrap_or(&[])
+    } else {
+        &[]
+    };
+    let highlight_chars = highlight_char_positions(name, highlight_bytes);
 
     let mut sequence: Vec<(char, bool)> = Vec::new();
     if app.is_pid_selected(proc.pid) {
         sequence.push(('✓', false));
         sequence.push((' ', false));
     }
-    for (idx, ch) in proc.name.chars().enumerate() {
+    if proc.thread_owner.is_some() {
+        sequence.push(('↳', false));
+        sequence.push((' ', false));
+    }
+    for (idx, ch) in name.chars().enumerate() {
         let highlight = highlight_chars.contains(&idx);
         sequence.push((ch, highlight));
     }
+    let mut markers = String::new();
     if needs_sudo || process::is_system_process(proc) {
-        for ch in " [needs sudo]".chars() {
-            sequence.push((ch, false));
-        }
+        markers.push_str(" [needs sudo]");
+    }
+    if disk_sleep {
+        markers.push_str(" [D: stuck in io]");
+    }
+    if proc.is_foreground() {
+        markers.push_str(" [fg]");
+    }
+    if app.has_network_sockets(proc.pid) {
+        markers.push_str(" ⇅");
+    }
+    let heavily_swapped = app.is_heavily_swapped(proc);
+    if let Some(swap) = proc.swap_bytes.filter(|_| heavily_swapped) {
+        markers.push_str(&format!(" [swap {}]", tree_view::format_bytes(swap)));
     }
 
-    let truncated_seq = truncate_sequence(&sequence, 20);
+    let truncated_seq = fit_name_and_markers(&sequence, &markers, width_of(Column::Name));
     let name_spans = sequence_to_spans(
         truncated_seq,
         Style::default().fg(palette.text_normal),
@@ -254,24 +524,76 @@ fn build_row(app: &App, proc: &ProcessInfo, is_selected: bool) -> Row<'static> {
     );
     let name_cell = Cell::from(Line::from(name_spans));
 
-    let cpu = format!("{:>5.1}%", proc.cpu_percent);
-    let mem = format!("{:>5.1}%", memory_percent(proc, app.total_memory_bytes()));
-    let user = truncated(&proc.user, 12);
+    let percent = |value: f32, precision: usize| {
+        let width = percent_width(precision) as usize - 1;
+        format!("{value:>width$.precision$}%")
+    };
+    let cpu = percent(proc.cpu_percent, app.cpu_precision());
+    let mem = percent(
+        memory_percent(proc, app.total_memory_bytes()),
+        app.mem_precision(),
+    );
+    let user = truncated(&proc.user, width_of(Column::User));
     let runtime = format_runtime(proc.runtime);
+    let cpu_time = format!("{:>9}", format_cpu_time(proc.cpu_time));
 
     let cpu_style = Style::default().fg(app.theme().get_cpu_color(proc.cpu_percent));
-    let mem_style = Style::default().fg(app.theme().get_memory_color(proc.memory_bytes));
-
-    Row::new(vec![
-        Cell::from(pid),
-        name_cell,
-        Cell::from(cpu).style(cpu_style),
-        Cell::from(mem).style(mem_style),
-        Cell::from(user),
-        Cell::from(runtime),
-    ])
-    .style(style)
-    .height(1)
+    let mem_style = if heavily_swapped {
+        Style::default()
+            .fg(palette.status_warning)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(app.memory_color(proc.memory_bytes))
+    };
+
+    let mut cells: Vec<Cell> = app
+        .columns()
+        .iter()
+        .map(|column| match column {
+            Column::Pid => Cell::from(pid.clone()),
+            Column::Name => name_cell.clone(),
+            Column::Cpu => Cell::from(cpu.clone()).style(cpu_style),
+            Column::Mem => Cell::from(mem.clone()).style(mem_style),
+            Column::User => Cell::from(user.clone()),
+            Column::Runtime => match app.runtime_color(proc.runtime) {
+                Some(color) => Cell::from(runtime.clone()).style(Style::default().fg(color)),
+                None => Cell::from(runtime.clone()),
+            },
+            Column::Time => Cell::from(cpu_time.clone()),
+            Column::Faults => Cell::from(format!("{:>8}", proc.major_faults)),
+        })
+        .collect();
+    if selection_style == SelectionStyle::Gutter {
+        cells.insert(0, Cell::from(if is_selected { "▶" } else { " " }));
+    }
+    if app.debug_scores() {
+        let score = app
+            .search_score(proc.pid)
+            .map(|score| score.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        cells.push(
+            Cell::from(score).style(
+                Style::default()
+                    .fg(palette.text_dim)
+                    .add_modifier(Modifier::DIM),
+            ),
+        );
+    }
+
+    Row::new(cells).style(style).height(1)
+}
+
+fn sort_column_for(column: Column) -> SortColumn {
+    match column {
+        Column::Pid => SortColumn::Pid,
+        Column::Name => SortColumn::Name,
+        Column::Cpu => SortColumn::Cpu,
+        Column::Mem => SortColumn::Memory,
+        Column::User => SortColumn::User,
+        Column::Runtime => SortColumn::Runtime,
+        Column::Time => SortColumn::CpuTime,
+        Column::Faults => SortColumn::MajorFaults,
+    }
 }
 
 fn render_scrollbar(
@@ -341,9 +663,20 @@ fn hints_for_mode(app: &App) -> String {
                 "t tree"
             });
 
+            match app.enter_action() {
+                EnterAction::Kill => {}
+                EnterAction::SignalMenu => parts.push("Enter signal menu"),
+                EnterAction::Info => parts.push("Enter info"),
+            }
             if app.has_selection() {
                 parts.push("Space toggle");
-                parts.push("Enter/k kill");
+                parts.push("* invert");
+                parts.push("u clear");
+                parts.push(if app.enter_action() == EnterAction::Kill {
+                    "Enter/k kill"
+                } else {
+                    "k kill"
+                });
                 parts.push("K sigkill");
                 parts.push("x tree kill");
             } else {
@@ -352,20 +685,32 @@ fn hints_for_mode(app: &App) -> String {
                 parts.push("s signal menu");
             }
 
+            parts.push(if app.show_threads() {
+                "H hide threads"
+            } else {
+                "H threads"
+            });
             parts.push("h history");
             parts.push("? help");
             parts.push("q quit");
             parts.join(" | ")
         }
-        AppMode::Search => {
-            "Type to filter | /pattern/ regex | /killed history | Enter/Esc exit".to_string()
-        }
-        AppMode::SignalMenu => "Esc cancel | ↑↓/jk navigate | 1-9 select | Enter send".to_string(),
+        AppMode::Search => format!(
+            "Type to filter ({}, {}) | Ctrl+F fuzzy/substring | Ctrl+E case | /pattern/ regex | /killed history | Tab layer | Enter/Esc exit",
+            app.match_style().label(),
+            if app.case_sensitive() {
+                "case-sensitive"
+            } else {
+                "ignore case"
+            }
+        ),
+        AppMode::SignalMenu => "Esc cancel | ↑↓/jk navigate | 1-31 select | Enter send".to_string(),
         AppMode::InfoPane => {
             "Esc close info | Tab toggle focus | e/f/n/c expand sections".to_string()
         }
         AppMode::TreeView => {
-            "Esc close tree | ↑↓/jk move | Space collapse | x kill tree".to_string()
+            "Esc close tree | ↑↓/jk move | Space collapse | p pgid/sid | o own/total | [ ] depth | l load only | T anchor | Z zoom out | zz center | x kill tree"
+                .to_string()
         }
         AppMode::HistoryView => "Any key close history".to_string(),
     }
@@ -427,6 +772,20 @@ fn truncate_sequence(seq: &[(char, bool)], max_len: usize) -> Vec<(char, bool)>
     truncated
 }
 
+/// the name cut short before its markers are, so a long name can't push
+/// `[D: stuck in io]` or `⇅` out of the column. markers only give way when
+/// keeping them would leave fewer than `MIN_NAME_CHARS` of the name.
+fn fit_name_and_markers(name: &[(char, bool)], markers: &str, width: usize) -> Vec<(char, bool)> {
+    let markers: Vec<(char, bool)> = markers.chars().map(|ch| (ch, false)).collect();
+    let room = width.saturating_sub(markers.len());
+    if name.len() + markers.len() <= width || room < MIN_NAME_CHARS.min(name.len()) {
+        return truncate_sequence(&[name, markers.as_slice()].concat(), width);
+    }
+    let mut fitted = truncate_sequence(name, room);
+    fitted.extend(markers);
+    fitted
+}
+
 fn sequence_to_spans(
     seq: Vec<(char, bool)>,
     base_style: Style,
@@ -473,6 +832,12 @@ fn memory_percent(proc: &ProcessInfo, total_memory_bytes: u64) -> f32 {
     (ratio * 100.0) as f32
 }
 
+/// `HH:MM:SS`, with hours growing past 99 rather than wrapping into days.
+fn format_cpu_time(duration: std::time::Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
 fn format_runtime(duration: std::time::Duration) -> String {
     let secs = duration.as_secs();
     let minutes = secs / 60;
@@ -486,3 +851,66 @@ fn format_runtime(duration: std::time::Duration) -> String {
         format!("{}m {}s", minutes, secs % 60)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{fit_name_and_markers, format_cpu_time, measure_column_widths};
+    use crate::config::Column;
+    use crate::process::{ProcessInfo, ProcessState};
+
+    fn process(name: &str, user: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid: 7,
+            name: name.to_string(),
+            user: user.to_string(),
+            runtime: Duration::from_secs(90),
+            state: ProcessState::Sleeping,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn measured_widths_fit_the_rows_within_limits() {
+        let processes = [
+            process("sh", "root"),
+            process("a-very-long-daemon-name-that-keeps-going-on", "postgres"),
+        ];
+        let widths = measure_column_widths(&Column::DEFAULT_ORDER, &processes);
+        assert_eq!(widths, [8, 40, 6, 6, 8, 8]);
+        assert_eq!(measure_column_widths(&[Column::Name], &processes[..1]), [5]);
+    }
+
+    #[test]
+    fn long_names_are_cut_before_their_markers() {
+        let fit = |name: &str, markers: &str, width: usize| {
+            let name: Vec<(char, bool)> = name.chars().map(|ch| (ch, false)).collect();
+            fit_name_and_markers(&name, markers, width)
+                .into_iter()
+                .map(|(ch, _)| ch)
+                .collect::<String>()
+        };
+        let name = "a-very-long-daemon-name-that-keeps-going-on";
+        assert_eq!(
+            fit(name, " [D: stuck in io]", 24),
+            "a-very… [D: stuck in io]"
+        );
+        assert_eq!(fit(name, " [fg] ⇅", 24), "a-very-long-daem… [fg] ⇅");
+        assert_eq!(fit("sh", " [fg]", 24), "sh [fg]");
+        assert_eq!(fit(name, " [D: stuck in io]", 12), "a-very-long…");
+    }
+
+    #[test]
+    fn cpu_time_renders_as_hours_minutes_seconds() {
+        assert_eq!(format_cpu_time(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_cpu_time(Duration::from_secs(3 * 3600 + 125)),
+            "03:02:05"
+        );
+        assert_eq!(
+            format_cpu_time(Duration::from_secs(150 * 3600)),
+            "150:00:00"
+        );
+    }
+}
@@ -6,7 +6,11 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-use crate::app::{App, RiskLevel, TreeKillPrompt, TreeRow};
+use crate::config::SelectionStyle;
+use crate::risk::RiskLevel;
+use crate::signals::Signal;
+use crate::ui::popup_rect;
+use pkillr::{App, TreeKillPrompt, TreeRow, process_count};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let palette = app.theme().palette();
@@ -26,6 +30,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
         offset = 0;
     }
     app.set_tree_scroll_offset(offset);
+    app.set_tree_visible_height(visible_height);
 
     let rows = app.tree_rows();
     let end = min(offset.saturating_add(visible_height), rows.len());
@@ -39,7 +44,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(palette.table_border))
         .title(Line::from(vec![Span::styled(
-            " Process Tree ",
+            tree_title(app),
             Style::default()
                 .fg(palette.table_header)
                 .add_modifier(Modifier::BOLD),
@@ -100,15 +105,43 @@ fn subtree_range_end(rows: &[TreeRow], selected_index: usize) -> usize {
     idx
 }
 
+fn tree_title(app: &App) -> String {
+    let metrics = if app.tree_show_own_metrics() {
+        "own cpu/mem"
+    } else {
+        "subtree totals"
+    };
+    let root = match app.tree_root() {
+        Some(pid) => format!(" under PID {}", pid),
+        None => String::new(),
+    };
+    if app.tree_hide_idle() {
+        format!(
+            " Process Tree{} ({}, {} idle hidden) ",
+            root,
+            metrics,
+            app.tree_hidden_count()
+        )
+    } else {
+        format!(" Process Tree{} ({}) ", root, metrics)
+    }
+}
+
 fn build_tree_line(app: &App, row: &TreeRow, is_selected: bool, in_subtree: bool) -> Line<'static> {
     let palette = app.theme().palette();
     let mut name = format!("{}{}", row.prefix, row.name);
-    if row.has_children {
+    if row.hidden_descendants > 0 && !row.collapsed {
+        name.push_str(&format!(" [+{} deeper]", row.hidden_descendants));
+    } else if row.has_children {
         name.push(' ');
         name.push_str(if row.collapsed { "[+]" } else { "[-]" });
     }
 
+    let selection_style = app.selection_style();
     let mut spans = Vec::new();
+    if selection_style == SelectionStyle::Gutter {
+        spans.push(Span::raw(if is_selected { "▶ " } else { "  " }));
+    }
     spans.push(Span::styled(name, Style::default().fg(palette.text_normal)));
     spans.push(Span::raw(" "));
     spans.push(Span::styled(
@@ -123,13 +156,34 @@ fn build_tree_line(app: &App, row: &TreeRow, is_selected: bool, in_subtree: bool
         ));
     }
 
+    if let Some(ids) = row.job_ids {
+        let leader_style = Style::default()
+            .fg(palette.kill_accent)
+            .add_modifier(Modifier::BOLD);
+        let pg_style = if ids.pgid == row.pid {
+            leader_style
+        } else {
+            Style::default().fg(palette.text_dim)
+        };
+        let sid_style = if ids.sid == row.pid {
+            leader_style.add_modifier(Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(palette.text_dim)
+        };
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!("pg:{}", ids.pgid), pg_style));
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!("s:{}", ids.sid), sid_style));
+    }
+
     let direct_cpu_color = app.theme().get_cpu_color(row.cpu_percent);
     spans.push(Span::raw(" "));
     spans.push(Span::styled(
         format!("[CPU: {:>5.1}%]", row.cpu_percent),
         Style::default().fg(direct_cpu_color),
     ));
-    if (row.subtree_cpu - row.cpu_percent).abs() > 0.1 {
+    let own_only = app.tree_show_own_metrics();
+    if !own_only && (row.subtree_cpu - row.cpu_percent).abs() > 0.1 {
         spans.push(Span::raw(" "));
         spans.push(Span::styled(
             format!("[Total: {:>5.1}%]", row.subtree_cpu),
@@ -137,17 +191,17 @@ fn build_tree_line(app: &App, row: &TreeRow, is_selected: bool, in_subtree: bool
         ));
     }
 
-    let direct_mem_color = app.theme().get_memory_color(row.memory_bytes);
+    let direct_mem_color = app.memory_color(row.memory_bytes);
     spans.push(Span::raw(" "));
     spans.push(Span::styled(
         format!("[Mem: {}]", format_bytes(row.memory_bytes)),
         Style::default().fg(direct_mem_color),
     ));
-    if row.subtree_memory_bytes > row.memory_bytes {
+    if !own_only && row.subtree_memory_bytes > row.memory_bytes {
         spans.push(Span::raw(" "));
         spans.push(Span::styled(
             format!("[Total: {}]", format_bytes(row.subtree_memory_bytes)),
-            Style::default().fg(app.theme().get_memory_color(row.subtree_memory_bytes)),
+            Style::default().fg(app.memory_color(row.subtree_memory_bytes)),
         ));
     }
 
@@ -165,7 +219,13 @@ fn build_tree_line(app: &App, row: &TreeRow, is_selected: bool, in_subtree: bool
 
     let mut line = Line::from(spans);
     if is_selected {
-        line.style = Style::default().bg(palette.highlight_selected);
+        line.style = match selection_style {
+            SelectionStyle::Background => Style::default().bg(palette.highlight_selected),
+            SelectionStyle::Gutter => Style::default().add_modifier(Modifier::BOLD),
+            SelectionStyle::Reverse => {
+                Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            }
+        };
     } else if let Some(risk) = &row.risk {
         let color = match risk.level {
             RiskLevel::Critical => palette.status_error,
@@ -232,13 +292,17 @@ fn render_kill_prompt(
 ) {
     let mut content: Vec<Line> = Vec::new();
     let count = prompt.lines.len();
-    content.push(Line::from(format!("Killing PID {} will", prompt.pid)));
-    content.push(Line::from(format!("terminate {} process(es):", count)));
-    content.push(Line::default());
-    for line in &prompt.lines {
-        content.push(Line::from(line.clone()));
+    // a leaf takes nothing else down with it, so skip the tree listing.
+    let leaf = count == 1;
+    if !leaf {
+        content.push(Line::from(format!("Killing PID {} will", prompt.pid)));
+        content.push(Line::from(format!("terminate {}:", process_count(count))));
+        content.push(Line::default());
+        for line in &prompt.lines {
+            content.push(Line::from(line.clone()));
+        }
+        content.push(Line::default());
     }
-    content.push(Line::default());
     if let Some(risk) = &prompt.risk {
         let (label, color) = match risk.level {
             RiskLevel::Critical => ("CRITICAL", palette.status_error),
@@ -256,7 +320,27 @@ fn render_kill_prompt(
         ]));
         content.push(Line::default());
     }
-    content.push(Line::from("Send SIGTERM? (y/n)"));
+    // SIGKILL is the one signal a tracer can't hold back.
+    if let Some((tracer, name)) = &prompt.tracer
+        && prompt.signal != Signal::Sigkill
+    {
+        content.push(Line::from(Span::styled(
+            format!(
+                "Traced by PID {tracer} ({name}), which may intercept {}",
+                prompt.signal.name()
+            ),
+            Style::default().fg(palette.status_warning),
+        )));
+        content.push(Line::default());
+    }
+    if leaf {
+        content.push(Line::from(format!(
+            "Kill PID {} (no children)? (y/n)",
+            prompt.pid
+        )));
+    } else {
+        content.push(Line::from("Send SIGTERM? (y/n)"));
+    }
 
     let max_width = content
         .iter()
@@ -264,17 +348,8 @@ fn render_kill_prompt(
         .max()
         .unwrap_or(20)
         .max(24);
-    let popup_width = min(max_width + 4, area.width as usize) as u16;
-    let popup_height = min(content.len() + 4, area.height as usize) as u16;
-
-    let popup_x = area.x + area.width.saturating_sub(popup_width) / 2;
-    let popup_y = area.y + area.height.saturating_sub(popup_height) / 2;
-    let popup = Rect {
-        x: popup_x,
-        y: popup_y,
-        width: popup_width,
-        height: popup_height,
-    };
+    let popup_width = min(max_width + 4, u16::MAX as usize) as u16;
+    let width = popup_rect(area, popup_width, 1).width;
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -289,12 +364,15 @@ fn render_kill_prompt(
     let paragraph = Paragraph::new(content)
         .block(block)
         .wrap(Wrap { trim: false });
+    // names wider than the clamped popup wrap, so size by rendered rows rather than lines
+    let rows = paragraph.line_count(width.saturating_sub(2)) + 2;
+    let popup = popup_rect(area, width, min(rows, u16::MAX as usize) as u16);
 
     frame.render_widget(Clear, popup);
     frame.render_widget(paragraph, popup);
 }
 
-fn format_bytes(bytes: u64) -> String {
+pub(crate) fn format_bytes(bytes: u64) -> String {
     const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
     if bytes == 0 {
         return "0 B".to_string();
@@ -311,3 +389,101 @@ fn format_bytes(bytes: u64) -> String {
         format!("{:.1} {}", value, UNITS[unit])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::text::Span;
+
+    use super::render_kill_prompt;
+    use crate::config::Theme;
+    use crate::signals::Signal;
+    use pkillr::TreeKillPrompt;
+
+    fn prompt() -> TreeKillPrompt {
+        TreeKillPrompt {
+            pid: 4242,
+            signal: Signal::Sigterm,
+            lines: vec![
+                "データベース同期サービス管理プロセス (4242)".to_string(),
+                "  └─ 中文进程名称非常非常长的后台工作线程 (4243)".to_string(),
+            ],
+            risk: None,
+            tracer: None,
+        }
+    }
+
+    fn draw(width: u16, height: u16) -> Buffer {
+        draw_prompt(&prompt(), width, height)
+    }
+
+    fn draw_prompt(prompt: &TreeKillPrompt, width: u16, height: u16) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| {
+                let area = frame.size();
+                render_kill_prompt(frame, area, Theme::default().palette(), prompt);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// buffer rows as text, skipping the filler cells behind wide characters.
+    fn rows(buffer: &Buffer) -> Vec<String> {
+        let area = buffer.area;
+        (area.y..area.y + area.height)
+            .map(|y| {
+                let mut row = String::new();
+                let mut x = area.x;
+                while x < area.x + area.width {
+                    let symbol = buffer.get(x, y).symbol();
+                    row.push_str(symbol);
+                    x += Span::raw(symbol).width().max(1) as u16;
+                }
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kill_prompt_with_cjk_names_fits_without_clipping() {
+        let text = rows(&draw(100, 20)).join("\n");
+        for line in prompt().lines {
+            assert!(text.contains(line.trim_start()), "missing {line:?}");
+        }
+        assert!(text.contains("Send SIGTERM? (y/n)"));
+    }
+
+    #[test]
+    fn kill_prompt_with_cjk_names_wraps_on_narrow_frames() {
+        let text = rows(&draw(30, 20)).concat();
+        assert!(text.contains("Send SIGTERM? (y/n)"));
+        assert!(text.contains("(4243)"));
+    }
+
+    #[test]
+    fn kill_prompt_for_a_leaf_says_nothing_else_dies() {
+        let mut leaf = prompt();
+        leaf.lines.truncate(1);
+        let text = rows(&draw_prompt(&leaf, 60, 10)).join("\n");
+        assert!(text.contains("Kill PID 4242 (no children)? (y/n)"));
+        assert!(!text.contains("terminate"));
+    }
+
+    #[test]
+    fn kill_prompt_warns_about_an_attached_debugger() {
+        let mut traced = prompt();
+        traced.tracer = Some((777, "gdb".to_string()));
+        let text = rows(&draw_prompt(&traced, 100, 20)).join("\n");
+        assert!(text.contains("Traced by PID 777 (gdb), which may intercept SIGTERM"));
+    }
+
+    #[test]
+    fn kill_prompt_survives_tiny_frames() {
+        for (width, height) in [(1, 1), (3, 2), (8, 4), (12, 3)] {
+            draw(width, height);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::json::quote;
+
+const VIEWS_SECTION: &str = "[views]";
+
+/// a search query saved under a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedView {
+    pub name: String,
+    pub query: String,
+}
+
+/// `$XDG_CONFIG_HOME/pkillr/config.toml`, falling back to `~/.config`.
+pub fn default_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("pkillr").join("config.toml"))
+}
+
+/// reads the `[views]` table; a missing file simply has no views.
+pub fn load(path: &Path) -> Result<Vec<SavedView>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("failed to read {}: {}", path.display(), err)),
+    };
+
+    let mut views = Vec::new();
+    for (idx, line) in section_lines(&contents, VIEWS_SECTION) {
+        let view = parse_entry(line)
+            .ok_or_else(|| format!("{}:{}: expected name = \"query\"", path.display(), idx + 1))?;
+        views.retain(|existing: &SavedView| existing.name != view.name);
+        views.push(view);
+    }
+    Ok(views)
+}
+
+/// rewrites the `[views]` table, leaving the rest of the file untouched.
+pub fn save(path: &Path, views: &[SavedView]) -> Result<(), String> {
+    let existing = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(format!("failed to read {}: {}", path.display(), err)),
+    };
+
+    let mut output: Vec<String> = Vec::new();
+    let mut in_views = false;
+    for line in existing.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_views = trimmed == VIEWS_SECTION;
+        }
+        if !in_views {
+            output.push(line.to_string());
+        }
+    }
+    while output.last().is_some_and(|line| line.trim().is_empty()) {
+        output.pop();
+    }
+    if !output.is_empty() {
+        output.push(String::new());
+    }
+    output.push(VIEWS_SECTION.to_string());
+    for view in views {
+        output.push(format!("{} = {}", view.name, quote(&view.query)));
+    }
+    output.push(String::new());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("failed to create {}: {}", dir.display(), err))?;
+    }
+    fs::write(path, output.join("\n"))
+        .map_err(|err| format!("failed to write {}: {}", path.display(), err))
+}
+
+/// view names are bare toml keys.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// non-blank, non-comment lines of one `[section]`, with their 0-based line index.
+pub(crate) fn section_lines<'a>(contents: &'a str, header: &str) -> Vec<(usize, &'a str)> {
+    let mut lines = Vec::new();
+    let mut inside = false;
+    for (idx, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            inside = line == header;
+            continue;
+        }
+        if inside {
+            lines.push((idx, line));
+        }
+    }
+    lines
+}
+
+/// strips the quotes from a basic toml string and resolves its escapes.
+pub(crate) fn unquote(value: &str) -> Option<String> {
+    let inner = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                'n' => text.push('\n'),
+                't' => text.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    text.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                other => text.push(other),
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    Some(text)
+}
+
+fn parse_entry(line: &str) -> Option<SavedView> {
+    let (name, value) = line.split_once('=')?;
+    let name = name.trim();
+    if !is_valid_name(name) {
+        return None;
+    }
+    Some(SavedView {
+        name: name.to_string(),
+        query: unquote(value)?,
+    })
+}
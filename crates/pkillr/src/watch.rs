@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::config::Config;
+use crate::process::ProcessManager;
+use pkillr::App;
+
+/// exit code when the timeout elapses before the condition holds.
+const EXIT_TIMEOUT: i32 = 1;
+/// exit code when the wait is aborted with ctrl+c.
+const EXIT_INTERRUPTED: i32 = 130;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WaitCondition {
+    Gone,
+    Appear,
+}
+
+impl WaitCondition {
+    fn is_met(self, matches: usize) -> bool {
+        match self {
+            WaitCondition::Gone => matches == 0,
+            WaitCondition::Appear => matches > 0,
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            WaitCondition::Gone => "exit",
+            WaitCondition::Appear => "appear",
+        }
+    }
+}
+
+/// polls the process list with the tui's filter logic until `condition` holds,
+/// printing progress to stderr. returns the process exit code.
+pub fn run(
+    config: Config,
+    filter: &str,
+    condition: WaitCondition,
+    timeout: Option<Duration>,
+    interval: Duration,
+) -> Result<i32> {
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&interrupted);
+    ctrlc::set_handler(move || flag.store(true, Ordering::SeqCst))
+        .context("failed to install ctrl+c handler")?;
+
+    // another user's process still counts as running, so --wait-gone always
+    // looks at every process, just as --kill signals any pid it is given.
+    let show_all_processes = config.show_all_processes || condition == WaitCondition::Gone;
+    let mut app = App::new(Config {
+        initial_filter: Some(filter.to_string()),
+        show_all_processes,
+        ..config
+    });
+    // pkillr and the shells that launched it carry the filter in their own
+    // command lines, so they would otherwise always match.
+    let ignored = self_and_ancestors();
+    let started = Instant::now();
+    let mut last_pids: Option<Vec<u32>> = None;
+
+    loop {
+        app.update_processes();
+        let mut pids: Vec<u32> = app
+            .filtered_processes()
+            .iter()
+            .map(|proc| proc.pid)
+            .filter(|pid| !ignored.contains(pid))
+            .collect();
+        pids.sort_unstable();
+
+        if condition.is_met(pids.len()) {
+            eprintln!(
+                "pkillr: '{}' {} after {:.1}s",
+                filter,
+                match condition {
+                    WaitCondition::Gone => "gone",
+                    WaitCondition::Appear => "appeared",
+                },
+                started.elapsed().as_secs_f32()
+            );
+            if condition == WaitCondition::Appear {
+                println!("{}", join_pids(&pids));
+            }
+            return Ok(0);
+        }
+
+        if last_pids.as_ref() != Some(&pids) {
+            eprintln!(
+                "pkillr: waiting for '{}' to {} ({} matching{})",
+                filter,
+                condition.verb(),
+                pids.len(),
+                if pids.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", join_pids(&pids))
+                }
+            );
+            last_pids = Some(pids);
+        }
+
+        if let Some(limit) = timeout
+            && started.elapsed() >= limit
+        {
+            eprintln!(
+                "pkillr: timed out after {:.1}s waiting for '{}' to {}",
+                limit.as_secs_f32(),
+                filter,
+                condition.verb()
+            );
+            return Ok(EXIT_TIMEOUT);
+        }
+
+        let wake = Instant::now() + interval;
+        while Instant::now() < wake {
+            if interrupted.load(Ordering::SeqCst) {
+                eprintln!("pkillr: interrupted");
+                return Ok(EXIT_INTERRUPTED);
+            }
+            thread::sleep(Duration::from_millis(50).min(interval));
+        }
+    }
+}
+
+fn self_and_ancestors() -> HashSet<u32> {
+    let parents: HashMap<u32, u32> = ProcessManager::new()
+        .get_processes(true)
+        .into_iter()
+        .filter_map(|proc| proc.parent_pid.map(|parent| (proc.pid, parent)))
+        .collect();
+    let mut chain = HashSet::new();
+    let mut current = std::process::id();
+    while chain.insert(current) {
+        match parents.get(&current) {
+            Some(parent) => current = *parent,
+            None => break,
+        }
+    }
+    chain
+}
+
+fn join_pids(pids: &[u32]) -> String {
+    pids.iter()
+        .map(|pid| pid.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// a `parse_duration` that refuses zero, which would poll in a busy loop.
+pub fn parse_poll_interval(value: &str) -> Result<Duration, String> {
+    match parse_duration(value)? {
+        interval if interval.is_zero() => Err("poll interval must be above zero".to_string()),
+        interval => Ok(interval),
+    }
+}
+
+/// parses durations like `30s`, `500ms`, `2m` or `1h`; a bare number is seconds.
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let amount: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{value}'"))?;
+    let seconds = match unit.trim() {
+        "" | "s" => amount,
+        "ms" => amount / 1000.0,
+        "m" => amount * 60.0,
+        "h" => amount * 3600.0,
+        other => {
+            return Err(format!(
+                "unknown duration unit '{other}' (use ms, s, m or h)"
+            ));
+        }
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration '{value}'"))
+}
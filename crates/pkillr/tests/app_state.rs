@@ -0,0 +1,1966 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use pkillr::config::{
+    Column, Config, CpuSource, EnterAction, MatchStyle, MemoryScale, MemoryThresholds, NameSource,
+    PathDisplay, SearchWeights, SelectionStyle, SignalOrder, SortField, Theme, TreeStyle,
+};
+use pkillr::process::{ProcessDetails, ProcessInfo, ProcessSource, ProcessState, can_kill};
+use pkillr::signals::{Signal, SignalSender, SignalTarget};
+use pkillr::{
+    App, AppMode, DisplaySettings, InfoSection, InfoSettings, ProtectedTarget, SortColumn,
+    StatusLevel, TableGeometry, UserSort,
+};
+
+struct FixedProcesses(Vec<ProcessInfo>);
+
+impl ProcessSource for FixedProcesses {
+    fn get_processes(&mut self, _show_all: bool) -> Vec<ProcessInfo> {
+        self.0.clone()
+    }
+
+    /// every fixture process runs in a service named after itself.
+    fn cgroup_paths(&mut self, pids: &[u32]) -> HashMap<u32, String> {
+        self.0
+            .iter()
+            .filter(|proc| pids.contains(&proc.pid))
+            .map(|proc| (proc.pid, format!("/system.slice/{}.service", proc.name)))
+            .collect()
+    }
+
+    /// the fixture's web servers are the only ones on the network; `locked` processes
+    /// can't have their descriptors read.
+    fn network_pids(&mut self, pids: &[u32]) -> HashMap<u32, bool> {
+        self.0
+            .iter()
+            .filter(|proc| pids.contains(&proc.pid) && proc.name != "locked")
+            .map(|proc| (proc.pid, proc.name == "nginx"))
+            .collect()
+    }
+
+    fn get_details(&mut self, _pid: u32) -> Option<ProcessDetails> {
+        None
+    }
+
+    fn total_memory_bytes(&self) -> u64 {
+        8 * 1024 * 1024 * 1024
+    }
+}
+
+/// a list the test can change between refreshes.
+struct SharedProcesses(Rc<RefCell<Vec<ProcessInfo>>>);
+
+impl ProcessSource for SharedProcesses {
+    fn get_processes(&mut self, _show_all: bool) -> Vec<ProcessInfo> {
+        self.0.borrow().clone()
+    }
+
+    fn get_details(&mut self, _pid: u32) -> Option<ProcessDetails> {
+        None
+    }
+
+    fn total_memory_bytes(&self) -> u64 {
+        8 * 1024 * 1024 * 1024
+    }
+}
+
+/// other users' processes show up only with `show_all`, like `ProcessManager`.
+struct OwnProcesses(Vec<ProcessInfo>);
+
+impl ProcessSource for OwnProcesses {
+    fn get_processes(&mut self, show_all: bool) -> Vec<ProcessInfo> {
+        self.0
+            .iter()
+            .filter(|proc| show_all || proc.user == "tester")
+            .cloned()
+            .collect()
+    }
+
+    fn get_details(&mut self, _pid: u32) -> Option<ProcessDetails> {
+        None
+    }
+
+    fn total_memory_bytes(&self) -> u64 {
+        8 * 1024 * 1024 * 1024
+    }
+}
+
+/// stands in for the live system when signalling: sends are logged, and pids missing
+/// from `processes` fail as "process not found", like ones that already exited.
+#[derive(Clone, Default)]
+struct FakeSignals {
+    processes: Rc<RefCell<Vec<ProcessInfo>>>,
+    sent: Rc<RefCell<Vec<(u32, Signal)>>>,
+}
+
+impl FakeSignals {
+    fn reaching(processes: Vec<ProcessInfo>) -> Self {
+        Self {
+            processes: Rc::new(RefCell::new(processes)),
+            ..Default::default()
+        }
+    }
+
+    fn sender(&self) -> SignalSender {
+        SignalSender::with_target(Box::new(self.clone()))
+    }
+
+    fn sent(&self) -> Vec<(u32, Signal)> {
+        self.sent.borrow().clone()
+    }
+}
+
+impl SignalTarget for FakeSignals {
+    fn processes(&mut self) -> Vec<ProcessInfo> {
+        self.processes.borrow().clone()
+    }
+
+    fn send(
+        &mut self,
+        target: &ProcessInfo,
+        signal: Signal,
+        _value: Option<i32>,
+    ) -> Result<(), String> {
+        self.sent.borrow_mut().push((target.pid, signal));
+        Ok(())
+    }
+}
+
+fn process(pid: u32, name: &str, cpu_percent: f32, memory_bytes: u64) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        name: name.to_string(),
+        cpu_percent,
+        memory_bytes,
+        user: "tester".to_string(),
+        runtime: Duration::from_secs(pid as u64),
+        cpu_time: Duration::from_secs(pid as u64 % 100),
+        cmdline: vec![format!("/usr/bin/{name}")],
+        parent_pid: Some(1),
+        state: ProcessState::Sleeping,
+        ..Default::default()
+    }
+}
+
+fn config() -> Config {
+    Config {
+        theme: Theme::default(),
+        selection_style: SelectionStyle::default(),
+        signal_order: SignalOrder::default(),
+        name_source: NameSource::default(),
+        show_all_processes: true,
+        cpu_source: CpuSource::default(),
+        refresh_rate_ms: 1000,
+        initial_filter: None,
+        initial_sort: SortField::Cpu,
+        sort_descending: true,
+        match_style: MatchStyle::Fuzzy,
+        debug_scores: false,
+        search_weights: SearchWeights::default(),
+        case_sensitive: false,
+        columns: Column::DEFAULT_ORDER.to_vec(),
+        lock_widths: false,
+        cpu_precision: 1,
+        mem_precision: 1,
+        info_path_display: PathDisplay::Full,
+        info_max_maps: 64,
+        info_max_open_files: 64,
+        info_max_environment: 64,
+        info_env_entry_max: 512,
+        tree_style: TreeStyle::Unicode,
+        tree_max_depth: None,
+        tree_min_cpu: 1.0,
+        tree_min_memory_bytes: 100 * 1024 * 1024,
+        tree_with_info: false,
+        tree_confirm_min: 0,
+        enter_action: EnterAction::Kill,
+        show_refresh_age: false,
+        pin_selection: false,
+        hide_idle: false,
+        idle_cpu_threshold: 0.5,
+        swap_warn_bytes: 100 * 1024 * 1024,
+        memory_thresholds: MemoryThresholds::default(),
+        runtime_colors: None,
+        safe_mode: false,
+        protected: Vec::new(),
+        kill_cooldown: Duration::ZERO,
+        arm_delay: Duration::ZERO,
+        arm_min_targets: 2,
+        advanced_signals: false,
+        mouse: true,
+        config_file: None,
+        cli_display: DisplaySettings::default(),
+        cli_info: InfoSettings::default(),
+    }
+}
+
+/// an app whose signals never leave the test; targets read as already exited.
+fn fake_signals_app(config: Config, source: Box<dyn ProcessSource>) -> App {
+    App::with_signals(config, source, FakeSignals::default().sender())
+}
+
+fn app() -> App {
+    let processes = vec![
+        process(4001, "firefox", 12.5, 900 * 1024 * 1024),
+        process(4002, "postgres", 3.0, 300 * 1024 * 1024),
+        process(4003, "nginx", 0.5, 40 * 1024 * 1024),
+        process(4004, "fish", 0.1, 8 * 1024 * 1024),
+    ];
+    fake_signals_app(config(), Box::new(FixedProcesses(processes)))
+}
+
+fn press(app: &mut App, code: KeyCode) -> bool {
+    app.handle_input(KeyEvent::new(code, KeyModifiers::NONE))
+        .expect("input handled")
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+fn names(app: &App) -> Vec<&str> {
+    app.filtered_processes()
+        .iter()
+        .map(|proc| proc.name.as_str())
+        .collect()
+}
+
+#[test]
+fn starts_in_normal_mode_sorted_by_cpu() {
+    let app = app();
+    assert_eq!(app.mode(), AppMode::Normal);
+    assert_eq!(app.sort_column(), SortColumn::Cpu);
+    assert_eq!(names(&app), ["firefox", "postgres", "nginx", "fish"]);
+    assert!(app.needs_refresh());
+}
+
+#[test]
+fn search_narrows_the_list() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('/'));
+    assert_eq!(app.mode(), AppMode::Search);
+
+    type_text(&mut app, "postg");
+    assert_eq!(app.search_query(), "postg");
+    assert_eq!(names(&app), ["postgres"]);
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.mode(), AppMode::Normal);
+    assert_eq!(names(&app), ["postgres"]);
+}
+
+#[test]
+fn typing_a_signal_name_hints_at_the_signal_menu() {
+    let mut by_name = app();
+    press(&mut by_name, KeyCode::Char('/'));
+    type_text(&mut by_name, "sigterm");
+    let (message, _) = by_name.status_message().expect("hint shown");
+    assert!(message.starts_with("SIGTERM is a signal"), "{message}");
+    assert!(names(&by_name).is_empty());
+
+    let mut by_pid = app();
+    press(&mut by_pid, KeyCode::Char('/'));
+    type_text(&mut by_pid, "4001");
+    assert!(
+        by_pid
+            .status_message()
+            .is_none_or(|(message, _)| !message.contains("is a signal"))
+    );
+}
+
+#[test]
+fn camel_case_matches_highlight_the_original_name() {
+    let processes = vec![process(4001, "NetworkManager", 1.0, 1)];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "net man");
+    app.tick(std::time::Instant::now() + Duration::from_secs(1));
+
+    let name = "NetworkManager";
+    let highlighted: String = app
+        .highlight_indices(4001)
+        .expect("name highlighted")
+        .iter()
+        .map(|offset| &name[*offset..=*offset])
+        .collect();
+    assert_eq!(highlighted, "NetMan");
+}
+
+#[test]
+fn filter_layers_narrow_together_and_pop_off_in_order() {
+    let mut processes = vec![
+        process(4001, "firefox", 12.5, 1),
+        process(4002, "firefox-bin", 3.0, 1),
+        process(4003, "fish", 0.1, 1),
+    ];
+    processes[1].user = "alice".to_string();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "user:tester");
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.search_query(), "");
+    type_text(&mut app, "fire");
+    app.tick(std::time::Instant::now() + Duration::from_secs(1));
+    assert_eq!(names(&app), ["firefox"]);
+    assert_eq!(app.filter_layers().collect::<Vec<_>>(), ["user:tester"]);
+
+    for _ in 0..4 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    assert_eq!(app.filter_layers().count(), 1);
+    press(&mut app, KeyCode::Backspace);
+    assert_eq!(app.filter_layers().count(), 0);
+    assert_eq!(names(&app).len(), 3);
+}
+
+#[test]
+fn clearing_the_search_restores_every_process() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "nginx");
+    assert_eq!(app.filtered_processes().len(), 1);
+
+    for _ in 0.."nginx".len() {
+        press(&mut app, KeyCode::Backspace);
+    }
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.mode(), AppMode::Normal);
+    assert_eq!(app.filtered_processes().len(), 4);
+}
+
+#[test]
+fn esc_in_normal_mode_clears_the_filter_and_keeps_selection() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "nginx");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.current_pid(), Some(4003));
+
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.search_query(), "");
+    assert_eq!(app.filtered_processes().len(), 4);
+    assert_eq!(app.current_pid(), Some(4003));
+}
+
+#[test]
+fn search_keys_do_not_trigger_normal_bindings() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('/'));
+    let quit = press(&mut app, KeyCode::Char('q'));
+    assert!(!quit);
+    assert_eq!(app.mode(), AppMode::Search);
+    assert_eq!(app.search_query(), "q");
+}
+
+#[test]
+fn sort_keys_cycle_columns() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('>'));
+    assert_eq!(app.sort_column(), SortColumn::Memory);
+    press(&mut app, KeyCode::Char('>'));
+    assert_eq!(app.sort_column(), SortColumn::Pid);
+    assert_eq!(names(&app), ["fish", "nginx", "postgres", "firefox"]);
+
+    press(&mut app, KeyCode::Char('<'));
+    press(&mut app, KeyCode::Char('<'));
+    assert_eq!(app.sort_column(), SortColumn::Cpu);
+
+    press(&mut app, KeyCode::Char('<'));
+    assert_eq!(app.sort_column(), SortColumn::Runtime);
+}
+
+#[test]
+fn space_toggles_selection_of_current_row() {
+    let mut app = app();
+    let pid = app.current_pid().expect("a row is selected");
+    assert!(!app.has_selection());
+
+    press(&mut app, KeyCode::Char(' '));
+    assert!(app.is_pid_selected(pid));
+
+    press(&mut app, KeyCode::Down);
+    let next = app.current_pid().expect("a row is selected");
+    press(&mut app, KeyCode::Char(' '));
+    assert!(app.is_pid_selected(pid));
+    assert!(app.is_pid_selected(next));
+
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char(' '));
+    assert!(!app.is_pid_selected(pid));
+    assert!(app.is_pid_selected(next));
+}
+
+#[test]
+fn selection_can_be_inverted_filled_and_cleared() {
+    let mut app = app();
+    let pid = app.current_pid().expect("a row is selected");
+    press(&mut app, KeyCode::Char(' '));
+
+    let selected = |app: &App| {
+        app.filtered_processes()
+            .iter()
+            .filter(|proc| app.is_pid_selected(proc.pid))
+            .count()
+    };
+
+    press(&mut app, KeyCode::Char('*'));
+    assert!(!app.is_pid_selected(pid));
+    assert_eq!(selected(&app), 3);
+
+    app.handle_input(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(selected(&app), 4);
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "nginx");
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('*'));
+    assert!(!app.has_selection());
+
+    press(&mut app, KeyCode::Char('*'));
+    press(&mut app, KeyCode::Char('u'));
+    assert!(!app.has_selection());
+}
+
+#[test]
+fn navigation_marks_the_app_for_redraw() {
+    let mut app = app();
+    app.clear_refresh_flag();
+    assert!(!app.needs_refresh());
+
+    press(&mut app, KeyCode::Down);
+    assert!(app.needs_refresh());
+    assert_eq!(app.selected_index(), 1);
+
+    app.clear_refresh_flag();
+    press(&mut app, KeyCode::Char('G'));
+    assert!(app.needs_refresh());
+    assert_eq!(app.selected_index(), 3);
+
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_index(), 0);
+}
+
+#[test]
+fn signal_menu_opens_and_closes() {
+    let mut app = app();
+    let pid = app.current_pid();
+    app.clear_refresh_flag();
+
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.mode(), AppMode::SignalMenu);
+    assert!(app.signal_menu_open());
+    assert_eq!(app.signal_menu_target(), pid);
+    assert!(app.needs_refresh());
+
+    let initial = app.signal_menu_selected();
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.signal_menu_selected(), initial + 1);
+
+    app.clear_refresh_flag();
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.mode(), AppMode::Normal);
+    assert!(!app.signal_menu_open());
+    assert!(app.needs_refresh());
+}
+
+#[test]
+fn enter_follows_the_configured_action() {
+    let with_action = |action: EnterAction| {
+        let mut cfg = config();
+        cfg.enter_action = action;
+        let processes = vec![process(4001, "firefox", 12.5, 1)];
+        let mut app = fake_signals_app(cfg, Box::new(FixedProcesses(processes)));
+        press(&mut app, KeyCode::Enter);
+        app
+    };
+
+    let app = with_action(EnterAction::SignalMenu);
+    assert!(app.signal_menu_open());
+    assert_eq!(app.signal_menu_target(), Some(4001));
+
+    let app = with_action(EnterAction::Info);
+    assert!(app.is_info_pane_open());
+    assert!(!app.signal_menu_open());
+}
+
+#[test]
+fn signal_menu_swallows_quit() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('s'));
+    let quit = press(&mut app, KeyCode::Char('q'));
+    assert!(!quit);
+    assert_eq!(app.mode(), AppMode::SignalMenu);
+}
+
+#[test]
+fn help_popup_returns_to_previous_mode() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('?'));
+    assert!(app.help_popup_open());
+
+    press(&mut app, KeyCode::Esc);
+    assert!(!app.help_popup_open());
+    assert_eq!(app.mode(), AppMode::Normal);
+}
+
+#[test]
+fn help_popup_filters_bindings_incrementally() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('?'));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "kill");
+    assert!(app.help_popup_open());
+    let entries = app.help_entries();
+    assert!(!entries.is_empty());
+    assert!(entries.iter().any(|entry| entry.keys == "K"));
+    assert!(entries.iter().all(|entry| entry.keys != "zz"));
+    assert!(entries.iter().any(|entry| entry.keys == " 9 SIGKILL"));
+
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('j'));
+    assert!(app.help_popup_open());
+    press(&mut app, KeyCode::Char('q'));
+    assert!(!app.help_popup_open());
+}
+
+#[test]
+fn info_pane_toggles() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('i'));
+    assert!(app.is_info_pane_open());
+    assert_eq!(app.mode(), AppMode::InfoPane);
+
+    press(&mut app, KeyCode::Esc);
+    assert!(!app.is_info_pane_open());
+    assert_eq!(app.mode(), AppMode::Normal);
+}
+
+#[test]
+fn digits_jump_between_info_sections() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('i'));
+    app.set_info_section_starts(vec![
+        (InfoSection::Basic, 0),
+        (InfoSection::Command, 12),
+        (InfoSection::Children, 20),
+    ]);
+
+    press(&mut app, KeyCode::Char('2'));
+    assert_eq!(app.info_pane_scroll(), 12);
+    press(&mut app, KeyCode::Char(']'));
+    assert_eq!(app.info_pane_scroll(), 20);
+    press(&mut app, KeyCode::Char('['));
+    press(&mut app, KeyCode::Char('['));
+    assert_eq!(app.info_pane_scroll(), 0);
+
+    press(&mut app, KeyCode::Char('9'));
+    assert_eq!(app.info_pane_scroll(), 0);
+    assert_eq!(
+        app.status_message().map(|(_, level)| *level),
+        Some(StatusLevel::Warning)
+    );
+}
+
+#[test]
+fn a_process_vanishing_mid_inspection_is_reported_by_name() {
+    // listed by the last refresh, but the mock source has no details for it: it exited in between.
+    let processes = vec![process(4_199_990, "flaky-worker", 1.0, 1)];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('i'));
+
+    assert!(app.process_details().is_none());
+    assert_eq!(app.info_exited(), Some((4_199_990, "flaky-worker")));
+
+    press(&mut app, KeyCode::Char('k'));
+    let (message, level) = app.status_message().expect("kill reported");
+    assert_eq!(message, "flaky-worker (PID 4199990) already exited");
+    assert_eq!(*level, StatusLevel::Warning);
+}
+
+#[test]
+fn refresh_age_counts_refreshes_and_redraws_as_it_ages() {
+    let mut app = app();
+    assert!(!app.show_refresh_age());
+    app.handle_input(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert!(app.show_refresh_age());
+
+    let generation = app.refresh_generation();
+    app.update_processes();
+    assert_eq!(app.refresh_generation(), generation + 1);
+
+    let now = std::time::Instant::now();
+    app.clear_refresh_flag();
+    app.tick(now + Duration::from_secs(3));
+    assert!(app.needs_refresh());
+    assert_eq!(app.refresh_age(now + Duration::from_secs(3)).as_secs(), 3);
+}
+
+#[test]
+fn pinned_selection_keeps_its_screen_row_as_rows_appear_above() {
+    let list = Rc::new(RefCell::new(vec![
+        process(4001, "a", 50.0, 1),
+        process(4002, "b", 40.0, 1),
+        process(4003, "c", 30.0, 1),
+        process(4004, "d", 20.0, 1),
+    ]));
+    let mut cfg = config();
+    cfg.pin_selection = true;
+    let mut app = fake_signals_app(cfg, Box::new(SharedProcesses(Rc::clone(&list))));
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Down);
+    }
+    app.set_table_scroll_offset(1);
+    assert_eq!(app.current_pid(), Some(4004));
+
+    list.borrow_mut()
+        .extend([process(4005, "x", 70.0, 1), process(4006, "y", 60.0, 1)]);
+    app.update_processes();
+    assert_eq!(app.current_pid(), Some(4004));
+    assert_eq!(app.selected_index(), 5);
+    assert_eq!(app.table_scroll_offset(), 3);
+}
+
+#[test]
+fn parent_jump_clears_a_filter_that_hides_the_parent() {
+    let mut worker = process(4005, "worker", 0.0, 1024);
+    worker.parent_pid = Some(4003);
+    let mut processes = vec![process(4003, "nginx", 0.5, 40 * 1024 * 1024)];
+    processes.push(worker);
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "worker");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(names(&app), ["worker"]);
+
+    press(&mut app, KeyCode::Char('P'));
+    assert_eq!(app.search_query(), "");
+    assert_eq!(app.current_pid(), Some(4003));
+
+    press(&mut app, KeyCode::Char('C'));
+    assert_eq!(app.current_pid(), Some(4005));
+}
+
+#[test]
+fn child_jump_still_works_with_the_info_pane_open() {
+    let mut worker = process(4005, "worker", 0.0, 1024);
+    worker.parent_pid = Some(4003);
+    let processes = vec![process(4003, "nginx", 0.5, 40 * 1024 * 1024), worker];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    assert_eq!(app.current_pid(), Some(4003));
+
+    press(&mut app, KeyCode::Char('i'));
+    let cgroups = app.info_cgroups_expanded();
+    press(&mut app, KeyCode::Char('C'));
+    assert_eq!(app.current_pid(), Some(4005));
+    assert_eq!(app.info_cgroups_expanded(), cgroups);
+
+    press(&mut app, KeyCode::Char('c'));
+    assert_eq!(app.info_cgroups_expanded(), !cgroups);
+}
+
+#[test]
+fn idle_filter_hides_quiet_processes_but_not_the_selection() {
+    let mut app = app();
+    app.jump_to_bottom();
+    assert_eq!(app.current_pid(), Some(4004));
+
+    press(&mut app, KeyCode::Char('I'));
+    assert!(app.hide_idle());
+    assert_eq!(names(&app), ["firefox", "postgres", "nginx", "fish"]);
+    assert_eq!(app.current_pid(), Some(4004));
+
+    app.jump_to_top();
+    app.update_processes();
+    assert_eq!(names(&app), ["firefox", "postgres", "nginx"]);
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "fish");
+    assert!(app.filtered_processes().is_empty());
+
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('I'));
+    assert_eq!(names(&app), ["fish"]);
+}
+
+#[test]
+fn jumps_to_the_heaviest_process_without_resorting() {
+    let mut processes = vec![
+        process(12, "beta", 40.0, 10),
+        process(11, "alpha", 40.0, 20),
+        process(13, "gamma", 5.0, 30),
+    ];
+    processes[2].memory_bytes = 900 * 1024 * 1024;
+    let mut config = config();
+    config.initial_sort = SortField::Pid;
+    config.sort_descending = false;
+    let mut app = fake_signals_app(config, Box::new(FixedProcesses(processes)));
+    assert_eq!(names(&app), ["alpha", "beta", "gamma"]);
+
+    press(&mut app, KeyCode::Char('J'));
+    assert_eq!(app.current_pid(), Some(11));
+    assert_eq!(names(&app), ["alpha", "beta", "gamma"]);
+
+    press(&mut app, KeyCode::Char('R'));
+    assert_eq!(app.current_pid(), Some(13));
+}
+
+#[test]
+fn safe_mode_refuses_critical_processes_inside_a_tree() {
+    let mut parent = process(4_190_001, "launcher", 1.0, 1);
+    parent.parent_pid = Some(1);
+    let mut child = process(4_190_002, "gnome-shell", 0.5, 1);
+    child.parent_pid = Some(parent.pid);
+    let mut safe = config();
+    safe.safe_mode = true;
+    let mut app = fake_signals_app(safe, Box::new(FixedProcesses(vec![parent, child])));
+    assert_eq!(app.current_pid(), Some(4_190_001));
+
+    press(&mut app, KeyCode::Char('x'));
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(*level, StatusLevel::Error);
+    assert!(message.contains("safe mode"), "{message}");
+    assert!(message.contains("gnome-shell"), "{message}");
+}
+
+#[test]
+fn tree_guards_see_descendants_owned_by_other_users() {
+    let mut parent = process(4_190_021, "launcher", 1.0, 1);
+    parent.parent_pid = Some(1);
+    let mut child = process(4_190_022, "gnome-shell", 0.5, 1);
+    child.parent_pid = Some(parent.pid);
+    child.user = "gdm".to_string();
+    let mut worker = process(4_190_023, "ghostd", 0.5, 1);
+    worker.parent_pid = Some(parent.pid);
+    worker.user = "gdm".to_string();
+
+    let mut safe = config();
+    safe.safe_mode = true;
+    safe.show_all_processes = false;
+    let processes = vec![parent.clone(), child.clone()];
+    let mut app = fake_signals_app(safe, Box::new(OwnProcesses(processes)));
+    assert_eq!(names(&app), ["launcher"]);
+    press(&mut app, KeyCode::Char('x'));
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(*level, StatusLevel::Error);
+    assert!(message.contains("safe mode"), "{message}");
+    assert!(message.contains("gnome-shell"), "{message}");
+
+    let mut guarded = config();
+    guarded.protected = vec![ProtectedTarget::Name("ghost*".to_string())];
+    guarded.show_all_processes = false;
+    let mut app = fake_signals_app(guarded, Box::new(OwnProcesses(vec![parent, worker])));
+    press(&mut app, KeyCode::Char('x'));
+    let (message, _) = app.status_message().unwrap();
+    assert!(
+        message.contains("ghostd (PID 4190023) is protected"),
+        "{message}"
+    );
+}
+
+#[test]
+fn safe_mode_can_be_turned_on_from_the_config_file() {
+    let dir = std::env::temp_dir().join(format!("pkillr-safe-mode-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&path, "[safety]\nsafe_mode = true\n").unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let processes = vec![process(4_190_003, "gnome-shell", 0.5, 1)];
+    let mut app = fake_signals_app(with_file, Box::new(FixedProcesses(processes)));
+    assert!(app.safe_mode());
+
+    press(&mut app, KeyCode::Char('x'));
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(*level, StatusLevel::Error);
+    assert!(message.contains("safe mode"), "{message}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn a_broken_display_table_leaves_the_safety_table_in_force() {
+    let dir = std::env::temp_dir().join(format!("pkillr-safety-first-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        &path,
+        "[display]\ntheme = \"no-such-theme\"\n\n[safety]\nsafe_mode = true\nprotected = [\"ghost*\"]\n",
+    )
+    .unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let processes = vec![process(4_199_995, "ghostd", 1.0, 1)];
+    let mut app = fake_signals_app(with_file, Box::new(FixedProcesses(processes)));
+    assert!(app.safe_mode());
+    press(&mut app, KeyCode::Char('k'));
+    let (message, level) = app.status_message().unwrap();
+    assert!(message.contains("is protected by 'ghost*'"), "{message}");
+    assert_eq!(*level, StatusLevel::Error);
+
+    // a [safety] table that stops parsing keeps the guards from before the reload.
+    std::fs::write(&path, "[safety]\nsafe_mode = maybe\n").unwrap();
+    app.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert!(app.safe_mode());
+    press(&mut app, KeyCode::Char('k'));
+    let (message, _) = app.status_message().unwrap();
+    assert!(message.contains("is protected by 'ghost*'"), "{message}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn argv0_titles_are_searchable_and_can_name_the_row() {
+    let mut checkpointer = process(4001, "postgres", 1.0, 1);
+    checkpointer.cmdline = vec!["postgres: checkpointer".to_string()];
+    let mut kworker = process(4002, "kworker/0:1", 0.0, 1);
+    kworker.cmdline.clear();
+    let mut argv0 = config();
+    argv0.name_source = NameSource::Argv0;
+    let mut app = fake_signals_app(argv0, Box::new(FixedProcesses(vec![checkpointer, kworker])));
+
+    assert_eq!(
+        app.display_name(&app.filtered_processes()[0]),
+        "postgres: checkpointer"
+    );
+    assert_eq!(
+        app.display_name(&app.filtered_processes()[1]),
+        "kworker/0:1"
+    );
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "checkp");
+    assert_eq!(names(&app), ["postgres"]);
+}
+
+#[test]
+fn exact_filter_matches_whole_names_only() {
+    let processes = vec![
+        process(4001, "ssh", 1.0, 1),
+        process(4002, "sshd", 1.0, 1),
+        process(4003, "ssh-agent", 1.0, 1),
+        process(4004, "SSH", 1.0, 1),
+    ];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "exact:ssh");
+    assert_eq!(names(&app), ["ssh", "SSH"]);
+
+    app.handle_input(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(names(&app), ["ssh"]);
+}
+
+#[test]
+fn exact_matches_highlight_one_index_per_character() {
+    let processes = vec![process(4001, "naïve", 1.0, 1)];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "exact:naïve");
+    assert_eq!(names(&app), ["naïve"]);
+    assert_eq!(app.highlight_indices(4001), Some(&[0, 1, 2, 4, 5][..]));
+}
+
+#[test]
+fn d_cycles_through_processes_in_disk_sleep() {
+    let mut processes = vec![
+        process(4001, "rsync", 9.0, 1),
+        process(4002, "shell", 5.0, 1),
+        process(4003, "nfs-reader", 1.0, 1),
+    ];
+    processes[0].state = ProcessState::DiskSleep;
+    processes[2].state = ProcessState::DiskSleep;
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('D'));
+    assert_eq!(app.current_pid(), Some(4003));
+    assert_eq!(app.status_message().unwrap().1, StatusLevel::Warning);
+    press(&mut app, KeyCode::Char('D'));
+    assert_eq!(app.current_pid(), Some(4001));
+}
+
+#[test]
+fn batch_failures_are_summarized_by_cause() {
+    let sleeper = process(4_199_998, "sleep", 5.0, 1);
+    let ghost = process(4_199_999, "ghost", 1.0, 1);
+    let signals = FakeSignals::reaching(vec![sleeper.clone()]);
+    let mut app = App::with_signals(
+        config(),
+        Box::new(FixedProcesses(vec![sleeper, ghost])),
+        signals.sender(),
+    );
+
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char('k'));
+
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(message, "SIGTERM: 1 killed, 1 not found (PID 4199999)");
+    assert_eq!(*level, StatusLevel::Warning);
+    assert_eq!(signals.sent(), [(4_199_998, Signal::Sigterm)]);
+}
+
+#[test]
+fn batch_results_only_say_killed_for_kill_signals() {
+    let sleeper = process(4_199_998, "sleep", 5.0, 1);
+    let ghost = process(4_199_999, "ghost", 1.0, 1);
+    let signals = FakeSignals::reaching(vec![sleeper.clone()]);
+    let mut app = App::with_signals(
+        config(),
+        Box::new(FixedProcesses(vec![sleeper, ghost])),
+        signals.sender(),
+    );
+
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char('S'));
+    type_text(&mut app, "18");
+    press(&mut app, KeyCode::Enter);
+
+    let (message, _) = app.status_message().unwrap();
+    assert_eq!(message, "SIGCONT: 1 signalled, 1 not found (PID 4199999)");
+    assert_eq!(signals.sent(), [(4_199_998, Signal::Sigcont)]);
+}
+
+#[test]
+fn q_quits_from_normal_mode() {
+    let mut app = app();
+    assert!(press(&mut app, KeyCode::Char('q')));
+}
+
+#[test]
+fn sparse_own_process_list_hints_at_show_all() {
+    let mut own_only = config();
+    own_only.show_all_processes = false;
+    let processes = vec![process(1, "bash", 0.0, 1), process(2, "vim", 0.0, 1)];
+    let mut app = fake_signals_app(own_only, Box::new(FixedProcesses(processes)));
+    assert!(app.hidden_processes_hint().is_some());
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "vim");
+    press(&mut app, KeyCode::Enter);
+    assert!(app.hidden_processes_hint().is_none());
+    press(&mut app, KeyCode::Char('/'));
+    press(&mut app, KeyCode::Tab);
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.filter_layers().count(), 1);
+    assert!(app.hidden_processes_hint().is_none());
+    app.clear_filter();
+    assert!(app.hidden_processes_hint().is_some());
+
+    press(&mut app, KeyCode::Char('a'));
+    assert!(app.show_all_processes());
+    assert!(app.hidden_processes_hint().is_none());
+}
+
+#[test]
+fn info_pane_comes_back_after_leaving_the_tree() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('i'));
+    press(&mut app, KeyCode::Char('t'));
+    assert!(app.tree_view_open());
+    assert!(!app.is_info_pane_open());
+
+    press(&mut app, KeyCode::Char('t'));
+    assert!(app.is_info_pane_open());
+    assert_eq!(app.mode(), AppMode::InfoPane);
+}
+
+#[test]
+fn info_pane_can_stay_beside_the_tree() {
+    let mut side_by_side = config();
+    side_by_side.tree_with_info = true;
+    let processes = vec![
+        process(4001, "firefox", 12.5, 1),
+        process(4002, "nginx", 0.5, 1),
+    ];
+    let mut app = fake_signals_app(side_by_side, Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('i'));
+    press(&mut app, KeyCode::Char('t'));
+    assert!(app.is_info_pane_open());
+    assert_eq!(app.info_pid(), app.tree_selected_pid());
+}
+
+#[test]
+fn memory_thresholds_scale_with_total_ram() {
+    let percent = MemoryThresholds::default();
+    let gib = 1024 * 1024 * 1024;
+    assert_eq!(percent.limits(100 * gib), (5 * gib, 20 * gib));
+    assert_eq!(
+        percent.limits(0),
+        (percent.warn_bytes, percent.critical_bytes)
+    );
+
+    let absolute = MemoryThresholds {
+        scale: MemoryScale::Absolute,
+        ..percent
+    };
+    assert_eq!(absolute.limits(100 * gib), (500 * 1024 * 1024, 2 * gib));
+}
+
+#[test]
+fn ctrl_r_reloads_display_settings_and_keeps_them_on_parse_errors() {
+    let dir = std::env::temp_dir().join(format!("pkillr-reload-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&path, "[display]\ntheme = \"serious\"\n").unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let mut app = fake_signals_app(with_file, Box::new(FixedProcesses(Vec::new())));
+    assert_eq!(app.theme(), Theme::Serious);
+
+    std::fs::write(
+        &path,
+        "[display]\ntheme = \"pink\"\nselection_style = \"gutter\"\n",
+    )
+    .unwrap();
+    app.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.theme(), Theme::Pink);
+    assert_eq!(app.selection_style(), SelectionStyle::Gutter);
+    assert_eq!(app.status_message().unwrap().0, "config reloaded");
+
+    std::fs::write(&path, "[display]\ntheme = \"neon\"\n").unwrap();
+    app.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.theme(), Theme::Pink);
+    assert_eq!(app.status_message().unwrap().1, StatusLevel::Error);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn columns_are_read_from_the_display_table() {
+    let dir = std::env::temp_dir().join(format!("pkillr-columns-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&path, "[display]\ntheme = \"serious\"\n").unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let mut app = fake_signals_app(with_file, Box::new(FixedProcesses(Vec::new())));
+    assert!(!app.columns().contains(&Column::Time));
+
+    std::fs::write(
+        &path,
+        "[display]\ncolumns = [\"pid\", \"name\", \"cpu\", \"time\"]\n",
+    )
+    .unwrap();
+    app.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(
+        app.columns(),
+        [Column::Pid, Column::Name, Column::Cpu, Column::Time]
+    );
+    assert_eq!(app.column_widths().len(), 4);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn runtime_thresholds_are_read_from_the_display_table() {
+    let dir = std::env::temp_dir().join(format!("pkillr-runtime-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        &path,
+        "[display]\nruntime_colors = true\nruntime_fresh = 30\nruntime_old = 600\n",
+    )
+    .unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let mut app = fake_signals_app(with_file, Box::new(FixedProcesses(Vec::new())));
+    let palette = app.theme().palette();
+    assert_eq!(
+        app.runtime_color(Duration::from_secs(20)),
+        Some(palette.runtime_fresh)
+    );
+    assert_eq!(
+        app.runtime_color(Duration::from_secs(45)),
+        Some(palette.text_normal)
+    );
+    assert_eq!(
+        app.runtime_color(Duration::from_secs(600)),
+        Some(palette.text_dim)
+    );
+
+    std::fs::write(&path, "[display]\nruntime_colors = false\n").unwrap();
+    app.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.runtime_color(Duration::from_secs(20)), None);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn info_limits_are_read_from_the_info_table_unless_given_on_the_command_line() {
+    let dir = std::env::temp_dir().join(format!("pkillr-info-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        &path,
+        "[info]\nmax_maps = 0\nmax_open_files = 8\nmax_env = 16\n",
+    )
+    .unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let app = fake_signals_app(with_file.clone(), Box::new(FixedProcesses(Vec::new())));
+    assert_eq!(app.info_maps_limit(), usize::MAX);
+    assert_eq!(app.info_open_files_limit(), 8);
+    assert_eq!(app.info_environment_limit(), 16);
+
+    with_file.cli_info.max_maps = Some(32);
+    let app = fake_signals_app(with_file, Box::new(FixedProcesses(Vec::new())));
+    assert_eq!(app.info_maps_limit(), 32);
+    assert_eq!(app.info_open_files_limit(), 8);
+
+    std::fs::write(&path, "[info]\nmax_lines = 3\n").unwrap();
+    let mut broken = config();
+    broken.config_file = Some(path.clone());
+    let app = fake_signals_app(broken, Box::new(FixedProcesses(Vec::new())));
+    assert_eq!(app.info_maps_limit(), 64);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn environment_reference_toggles_and_survives_its_process() {
+    let mut reference = process(4001, "api", 0.0, 1);
+    reference.environment = vec!["MODE=prod".to_string(), "PORT=80".to_string()];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(vec![reference])));
+
+    press(&mut app, KeyCode::Char('='));
+    let marked = app.env_reference().unwrap();
+    assert_eq!(marked.pid, 4001);
+    assert_eq!(marked.environment.len(), 2);
+    assert!(app.env_reference_alive());
+
+    press(&mut app, KeyCode::Char('='));
+    assert!(app.env_reference().is_none());
+}
+
+#[test]
+fn batch_signal_previews_filtered_processes_until_confirmed() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('X'));
+    assert!(app.batch_kill_prompt().is_none());
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "firefox");
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('X'));
+    let prompt = app.batch_kill_prompt().unwrap();
+    assert_eq!(prompt.signal, Signal::Sigterm);
+    assert_eq!(
+        prompt.targets.iter().map(|t| t.pid).collect::<Vec<_>>(),
+        [4001]
+    );
+
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.batch_kill_prompt().unwrap().signal, Signal::Sigkill);
+    press(&mut app, KeyCode::Char('n'));
+    assert!(app.batch_kill_prompt().is_none());
+}
+
+#[test]
+fn batch_signal_counts_filter_layers_as_a_filter() {
+    let mut processes = vec![
+        process(4001, "firefox", 12.5, 1),
+        process(4002, "firefox-bin", 3.0, 1),
+        process(4003, "fish", 0.1, 1),
+    ];
+    processes[1].user = "alice".to_string();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "fire");
+    press(&mut app, KeyCode::Tab);
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.search_query(), "");
+
+    press(&mut app, KeyCode::Char('X'));
+    let prompt = app.batch_kill_prompt().unwrap();
+    assert_eq!(prompt.query, "fire");
+    assert_eq!(prompt.targets.len(), 2);
+    press(&mut app, KeyCode::Char('n'));
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "user:alice");
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('X'));
+    let prompt = app.batch_kill_prompt().unwrap();
+    assert_eq!(prompt.query, "fire › user:alice");
+    assert_eq!(
+        prompt.targets.iter().map(|t| t.pid).collect::<Vec<_>>(),
+        [4002]
+    );
+}
+
+#[test]
+fn huge_environment_entries_are_cut_down_before_fuzzy_search() {
+    let mut bloated = process(4005, "worker", 1.0, 1024);
+    bloated.environment = vec![
+        format!("APP_CONFIG={}zqjvk", "x".repeat(100 * 1024)),
+        "WORKER_MODE=batch".to_string(),
+    ];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(vec![bloated])));
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "batch");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(names(&app), ["worker"]);
+
+    // the tail of the oversized entry is never handed to the matcher.
+    press(&mut app, KeyCode::Char('/'));
+    for _ in 0.."batch".len() {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_text(&mut app, "zqjvk");
+    press(&mut app, KeyCode::Enter);
+    assert!(names(&app).is_empty());
+}
+
+#[test]
+fn signal_menu_can_be_ordered_by_name_without_breaking_number_entry() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.signal_menu_signals()[0], Signal::Sighup);
+
+    press(&mut app, KeyCode::Char('o'));
+    assert_eq!(app.signal_order(), SignalOrder::Name);
+    let signals = app.signal_menu_signals();
+    assert_eq!(signals[0], Signal::Sigabrt);
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigterm);
+
+    press(&mut app, KeyCode::Char('9'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigkill);
+}
+
+#[test]
+fn typed_signal_numbers_start_over_after_a_match_or_another_key() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('s'));
+    let signals = app.signal_menu_signals();
+
+    // nothing starts with 9 but 9, so the next digit is a new number.
+    press(&mut app, KeyCode::Char('9'));
+    press(&mut app, KeyCode::Char('2'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigint);
+
+    // 2 could still become 20-29, until j moves the selection.
+    press(&mut app, KeyCode::Char('j'));
+    press(&mut app, KeyCode::Char('5'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigtrap);
+
+    press(&mut app, KeyCode::Char('1'));
+    press(&mut app, KeyCode::Char('5'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigterm);
+}
+
+#[test]
+fn typed_signal_numbers_never_fall_back_to_their_last_digit() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('s'));
+    let signals = app.signal_menu_signals();
+    press(&mut app, KeyCode::Char('3'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigquit);
+    press(&mut app, KeyCode::Char('2'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigquit);
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(message, "signal #32 is not a valid signal");
+    assert_eq!(*level, StatusLevel::Warning);
+
+    press(&mut app, KeyCode::Char('9'));
+    assert_eq!(signals[app.signal_menu_selected()], Signal::Sigkill);
+}
+
+#[test]
+fn kill_keys_cool_down_after_a_kill() {
+    let mut cautious = config();
+    cautious.kill_cooldown = Duration::from_secs(60);
+    let processes = vec![
+        process(4_199_998, "ghost", 2.0, 1),
+        process(4_199_997, "phantom", 1.0, 1),
+    ];
+    let mut app = fake_signals_app(cautious, Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('k'));
+    assert_ne!(app.status_message().unwrap().0, "slow down");
+
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.current_pid(), Some(4_199_997));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.status_message().unwrap().0, "slow down");
+
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.status_message().unwrap().0, "slow down");
+    assert!(app.signal_menu_open());
+    press(&mut app, KeyCode::Esc);
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "phantom");
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('X'));
+    press(&mut app, KeyCode::Char('y'));
+    assert_eq!(app.status_message().unwrap().0, "slow down");
+    assert!(app.batch_kill_prompt().is_some());
+}
+
+#[test]
+fn ignored_kill_presses_restart_the_cooldown() {
+    let mut cautious = config();
+    cautious.kill_cooldown = Duration::from_millis(150);
+    let processes = vec![process(4_199_998, "ghost", 2.0, 1)];
+    let mut app = fake_signals_app(cautious, Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('k'));
+    std::thread::sleep(Duration::from_millis(100));
+    press(&mut app, KeyCode::Char('k'));
+    assert_eq!(app.status_message().unwrap().0, "slow down");
+    std::thread::sleep(Duration::from_millis(100));
+    press(&mut app, KeyCode::Char('k'));
+    assert_eq!(app.status_message().unwrap().0, "slow down");
+}
+
+#[test]
+fn resting_on_an_unkillable_row_explains_why() {
+    let processes = vec![process(1, "init", 0.0, 1), process(4001, "firefox", 0.0, 1)];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    let pid_one = app
+        .filtered_processes()
+        .iter()
+        .position(|proc| proc.pid == 1)
+        .unwrap();
+    while app.selected_index() != pid_one {
+        press(&mut app, KeyCode::Down);
+    }
+
+    let start = std::time::Instant::now();
+    app.tick(start);
+    assert!(
+        app.status_message()
+            .is_none_or(|(message, _)| !message.starts_with("can't kill"))
+    );
+    app.tick(start + Duration::from_secs(1));
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(message, "can't kill init (PID 1): cannot kill pid 1");
+    assert_eq!(*level, StatusLevel::Warning);
+}
+
+#[test]
+fn protected_targets_need_an_explicit_override() {
+    let mut guarded = config();
+    guarded.protected = vec![ProtectedTarget::Name("ghost*".to_string())];
+    let processes = vec![process(4_199_996, "ghostd", 1.0, 1)];
+    let mut app = fake_signals_app(guarded, Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('k'));
+    let (message, level) = app.status_message().unwrap();
+    assert!(message.contains("is protected by 'ghost*'"), "{message}");
+    assert_eq!(*level, StatusLevel::Error);
+
+    press(&mut app, KeyCode::Char('!'));
+    let (message, _) = app.status_message().unwrap();
+    assert!(!message.contains("protected"), "{message}");
+}
+
+#[test]
+fn net_filter_keeps_processes_with_sockets() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "net:true");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(names(&app), ["nginx"]);
+    assert!(app.has_network_sockets(4003));
+
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "net:false");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.filtered_processes().len(), 3);
+    assert!(!app.has_network_sockets(4001));
+}
+
+#[test]
+fn net_filter_keeps_processes_whose_sockets_are_unknown() {
+    let processes = vec![
+        process(4001, "firefox", 1.0, 1),
+        process(4002, "nginx", 1.0, 1),
+        process(4003, "locked", 1.0, 1),
+    ];
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "net:true");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(names(&app), ["nginx", "locked"]);
+    assert!(!app.has_network_sockets(4003));
+
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "net:false");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(names(&app), ["firefox", "locked"]);
+}
+
+#[test]
+fn fast_refresh_rates_explain_the_cpu_sampling_interval() {
+    let mut fast = config();
+    fast.refresh_rate_ms = 50;
+    let mut app = fake_signals_app(fast, Box::new(FixedProcesses(Vec::new())));
+    app.tick(std::time::Instant::now());
+    let (message, _) = app.status_message().unwrap();
+    assert!(
+        message.starts_with("refreshing every 50ms; CPU% updates every"),
+        "{message}"
+    );
+
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(Vec::new())));
+    app.tick(std::time::Instant::now());
+    assert!(
+        app.status_message()
+            .is_none_or(|(message, _)| !message.contains("CPU%"))
+    );
+}
+
+#[test]
+fn user_summary_groups_processes_and_filters_on_enter() {
+    let mut processes = vec![
+        process(4001, "firefox", 12.5, 900),
+        process(4002, "postgres", 3.0, 300),
+        process(4003, "pg_worker", 1.0, 200),
+    ];
+    processes[1].user = "postgres".to_string();
+    processes[2].user = "postgres".to_string();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('U'));
+    assert!(app.users_popup_open());
+    let summaries = app.user_summaries();
+    assert_eq!(summaries[0].user, "postgres");
+    assert_eq!(summaries[0].processes, 2);
+    assert_eq!(summaries[0].memory_bytes, 500);
+
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.users_sort(), UserSort::Cpu);
+    assert_eq!(app.user_summaries()[0].user, "tester");
+
+    press(&mut app, KeyCode::Enter);
+    assert!(!app.users_popup_open());
+    assert_eq!(app.search_query(), "user:tester");
+    assert_eq!(names(&app), ["firefox"]);
+}
+
+#[test]
+fn users_popup_scroll_starts_over_when_reopened_or_resorted() {
+    let processes = (0..30)
+        .map(|idx| {
+            let mut proc = process(4001 + idx, "worker", 1.0, 1);
+            proc.user = format!("user{idx:02}");
+            proc
+        })
+        .collect();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('U'));
+    app.set_users_scroll_offset(12);
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.users_scroll_offset(), 0);
+
+    app.set_users_scroll_offset(12);
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('U'));
+    assert_eq!(app.users_selected(), 0);
+    assert_eq!(app.users_scroll_offset(), 0);
+}
+
+#[test]
+fn ascii_tree_style_keeps_the_unicode_alignment() {
+    let tree = |style: TreeStyle| {
+        let mut root = process(4_190_011, "init-ish", 1.0, 1);
+        root.parent_pid = Some(1);
+        let mut shell = process(4_190_012, "bash", 0.5, 1);
+        shell.parent_pid = Some(root.pid);
+        let mut editor = process(4_190_013, "vim", 0.5, 1);
+        editor.parent_pid = Some(shell.pid);
+        let mut daemon = process(4_190_014, "sshd", 0.5, 1);
+        daemon.parent_pid = Some(root.pid);
+        let mut cfg = config();
+        cfg.tree_style = style;
+        let mut app = fake_signals_app(
+            cfg,
+            Box::new(FixedProcesses(vec![root, shell, editor, daemon])),
+        );
+        press(&mut app, KeyCode::Char('t'));
+        app.tree_rows()
+            .iter()
+            .take(4)
+            .map(|row| row.prefix.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let unicode = tree(TreeStyle::Unicode);
+    let ascii = tree(TreeStyle::Ascii);
+    assert_eq!(ascii, ["", "+- ", "|  `- ", "`- "]);
+    for (u, a) in unicode.iter().zip(&ascii) {
+        assert_eq!(u.chars().count(), a.chars().count());
+    }
+}
+
+#[test]
+fn auto_tree_style_follows_the_locale() {
+    assert_eq!(
+        TreeStyle::Auto.resolve_for_locale(Some("en_US.UTF-8")),
+        TreeStyle::Unicode
+    );
+    assert_eq!(
+        TreeStyle::Auto.resolve_for_locale(Some("de_DE.utf8")),
+        TreeStyle::Unicode
+    );
+    assert_eq!(
+        TreeStyle::Auto.resolve_for_locale(Some("C")),
+        TreeStyle::Ascii
+    );
+    assert_eq!(TreeStyle::Auto.resolve_for_locale(None), TreeStyle::Unicode);
+    assert_eq!(
+        TreeStyle::Unicode.resolve_for_locale(Some("C")),
+        TreeStyle::Unicode
+    );
+}
+
+#[test]
+fn selected_pids_copy_sorted_and_a_pid_list_selects_them() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('L'));
+    for c in "4003, 4001 4199991".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    assert!(app.is_pid_selected(4001) && app.is_pid_selected(4003));
+    assert!(!app.is_pid_selected(4002));
+    let (message, level) = app.status_message().unwrap();
+    assert_eq!(*level, StatusLevel::Warning);
+    assert_eq!(message, "2 processes selected; not running: 4199991");
+
+    press(&mut app, KeyCode::Char('Y'));
+    assert_eq!(app.take_clipboard_request().as_deref(), Some("4001 4003"));
+}
+
+#[test]
+fn same_name_signal_targets_every_killable_namesake() {
+    let mut init = process(1, "worker", 0.1, 1);
+    init.user = "root".to_string();
+    let workers = vec![
+        process(4_199_991, "worker", 9.0, 1),
+        process(4_199_992, "worker", 2.0, 1),
+        init,
+        process(4003, "nginx", 0.5, 1),
+    ];
+    let expected: Vec<u32> = workers
+        .iter()
+        .filter(|proc| proc.name == "worker" && can_kill(proc).is_ok())
+        .map(|proc| proc.pid)
+        .collect();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(workers)));
+    assert_eq!(app.current_pid(), Some(4_199_991));
+
+    press(&mut app, KeyCode::Char('b'));
+    let prompt = app.batch_kill_prompt().unwrap();
+    assert_eq!(prompt.query, "worker");
+    assert_eq!(
+        prompt.targets.iter().map(|t| t.pid).collect::<Vec<_>>(),
+        expected
+    );
+    let (message, _) = app.status_message().unwrap();
+    assert!(message.contains("left out"), "{message}");
+}
+
+#[test]
+fn tty_signal_targets_everything_on_the_rows_terminal() {
+    // pts/900, far past anything the test runner sits on.
+    let stale = (139 << 8) | 132;
+    let mut shell = process(4_199_971, "bash", 3.0, 1);
+    shell.tty = Some(stale);
+    let mut editor = process(4_199_972, "vim", 1.0, 1);
+    editor.tty = Some(stale);
+    let mut elsewhere = process(4_199_973, "top", 0.5, 1);
+    elsewhere.tty = Some(stale + 1);
+    let daemon = process(4_199_974, "sshd", 0.1, 1);
+    let mut app = fake_signals_app(
+        config(),
+        Box::new(FixedProcesses(vec![shell, editor, elsewhere, daemon])),
+    );
+
+    app.handle_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL))
+        .unwrap();
+    let prompt = app.batch_kill_prompt().expect("preview opened");
+    assert_eq!(prompt.query, "pts/900");
+    assert_eq!(
+        prompt.targets.iter().map(|t| t.pid).collect::<Vec<_>>(),
+        [4_199_971, 4_199_972]
+    );
+}
+
+#[test]
+fn armed_kills_announce_their_targets_and_can_be_aborted() {
+    let mut cfg = config();
+    cfg.arm_delay = Duration::from_secs(1);
+    let processes = vec![
+        process(4_199_961, "chrome", 9.0, 1),
+        process(4_199_962, "node", 5.0, 1),
+    ];
+    let mut app = fake_signals_app(cfg, Box::new(FixedProcesses(processes)));
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char(' '));
+
+    press(&mut app, KeyCode::Char('k'));
+    assert!(app.kill_armed());
+    let (message, _) = app.status_message().unwrap();
+    assert_eq!(
+        message,
+        "→ SIGTERM to 2 processes: chrome, node — Esc to abort"
+    );
+    press(&mut app, KeyCode::Esc);
+    assert!(!app.kill_armed());
+    assert_eq!(app.mode(), AppMode::Normal);
+
+    press(&mut app, KeyCode::Char('k'));
+    app.tick(std::time::Instant::now());
+    assert!(app.kill_armed());
+    app.tick(std::time::Instant::now() + Duration::from_secs(2));
+    assert!(!app.kill_armed());
+    let (message, _) = app.status_message().unwrap();
+    assert!(!message.starts_with('→'), "{message}");
+}
+
+#[test]
+fn signalled_children_of_our_own_are_reaped_and_reported() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .expect("spawn sleep");
+    let mut sleeper = process(child.id(), "sleep", 0.0, 1);
+    sleeper.parent_pid = Some(std::process::id());
+    let mut app = App::with_source(config(), Box::new(FixedProcesses(vec![sleeper])));
+
+    press(&mut app, KeyCode::Char('k'));
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        app.tick(std::time::Instant::now());
+        let (message, _) = app.status_message().unwrap();
+        if message.starts_with("reaped") {
+            assert_eq!(
+                *message,
+                format!(
+                    "reaped our child PID {}: it was killed by SIGTERM",
+                    child.id()
+                )
+            );
+            break;
+        }
+        assert!(std::time::Instant::now() < deadline, "never reaped");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(child.wait().is_err(), "the app already reaped it");
+}
+
+#[test]
+fn small_subtrees_skip_the_kill_preview_when_configured() {
+    let tree = |confirm_min: usize| {
+        let mut parent = process(4_199_981, "builder", 1.0, 1);
+        parent.parent_pid = Some(1);
+        let mut child = process(4_199_982, "cc1", 0.5, 1);
+        child.parent_pid = Some(parent.pid);
+        let mut cfg = config();
+        cfg.tree_confirm_min = confirm_min;
+        let mut app = fake_signals_app(cfg, Box::new(FixedProcesses(vec![parent, child])));
+        press(&mut app, KeyCode::Char('t'));
+        press(&mut app, KeyCode::Char('x'));
+        app
+    };
+
+    assert!(tree(0).tree_kill_prompt().is_some());
+    assert!(tree(2).tree_kill_prompt().is_some());
+    let direct = tree(3);
+    assert!(direct.tree_kill_prompt().is_none());
+    assert!(direct.status_message().is_some());
+}
+
+#[test]
+fn risky_descendants_keep_the_kill_preview() {
+    let mut parent = process(4_199_983, "session", 1.0, 1);
+    parent.parent_pid = Some(1);
+    let mut child = process(4_199_984, "sway", 0.5, 1);
+    child.parent_pid = Some(parent.pid);
+    let mut cfg = config();
+    cfg.tree_confirm_min = 5;
+    let mut app = fake_signals_app(cfg, Box::new(FixedProcesses(vec![parent, child])));
+    press(&mut app, KeyCode::Char('t'));
+    press(&mut app, KeyCode::Char('x'));
+
+    let prompt = app.tree_kill_prompt().expect("preview opened");
+    assert_eq!(
+        prompt.risk.as_ref().unwrap().reason,
+        "PID 4199984: window manager"
+    );
+}
+
+#[test]
+fn percent_precision_cycles_per_column_and_resizes_it() {
+    let mut app = app();
+    let index_of = |wanted: Column| {
+        Column::DEFAULT_ORDER
+            .iter()
+            .position(|column| *column == wanted)
+            .unwrap()
+    };
+    let (cpu, mem) = (index_of(Column::Cpu), index_of(Column::Mem));
+    assert_eq!(app.column_widths()[cpu], 6);
+
+    press(&mut app, KeyCode::Char('.'));
+    assert_eq!((app.cpu_precision(), app.mem_precision()), (2, 1));
+    assert_eq!(app.column_widths()[cpu], 7);
+    assert_eq!(app.column_widths()[mem], 6);
+
+    press(&mut app, KeyCode::Char('.'));
+    assert_eq!(app.cpu_precision(), 0);
+    assert_eq!(app.column_widths()[cpu], 4);
+    assert_eq!(app.status_message().unwrap().0, "CPU% shows whole numbers");
+}
+
+#[test]
+fn cgroup_signal_previews_the_current_service() {
+    let workers = vec![
+        process(4_199_971, "nginx", 5.0, 1),
+        process(4_199_972, "nginx", 1.0, 1),
+        process(4_199_973, "postgres", 0.5, 1),
+    ];
+    let expected: Vec<u32> = workers
+        .iter()
+        .filter(|proc| proc.name == "nginx" && can_kill(proc).is_ok())
+        .map(|proc| proc.pid)
+        .collect();
+    let mut app = fake_signals_app(config(), Box::new(FixedProcesses(workers)));
+
+    press(&mut app, KeyCode::Char('w'));
+    let prompt = app.batch_kill_prompt().unwrap();
+    assert_eq!(prompt.query, "/system.slice/nginx.service");
+    assert_eq!(
+        prompt.targets.iter().map(|t| t.pid).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn queued_signals_pass_the_same_guards_as_kills() {
+    let mut guarded = config();
+    guarded.advanced_signals = true;
+    guarded.protected = vec![ProtectedTarget::Name("ghost*".to_string())];
+    let processes = vec![process(4_199_951, "ghostd", 1.0, 1)];
+    let mut app = fake_signals_app(guarded, Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Char('v'));
+    type_text(&mut app, "42");
+    press(&mut app, KeyCode::Enter);
+    let (message, level) = app.status_message().unwrap();
+    assert!(message.contains("is protected by 'ghost*'"), "{message}");
+    assert_eq!(*level, StatusLevel::Error);
+
+    press(&mut app, KeyCode::Char('!'));
+    let (message, _) = app.status_message().unwrap();
+    assert_eq!(message, "ghostd (PID 4199951) already exited");
+}
+
+#[test]
+fn an_overridden_protection_still_holds_once_the_kill_is_armed() {
+    let mut cfg = config();
+    cfg.arm_delay = Duration::from_secs(1);
+    cfg.arm_min_targets = 1;
+    cfg.protected = vec![ProtectedTarget::Name("ghost*".to_string())];
+    let processes = vec![process(4_199_941, "ghostd", 1.0, 1)];
+    let mut app = fake_signals_app(cfg, Box::new(FixedProcesses(processes)));
+
+    press(&mut app, KeyCode::Char('k'));
+    assert!(!app.kill_armed());
+    press(&mut app, KeyCode::Char('!'));
+    assert!(app.kill_armed());
+
+    app.tick(std::time::Instant::now() + Duration::from_secs(2));
+    assert!(!app.kill_armed());
+    let (message, _) = app.status_message().unwrap();
+    assert_eq!(message, "ghostd (PID 4199941) already exited");
+}
+
+#[test]
+fn blacklisted_processes_are_killed_again_when_they_respawn() {
+    let signals = FakeSignals::reaching(vec![process(4_199_961, "sleep", 1.0, 1)]);
+    let list = Rc::clone(&signals.processes);
+    let mut app = App::with_signals(
+        config(),
+        Box::new(SharedProcesses(Rc::clone(&list))),
+        signals.sender(),
+    );
+
+    press(&mut app, KeyCode::Char('B'));
+    press(&mut app, KeyCode::Char('y'));
+    assert_eq!(app.respawn_watch_count(), 1);
+    assert_eq!(signals.sent(), [(4_199_961, Signal::Sigterm)]);
+
+    list.borrow_mut().push(process(4_199_962, "sleep", 1.0, 1));
+    app.update_processes();
+    let (message, _) = app.status_message().unwrap();
+    assert_eq!(message, "auto-killed respawned sleep (PID 4199962) [1/5]");
+    assert_eq!(signals.sent()[1], (4_199_962, Signal::Sigterm));
+}
+
+#[test]
+fn respawn_kills_still_respect_protection() {
+    let mut cfg = config();
+    cfg.protected = vec![ProtectedTarget::Pid(4_199_962)];
+    let signals = FakeSignals::reaching(vec![process(4_199_961, "sleep", 1.0, 1)]);
+    let list = Rc::clone(&signals.processes);
+    let mut app = App::with_signals(
+        cfg,
+        Box::new(SharedProcesses(Rc::clone(&list))),
+        signals.sender(),
+    );
+
+    press(&mut app, KeyCode::Char('B'));
+    press(&mut app, KeyCode::Char('y'));
+    assert_eq!(signals.sent(), [(4_199_961, Signal::Sigterm)]);
+
+    list.borrow_mut().push(process(4_199_962, "sleep", 1.0, 1));
+    app.update_processes();
+    let (message, level) = app.status_message().unwrap();
+    assert!(message.contains("is protected by PID"), "{message}");
+    assert_eq!(*level, StatusLevel::Error);
+    assert_eq!(signals.sent().len(), 1);
+}
+
+#[test]
+fn respawns_of_other_commands_are_left_alone() {
+    let signals = FakeSignals::reaching(vec![process(4_199_961, "sleep", 1.0, 1)]);
+    let list = Rc::clone(&signals.processes);
+    let mut app = App::with_signals(
+        config(),
+        Box::new(SharedProcesses(Rc::clone(&list))),
+        signals.sender(),
+    );
+
+    press(&mut app, KeyCode::Char('B'));
+    press(&mut app, KeyCode::Char('y'));
+    assert_eq!(signals.sent().len(), 1);
+
+    let mut lookalike = process(4_199_971, "sleep", 1.0, 1);
+    lookalike.cmdline = vec!["/usr/bin/sleep".to_string(), "60".to_string()];
+    list.borrow_mut().push(lookalike);
+    app.update_processes();
+    assert!(!app.status_message().unwrap().0.contains("respawned"));
+    assert_eq!(app.respawn_watch_count(), 1);
+    assert_eq!(signals.sent().len(), 1);
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+/// a header on row 2 with PID in 0..8 and Name in 8..40, and rows from 3 down.
+fn with_geometry(app: &mut App) {
+    app.set_table_geometry(Some(TableGeometry {
+        header_y: 2,
+        rows_y: 3,
+        rows_height: 10,
+        columns: vec![(0, 8, SortColumn::Pid), (8, 40, SortColumn::Name)],
+    }));
+}
+
+#[test]
+fn header_clicks_sort_and_flip_the_order() {
+    let mut app = app();
+    with_geometry(&mut app);
+    let click = MouseEventKind::Down(MouseButton::Left);
+
+    app.handle_mouse(mouse(click, 12, 2));
+    assert_eq!(app.sort_column(), SortColumn::Name);
+    let first = names(&app)[0].to_string();
+
+    app.handle_mouse(mouse(click, 20, 2));
+    assert_eq!(app.sort_column(), SortColumn::Name);
+    assert_ne!(names(&app)[0], first);
+
+    app.handle_mouse(mouse(click, 60, 2));
+    assert_eq!(app.sort_column(), SortColumn::Name);
+}
+
+#[test]
+fn row_clicks_and_the_wheel_move_the_selection() {
+    let mut app = app();
+    with_geometry(&mut app);
+
+    app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 4, 5));
+    assert_eq!(app.selected_index(), 2);
+    app.handle_mouse(mouse(MouseEventKind::ScrollDown, 4, 5));
+    assert_eq!(app.selected_index(), 3);
+    app.handle_mouse(mouse(MouseEventKind::ScrollUp, 4, 5));
+    assert_eq!(app.selected_index(), 2);
+
+    // past the last process
+    app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 4, 11));
+    assert_eq!(app.selected_index(), 2);
+}
+
+#[test]
+fn mouse_events_are_ignored_when_the_mouse_is_off() {
+    let mut no_mouse = config();
+    no_mouse.mouse = false;
+    let processes = vec![
+        process(4001, "firefox", 12.5, 1),
+        process(4002, "postgres", 3.0, 1),
+    ];
+    let mut app = fake_signals_app(no_mouse, Box::new(FixedProcesses(processes)));
+    assert!(!app.mouse_enabled());
+    with_geometry(&mut app);
+    let sorted_by = app.sort_column();
+
+    app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 12, 2));
+    app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 4, 4));
+    app.handle_mouse(mouse(MouseEventKind::ScrollDown, 4, 4));
+    assert_eq!(app.sort_column(), sorted_by);
+    assert_eq!(app.selected_index(), 0);
+}
+
+#[test]
+fn mouse_can_be_turned_off_from_the_display_table() {
+    let dir = std::env::temp_dir().join(format!("pkillr-mouse-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&path, "[display]\nmouse = false\n").unwrap();
+
+    let mut with_file = config();
+    with_file.config_file = Some(path.clone());
+    let app = fake_signals_app(with_file, Box::new(FixedProcesses(Vec::new())));
+    assert!(!app.mouse_enabled());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,45 @@
+use std::path::Path;
+use std::time::Duration;
+
+use pkillr::config::{PathDisplay, RuntimeThresholds, Theme, mib_to_bytes};
+
+#[test]
+fn short_paths_abbreviate_home_and_deep_middles() {
+    let home = Some(Path::new("/home/ana"));
+    let short = |path: &str| PathDisplay::Short.render(Path::new(path), home);
+    assert_eq!(short("/home/ana/src/pkillr"), "~/src/pkillr");
+    assert_eq!(short("/home/ana"), "~");
+    assert_eq!(
+        short("/srv/apps/billing/releases/42/current"),
+        "/srv/…/42/current"
+    );
+    assert_eq!(short("/usr/bin"), "/usr/bin");
+    assert_eq!(short("/"), "/");
+    assert_eq!(
+        PathDisplay::Basename.render(Path::new("/usr/lib/postgresql/bin/postgres"), home),
+        "postgres"
+    );
+    assert_eq!(
+        PathDisplay::Full.render(Path::new("/home/ana/x"), home),
+        "/home/ana/x"
+    );
+}
+
+#[test]
+fn runtime_color_goes_from_bright_to_dim_with_age() {
+    let palette = Theme::Serious.palette();
+    let color = |secs| {
+        Theme::Serious.get_runtime_color(Duration::from_secs(secs), RuntimeThresholds::default())
+    };
+    assert_eq!(color(5), palette.runtime_fresh);
+    assert_eq!(color(60), palette.text_normal);
+    assert_eq!(color(3 * 60 * 60), palette.text_normal);
+    assert_eq!(color(2 * 24 * 60 * 60), palette.text_dim);
+}
+
+#[test]
+fn huge_mib_settings_saturate_instead_of_wrapping() {
+    assert_eq!(mib_to_bytes(2), 2 * 1024 * 1024);
+    assert_eq!(mib_to_bytes(u64::MAX / 1024), u64::MAX);
+    assert_eq!(mib_to_bytes(u64::MAX), u64::MAX);
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use pkillr::process::{ProcessInfo, ProcessState};
+use pkillr::{ProcessDto, SCHEMA_VERSION};
+
+fn process() -> ProcessInfo {
+    ProcessInfo {
+        pid: 4242,
+        name: "naïve \"worker\"".to_string(),
+        cpu_percent: 12.345,
+        memory_bytes: 300 * 1024 * 1024,
+        user: "tester".to_string(),
+        runtime: Duration::from_secs(3_600),
+        cpu_time: Duration::from_millis(90_500),
+        cmdline: vec!["/usr/bin/worker".to_string(), "--title=a\\b\n".to_string()],
+        environment: vec!["SECRET=hunter2".to_string()],
+        parent_pid: Some(1),
+        state: ProcessState::DiskSleep,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn process_records_round_trip_through_json() {
+    let dto = ProcessDto::from(&process());
+    assert_eq!(dto.schema_version, SCHEMA_VERSION);
+    assert_eq!(dto.state, "Disk Sleep");
+
+    let json = dto.to_json();
+    assert!(!json.contains('\n'));
+    assert!(!json.contains("SECRET"));
+    assert_eq!(ProcessDto::from_json(&json).unwrap(), dto);
+}
+
+#[test]
+fn non_finite_cpu_is_written_as_null() {
+    for cpu in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        let mut proc = process();
+        proc.cpu_percent = cpu;
+        let json = ProcessDto::from(&proc).to_json();
+        assert!(json.contains("\"cpu_percent\": null"), "{json}");
+        assert_eq!(ProcessDto::from_json(&json).unwrap().cpu_percent, 0.0);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn records_are_serializable_with_serde() {
+    fn assert_serialize<T: serde::Serialize>(_: &T) {}
+    assert_serialize(&ProcessDto::from(&process()));
+}
+
+#[test]
+fn newer_schema_versions_are_refused() {
+    let mut dto = ProcessDto::from(&process());
+    dto.schema_version = SCHEMA_VERSION + 1;
+    let err = ProcessDto::from_json(&dto.to_json()).unwrap_err();
+    assert!(err.contains("newer"), "{err}");
+    assert!(ProcessDto::from_json("{\"schema_version\": 1}").is_err());
+}
@@ -0,0 +1,42 @@
+use pkillr::Metrics;
+use pkillr::signals::Signal;
+
+#[test]
+fn metrics_render_counters_in_prometheus_text_format() {
+    let metrics = Metrics::new();
+    metrics.record_signal(Signal::Sigterm, true, 3);
+    metrics.record_signal(Signal::Sigkill, false, 1);
+    metrics.set_tracked_processes(42);
+
+    let text = metrics.render();
+    assert!(text.contains("pkillr_signals_sent_total{signal=\"SIGTERM\"} 3\n"));
+    assert!(!text.contains("signal=\"SIGKILL\""));
+    assert!(text.contains("pkillr_kills_succeeded_total 3\n"));
+    assert!(text.contains("pkillr_kills_failed_total 1\n"));
+    assert!(text.contains("# TYPE pkillr_tracked_processes gauge\npkillr_tracked_processes 42\n"));
+    assert_eq!(metrics.signals_sent(Signal::Sigterm), 3);
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn an_idle_connection_does_not_block_later_scrapes() {
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+    use std::sync::Arc;
+
+    let addr: SocketAddr = {
+        let probe = TcpListener::bind("127.0.0.1:0").unwrap();
+        probe.local_addr().unwrap()
+    };
+    pkillr::serve_metrics(addr, Arc::new(Metrics::new())).unwrap();
+
+    let _idle = TcpStream::connect(addr).unwrap();
+    let mut scrape = TcpStream::connect(addr).unwrap();
+    scrape
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    write!(scrape, "GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+    let mut response = String::new();
+    scrape.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+}
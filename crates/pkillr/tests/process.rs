@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use pkillr::process::{
+    EnvChange, FdSummary, argv0_basename, decode_socket_address, describe_io_priority,
+    diff_environment, label_socket_fds, parse_cgroup_path, parse_cpu_time, parse_cpus_allowed,
+    parse_page_faults, parse_schedstat, parse_terminal_group, parse_tracer_pid, parse_tty_nr,
+    truncate_env_entry, tty_name,
+};
+
+#[test]
+fn argv0_basename_drops_the_directory_but_keeps_titles() {
+    let args = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        argv0_basename(&args(&["/usr/bin/python3", "app.py"])),
+        Some("python3")
+    );
+    assert_eq!(
+        argv0_basename(&args(&["postgres: 16/main: checkpointer"])),
+        Some("postgres: 16/main: checkpointer")
+    );
+    assert_eq!(
+        argv0_basename(&args(&["/opt/app/bin/worker --queue /tmp/q"])),
+        Some("worker --queue /tmp/q")
+    );
+    assert_eq!(argv0_basename(&[]), None);
+}
+
+#[test]
+fn environment_diff_marks_added_removed_and_changed() {
+    let reference = vec!["A=1".to_string(), "B=2".to_string(), "C=3".to_string()];
+    let current = vec!["A=1".to_string(), "B=9".to_string(), "D=4".to_string()];
+    assert_eq!(
+        diff_environment(&reference, &current),
+        [
+            EnvChange::Same("A=1".to_string()),
+            EnvChange::Changed {
+                name: "B".to_string(),
+                reference: "2".to_string(),
+                current: "9".to_string(),
+            },
+            EnvChange::Removed("C=3".to_string()),
+            EnvChange::Added("D=4".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn open_files_are_summarized_by_target_type() {
+    let entries: Vec<String> = [
+        "fd 0 -> /dev/null",
+        "fd 1 -> pipe:[1234]",
+        "fd 3 -> socket:[99]",
+        "fd 4 -> socket:[100]",
+        "fd 5 -> anon_inode:[eventpoll]",
+        "fd 6 -> /var/log/app.log",
+    ]
+    .map(String::from)
+    .to_vec();
+    let summary = FdSummary::from_entries(&entries);
+    assert_eq!(summary.files, 2);
+    assert_eq!(summary.sockets, 2);
+    assert_eq!(
+        summary.describe(),
+        "files: 2, sockets: 2, pipes: 1, anon_inode: 1"
+    );
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn socket_addresses_decode_from_proc_hex() {
+    assert_eq!(decode_socket_address("0100007F:1F90"), "127.0.0.1:8080");
+    assert_eq!(decode_socket_address("0500000A:1538"), "10.0.0.5:5432");
+    assert_eq!(
+        decode_socket_address("00000000000000000000000001000000:0050"),
+        "[::1]:80"
+    );
+    assert_eq!(decode_socket_address("garbage"), "garbage");
+}
+
+#[test]
+fn socket_fds_gain_their_endpoint_or_stay_raw() {
+    let peers = HashMap::from([(42, "unix /run/app.sock".to_string())]);
+    let entries = vec![
+        "fd 3 -> socket:[42]".to_string(),
+        "fd 4 -> socket:[7]".to_string(),
+        "fd 5 -> /etc/hosts".to_string(),
+    ];
+    assert_eq!(
+        label_socket_fds(entries, &peers),
+        [
+            "fd 3 -> socket:[42] unix /run/app.sock",
+            "fd 4 -> socket:[7]",
+            "fd 5 -> /etc/hosts",
+        ]
+    );
+}
+
+#[test]
+fn cpu_time_comes_from_utime_and_stime_ticks() {
+    let stat =
+        "4242 (my (odd) name) S 1 4242 4242 0 -1 4194560 500 0 0 0 250 125 0 0 20 0 1 0 100 0 0";
+    assert_eq!(
+        parse_cpu_time(stat, 100),
+        Some(std::time::Duration::from_millis(3750))
+    );
+    assert_eq!(parse_cpu_time("garbage", 100), None);
+}
+
+#[test]
+fn foreground_group_comes_from_tpgid() {
+    assert_eq!(
+        parse_terminal_group("812 (vim) S 700 812 700 34816 812 0"),
+        Some((812, Some(812)))
+    );
+    assert_eq!(
+        parse_terminal_group("913 (make (1)) S 700 913 700 34816 812 0"),
+        Some((913, Some(812)))
+    );
+    assert_eq!(
+        parse_terminal_group("2 (sshd) S 1 2 2 0 -1 0"),
+        Some((2, None))
+    );
+}
+
+#[test]
+fn page_faults_survive_spaces_and_parens_in_the_command_name() {
+    assert_eq!(
+        parse_page_faults("4242 (Web Content (2)) S 1 4242 4242 0 -1 4194560 98123 0 417 0"),
+        Some((98123, 417))
+    );
+    assert_eq!(parse_page_faults("7 (kworker) I 2 0 0 0 -1"), None);
+}
+
+#[test]
+fn schedstat_splits_cpu_time_from_runqueue_wait() {
+    assert_eq!(
+        parse_schedstat("1250000000 310000000 4821\n"),
+        Some((Duration::from_millis(1250), Duration::from_millis(310)))
+    );
+    assert_eq!(parse_schedstat(""), None);
+    assert_eq!(parse_schedstat("1250000000 310000000"), None);
+}
+
+#[test]
+fn tty_numbers_decode_to_device_names() {
+    assert_eq!(
+        parse_tty_nr("812 (vim) S 700 812 700 34816 812 0"),
+        Some(34816)
+    );
+    assert_eq!(parse_tty_nr("2 (sshd) S 1 2 2 0 -1 0"), None);
+    assert_eq!(tty_name(34816), "pts/0");
+    assert_eq!(tty_name((136 << 8) | 7), "pts/7");
+    assert_eq!(tty_name((4 << 8) | 2), "tty2");
+    assert_eq!(tty_name((4 << 8) | 64), "ttyS0");
+}
+
+#[test]
+fn long_environment_entries_are_cut_for_display() {
+    let entry = format!("BLOB={}", "é".repeat(100 * 1024));
+    let shown = truncate_env_entry(&entry, 16);
+    assert_eq!(shown, "BLOB=ééééééééééé… (truncated)");
+    assert_eq!(truncate_env_entry("PATH=/bin", 16), "PATH=/bin");
+    assert_eq!(truncate_env_entry(&entry, 0).len(), entry.len());
+}
+
+#[test]
+fn cpu_affinity_comes_from_cpus_allowed_list() {
+    let status =
+        "Name:\tpostgres\nCapEff:\t0000000000000000\nCpus_allowed:\tf\nCpus_allowed_list:\t0-3,8\n";
+    assert_eq!(parse_cpus_allowed(status), Some("0-3,8".to_string()));
+    assert_eq!(parse_cpus_allowed("Name:\tsh\n"), None);
+}
+
+#[test]
+fn io_priority_names_the_class_and_level() {
+    assert_eq!(describe_io_priority((2 << 13) | 7, None), "best-effort 7");
+    assert_eq!(describe_io_priority((1 << 13) | 4, Some(0)), "realtime 4");
+    assert_eq!(describe_io_priority(3 << 13, None), "idle");
+    assert_eq!(
+        describe_io_priority(0, Some(10)),
+        "best-effort 6 (from nice)"
+    );
+    assert_eq!(describe_io_priority(0, None), "none");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn stat_cpu_source_measures_recent_usage() {
+    use pkillr::config::CpuSource;
+    use pkillr::process::ProcessManager;
+    use std::time::{Duration, Instant};
+
+    let me = std::process::id();
+    let mut manager = ProcessManager::new();
+    manager.set_cpu_source(CpuSource::Stat);
+    manager.get_processes(false);
+
+    let started = Instant::now();
+    let mut spins = 0u64;
+    while started.elapsed() < Duration::from_millis(400) {
+        spins = std::hint::black_box(spins.wrapping_add(1));
+    }
+    let busy = manager
+        .get_processes(false)
+        .into_iter()
+        .find(|proc| proc.pid == me)
+        .expect("the test process is listed");
+    assert!(busy.cpu_percent > 30.0, "{}", busy.cpu_percent);
+}
+
+#[test]
+fn cgroup_path_prefers_the_unified_hierarchy() {
+    assert_eq!(
+        parse_cgroup_path("0::/system.slice/nginx.service\n").as_deref(),
+        Some("/system.slice/nginx.service")
+    );
+    let v1 = "12:cpu,cpuacct:/user.slice\n1:name=systemd:/system.slice/cron.service\n";
+    assert_eq!(
+        parse_cgroup_path(v1).as_deref(),
+        Some("/system.slice/cron.service")
+    );
+    assert_eq!(parse_cgroup_path("").as_deref(), None);
+}
+
+#[test]
+fn tracer_pid_is_read_from_status() {
+    let status = "Name:\tsleep\nState:\tt (tracing stop)\nTracerPid:\t4242\n";
+    assert_eq!(parse_tracer_pid(status), Some(4242));
+    assert_eq!(parse_tracer_pid("TracerPid:\t0\n"), None);
+    assert_eq!(parse_tracer_pid("Name:\tsleep\n"), None);
+}
@@ -0,0 +1,79 @@
+use std::fs;
+
+use pkillr::process::{ProcessInfo, ProcessState};
+use pkillr::{ProtectedTarget, load_safety, protecting};
+
+fn named(pid: u32, name: &str) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        name: name.to_string(),
+        user: "tester".to_string(),
+        parent_pid: Some(1),
+        state: ProcessState::Sleeping,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn protected_entries_match_pids_and_name_globs() {
+    let postgres = named(812, "postgres");
+    assert!(ProtectedTarget::parse("812").unwrap().matches(&postgres));
+    assert!(
+        ProtectedTarget::parse("postgre?")
+            .unwrap()
+            .matches(&postgres)
+    );
+    assert!(ProtectedTarget::parse("*gres").unwrap().matches(&postgres));
+    assert!(ProtectedTarget::parse("p*s*s").unwrap().matches(&postgres));
+    assert!(
+        !ProtectedTarget::parse("postgres-*")
+            .unwrap()
+            .matches(&postgres)
+    );
+    assert!(ProtectedTarget::parse("  ").is_err());
+}
+
+#[test]
+fn protected_list_is_read_from_the_safety_table() {
+    let dir = std::env::temp_dir().join(format!("pkillr-protect-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        &path,
+        "[display]\ntheme = \"pink\"\n\n[safety]\nprotected = [4242, \"postgres*\", \"a,b\"]\n",
+    )
+    .unwrap();
+
+    let protected = load_safety(&path).unwrap().protected;
+    assert_eq!(
+        protected,
+        [
+            ProtectedTarget::Pid(4242),
+            ProtectedTarget::Name("postgres*".to_string()),
+            ProtectedTarget::Name("a,b".to_string()),
+        ]
+    );
+    assert_eq!(
+        protecting(&protected, &named(7, "postgres")),
+        Some(&protected[1])
+    );
+
+    fs::write(&path, "[safety]\nprotected = [postgres]\n").unwrap();
+    assert!(load_safety(&path).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn safe_mode_is_read_from_the_safety_table() {
+    let dir = std::env::temp_dir().join(format!("pkillr-safe-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::write(&path, "[safety]\nsafe_mode = true\n").unwrap();
+    assert!(load_safety(&path).unwrap().safe_mode);
+    fs::write(&path, "[safety]\nsafe_mode = false\n").unwrap();
+    assert!(!load_safety(&path).unwrap().safe_mode);
+    fs::write(&path, "[safety]\nsafe_mode = \"yes\"\n").unwrap();
+    assert!(load_safety(&path).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
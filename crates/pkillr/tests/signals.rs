@@ -0,0 +1,101 @@
+use std::process::Command;
+
+use pkillr::process::ProcessInfo;
+use pkillr::signals::{
+    Reaped, Signal, SignalSender, SignalTarget, TargetKind, parse_signal_value, reap_child,
+};
+
+/// one process that accepts every signal, keeping the values it was queued with.
+struct Queue(ProcessInfo, Vec<Option<i32>>);
+
+impl SignalTarget for Queue {
+    fn processes(&mut self) -> Vec<ProcessInfo> {
+        vec![self.0.clone()]
+    }
+
+    fn send(
+        &mut self,
+        _target: &ProcessInfo,
+        _signal: Signal,
+        value: Option<i32>,
+    ) -> Result<(), String> {
+        self.1.push(value);
+        Ok(())
+    }
+}
+
+#[test]
+fn only_our_own_children_can_be_reaped() {
+    let mut child = Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .expect("spawn sleep");
+    assert_eq!(reap_child(child.id()), Ok(Reaped::Running));
+    child.kill().expect("kill sleep");
+    let status = loop {
+        match reap_child(child.id()).expect("our child") {
+            Reaped::Running => std::thread::sleep(std::time::Duration::from_millis(10)),
+            status => break status,
+        }
+    };
+    assert_eq!(status, Reaped::Killed(Signal::Sigkill));
+    assert!(child.wait().is_err(), "already reaped");
+    assert!(reap_child(1).is_err());
+}
+
+#[test]
+fn signal_values_must_fit_a_c_int() {
+    assert_eq!(parse_signal_value("42"), Ok(42));
+    assert_eq!(parse_signal_value(" -7 "), Ok(-7));
+    assert!(parse_signal_value("2147483648").is_err());
+    assert!(parse_signal_value("ten").is_err());
+}
+
+#[test]
+fn queued_signals_record_their_value() {
+    let worker = ProcessInfo {
+        pid: 4_199_901,
+        name: "worker".to_string(),
+        ..Default::default()
+    };
+    let mut sender = SignalSender::with_target(Box::new(Queue(worker, Vec::new())));
+    let result = sender.send_signal_with_value(4_199_901, Signal::Sigterm, Some(7));
+
+    assert_eq!(result, Ok(()));
+    let event = sender.history().next().unwrap();
+    assert_eq!(event.pid, 4_199_901);
+    assert_eq!(event.process_name, "worker");
+    assert_eq!(event.value, Some(7));
+
+    let missing = sender.send_signal_with_value(4_199_902, Signal::Sigterm, Some(7));
+    assert_eq!(missing, Err("process not found".to_string()));
+    assert_eq!(sender.history().next().unwrap().process_name, "unknown");
+}
+
+#[test]
+fn predicted_effect_depends_on_the_target() {
+    assert!(
+        Signal::Sighup
+            .predicted_effect(TargetKind::Daemon)
+            .starts_with("reloads")
+    );
+    assert!(
+        !Signal::Sighup
+            .predicted_effect(TargetKind::Interactive)
+            .starts_with("reloads")
+    );
+    assert_eq!(
+        Signal::Sigkill.predicted_effect(TargetKind::Unknown),
+        "force-kills (no cleanup)"
+    );
+    assert_eq!(
+        Signal::Sigstop.predicted_effect(TargetKind::Daemon),
+        "suspends (resumable with SIGCONT)"
+    );
+    for signal in [Signal::Sigttin, Signal::Sigttou] {
+        assert_eq!(
+            signal.predicted_effect(TargetKind::Interactive),
+            "suspends unless handled"
+        );
+    }
+}
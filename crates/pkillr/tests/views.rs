@@ -0,0 +1,44 @@
+use std::fs;
+
+use pkillr::{SavedView, load_views, save_views};
+
+#[test]
+fn saved_views_round_trip_and_keep_other_sections() {
+    let dir = std::env::temp_dir().join(format!("pkillr-views-{}", std::process::id()));
+    let path = dir.join("config.toml");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        &path,
+        "# settings\n[other]\nkey = 1\n\n[views]\nold = \"stale\"\n",
+    )
+    .unwrap();
+
+    let saved = vec![
+        SavedView {
+            name: "dev".to_string(),
+            query: "/node|python|postgres/".to_string(),
+        },
+        SavedView {
+            name: "quoted".to_string(),
+            query: "say \"hi\" \\ bye".to_string(),
+        },
+        SavedView {
+            name: "control".to_string(),
+            query: "tab\there\u{1b}[0m".to_string(),
+        },
+    ];
+    save_views(&path, &saved).unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("[other]\nkey = 1"));
+    assert!(!contents.contains("stale"));
+    assert_eq!(load_views(&path).unwrap(), saved);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn missing_views_file_has_no_views() {
+    let path = std::env::temp_dir().join("pkillr-views-does-not-exist/config.toml");
+    assert_eq!(load_views(&path).unwrap(), Vec::new());
+}